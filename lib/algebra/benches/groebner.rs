@@ -0,0 +1,188 @@
+//! Compares Buchberger's algorithm with F4 on the cyclic and Katsura systems
+//! and on random linear combinations of the quadrics of Segre and Veronese varieties
+//! run with cargo bench -p algebra
+use algebra::{
+    field::Field,
+    groebner::GroebnerBasis,
+    group::AbelianGroup,
+    polynomials::{Monomial, MonomialOrder, Polynomial},
+    prime_field::PrimeField,
+    projective::ProjectiveMorphism,
+};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+type F = PrimeField<32003>;
+
+const RUNS: usize = 5;
+
+/// Binomial quadrics z_a z_b - z_c z_d vanishing on the image of a monomial map
+/// for Segre and Veronese embeddings these generate the ideal of the image
+fn quadratic_relations(morphism: &ProjectiveMorphism<F>) -> Vec<Polynomial<F>> {
+    let num_coords = morphism.dim_codomain();
+    let coords: Vec<Vec<usize>> = (0..num_coords)
+        .map(|j| morphism.nth_coordinate(j).unwrap().monomials()[0].powers())
+        .collect();
+    let mut by_product: HashMap<Vec<usize>, Vec<(usize, usize)>> = HashMap::new();
+    for a in 0..num_coords {
+        for b in a..num_coords {
+            let product = coords[a]
+                .iter()
+                .zip(coords[b].iter())
+                .map(|(x, y)| x + y)
+                .collect();
+            by_product.entry(product).or_default().push((a, b));
+        }
+    }
+    let quadric = |(a, b): (usize, usize)| {
+        let mut powers = vec![0; num_coords];
+        powers[a] += 1;
+        powers[b] += 1;
+        Polynomial::from(Monomial::new(<F as Field>::one(), powers))
+    };
+    let mut relations = vec![];
+    for pairs in by_product.values() {
+        for pair in pairs[1..].iter() {
+            relations.push(quadric(pairs[0]) - quadric(*pair));
+        }
+    }
+    relations
+}
+
+/// cyclic-n: the elementary symmetric sums of consecutive variables and x_0 ... x_(n-1) - 1
+fn cyclic(n: usize) -> Vec<Polynomial<F>> {
+    let mut generators: Vec<Polynomial<F>> = (1..n)
+        .map(|k| {
+            (0..n)
+                .map(|i| {
+                    let mut powers = vec![0; n];
+                    for j in 0..k {
+                        powers[(i + j) % n] = 1;
+                    }
+                    Polynomial::from(Monomial::new(<F as Field>::one(), powers))
+                })
+                .fold(Polynomial::zero().with_dim(n), |sum, term| sum + term)
+        })
+        .collect();
+    generators.push(
+        Polynomial::from(Monomial::new(<F as Field>::one(), vec![1; n]))
+            - Polynomial::constant(<F as Field>::one()).with_dim(n),
+    );
+    generators
+}
+
+/// katsura-n in u_0, ..., u_n: u_0 + 2 (u_1 + ... + u_n) = 1
+/// and sum_l u_|l| u_|m-l| = u_m for m = 0, ..., n-1 with |l|, |m-l| <= n
+fn katsura(n: usize) -> Vec<Polynomial<F>> {
+    let dim = n + 1;
+    let u = |i: usize| Polynomial::<F>::variable(i, dim);
+    let two = F::from(2);
+    let mut linear = u(0) - Polynomial::constant(<F as Field>::one()).with_dim(dim);
+    for i in 1..=n {
+        linear = linear + u(i).scale(two);
+    }
+    let mut generators = vec![linear];
+    for m in 0..n as i64 {
+        let mut relation = -u(m as usize);
+        for l in -(n as i64)..=n as i64 {
+            if (m - l).unsigned_abs() as usize <= n {
+                relation =
+                    relation + u(l.unsigned_abs() as usize) * u((m - l).unsigned_abs() as usize);
+            }
+        }
+        generators.push(relation);
+    }
+    generators
+}
+
+/// the same number of random linear combinations of the generators
+/// so that the input is no longer a Groebner basis, seeded for reproducible timings
+fn random_combinations(generators: &[Polynomial<F>], seed: u64) -> Vec<Polynomial<F>> {
+    let mut state = seed;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        F::from(((state >> 33) % 32003) as i64)
+    };
+    (0..generators.len())
+        .map(|_| {
+            generators.iter().fold(Polynomial::zero(), |sum, gen| {
+                sum + gen.clone().scale(next())
+            })
+        })
+        .collect()
+}
+
+fn time<T>(f: impl Fn() -> T) -> (Duration, T) {
+    let mut best = Duration::MAX;
+    let mut res = None;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        res = Some(f());
+        best = best.min(start.elapsed());
+    }
+    (best, res.unwrap())
+}
+
+fn main() {
+    let mut cases: Vec<(String, Vec<Polynomial<F>>)> = vec![];
+    for n in 4..=6 {
+        cases.push((format!("cyclic-{n}"), cyclic(n)));
+    }
+    for n in 3..=5 {
+        cases.push((format!("katsura-{n}"), katsura(n)));
+    }
+    let varieties = vec![
+        (
+            "Segre P1xP2",
+            ProjectiveMorphism::multi_segre_embedding(&[1, 2]),
+        ),
+        (
+            "Segre P1xP1xP1",
+            ProjectiveMorphism::multi_segre_embedding(&[1, 1, 1]),
+        ),
+        (
+            "Segre P2xP2",
+            ProjectiveMorphism::multi_segre_embedding(&[2, 2]),
+        ),
+        (
+            "Veronese P1 d=4",
+            ProjectiveMorphism::veronese_embedding(1, 4),
+        ),
+        (
+            "Veronese P2 d=2",
+            ProjectiveMorphism::veronese_embedding(2, 2),
+        ),
+        (
+            "Veronese P2 d=3",
+            ProjectiveMorphism::veronese_embedding(2, 3),
+        ),
+    ];
+    for (i, (name, morphism)) in varieties.into_iter().enumerate() {
+        let generators = random_combinations(&quadratic_relations(&morphism), i as u64 + 1);
+        cases.push((format!("random {name}"), generators));
+    }
+
+    println!(
+        "{:<24} {:>6} {:>8} {:>14} {:>14}",
+        "ideal", "gens", "basis", "buchberger", "f4"
+    );
+    for (name, generators) in cases.into_iter() {
+        let (buchberger_time, buchberger_basis) =
+            time(|| GroebnerBasis::new(generators.clone(), MonomialOrder::GRevLex));
+        let (f4_time, f4_basis) =
+            time(|| GroebnerBasis::new_f4(generators.clone(), MonomialOrder::GRevLex));
+        assert!(buchberger_basis == f4_basis);
+        println!(
+            "{:<24} {:>6} {:>8} {:>14?} {:>14?}",
+            name,
+            generators.len(),
+            f4_basis.basis().len(),
+            buchberger_time,
+            f4_time
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use super::{
+    errors::Error,
+    field::{Field, FiniteField},
+    finite_group::{GeneralLinear, Permutation, ProjectiveLinear},
+    group::{FiniteGroup, Group, SubGroup},
+    matrix::Matrix,
+    polynomials::{HomogeneousPolynomial, Monomial},
+    projective::{ProjectiveMorphism, ProjectivePoint, ProjectiveScheme},
+};
+
+/// Left action of a group on a set X
+/// (gh).x = g.(h.x) and 1.x = x
+/// acting fails if x does not fit the group element, e.g. has too few coordinates
+pub trait GroupAction<X>: Group + Clone {
+    fn act(&self, x: &X) -> Result<X, Error>;
+}
+
+/// the orbit of x under the group generated by generators, x first
+/// together with group elements mapping x to each orbit element
+/// the orbit has to be finite
+pub fn orbit_transversal<G, X>(generators: &[G], x: &X) -> Result<Vec<(X, G)>, Error>
+where
+    G: GroupAction<X>,
+    X: PartialEq + Clone,
+{
+    let mut orbit = vec![(x.clone(), G::one())];
+    let mut next = 0;
+    while next < orbit.len() {
+        for gen in generators.iter() {
+            let image = gen.act(&orbit[next].0)?;
+            if orbit.iter().all(|(y, _)| *y != image) {
+                let to_image = gen.clone() * orbit[next].1.clone();
+                orbit.push((image, to_image));
+            }
+        }
+        next += 1;
+    }
+    Ok(orbit)
+}
+
+/// the orbit of x under the group generated by generators, x first
+pub fn orbit<G, X>(generators: &[G], x: &X) -> Result<Vec<X>, Error>
+where
+    G: GroupAction<X>,
+    X: PartialEq + Clone,
+{
+    Ok(orbit_transversal(generators, x)?
+        .into_iter()
+        .map(|(y, _)| y)
+        .collect())
+}
+
+/// the stabilizer of x, generated by the Schreier generators u_(s.y)^-1 s u_y
+/// where u_y maps x to y
+pub fn stabilizer<G, X>(generators: &[G], x: &X) -> Result<SubGroup<G>, Error>
+where
+    G: GroupAction<X> + FiniteGroup,
+    X: PartialEq + Clone,
+{
+    let transversal = orbit_transversal(generators, x)?;
+    let mut schreier_generators: Vec<G> = vec![];
+    for (y, to_y) in transversal.iter() {
+        for gen in generators.iter() {
+            let image = gen.act(y)?;
+            let to_image = transversal
+                .iter()
+                .find(|(z, _)| *z == image)
+                .map(|(_, to_z)| to_z.clone())
+                .unwrap();
+            let schreier = to_image.inverse() * gen.clone() * to_y.clone();
+            if schreier != G::one() && !schreier_generators.contains(&schreier) {
+                schreier_generators.push(schreier);
+            }
+        }
+    }
+    Ok(SubGroup::generated_by(schreier_generators))
+}
+
+/// partition of a finite invariant set into orbits
+pub fn orbits<G, X>(generators: &[G], elements: &[X]) -> Result<Vec<Vec<X>>, Error>
+where
+    G: GroupAction<X>,
+    X: PartialEq + Clone,
+{
+    let mut orbits: Vec<Vec<X>> = vec![];
+    for elem in elements.iter() {
+        if orbits.iter().all(|orbit| !orbit.contains(elem)) {
+            orbits.push(orbit(generators, elem)?);
+        }
+    }
+    Ok(orbits)
+}
+
+/// orbits on the points of P^(num_variables-1) over a finite field
+pub fn point_orbits<G, K>(
+    generators: &[G],
+    num_variables: usize,
+) -> Result<Vec<Vec<ProjectivePoint<K>>>, Error>
+where
+    G: GroupAction<ProjectivePoint<K>>,
+    K: FiniteField,
+{
+    orbits(generators, &ProjectivePoint::all_points(num_variables))
+}
+
+/// basis of the homogeneous polynomials of degree deg
+/// that are fixed by all generators
+/// computed as the common kernel of g - 1 on the monomials of degree deg
+pub fn invariant_polynomials<G, K>(
+    generators: &[G],
+    num_variables: usize,
+    deg: usize,
+) -> Result<Vec<HomogeneousPolynomial<K>>, Error>
+where
+    G: GroupAction<HomogeneousPolynomial<K>>,
+    K: Field + Clone,
+{
+    let monomials = Monomial::<K>::all_of_degree(num_variables, deg);
+    let index = |mono: &Monomial<K>| {
+        monomials
+            .iter()
+            .position(|other| other.exponents() == mono.exponents())
+    };
+    let mut rows = vec![];
+    for gen in generators.iter() {
+        // column j holds g.m_j - m_j
+        let mut block = vec![vec![K::zero(); monomials.len()]; monomials.len()];
+        for (j, mono) in monomials.iter().enumerate() {
+            let poly = HomogeneousPolynomial::from(mono.clone()).with_dim(num_variables);
+            for term in gen.act(&poly)?.monomials() {
+                let i = index(&term).ok_or(Error::WrongDegree {
+                    found: term.deg(),
+                    expected: deg,
+                })?;
+                block[i][j] = block[i][j].clone() + term.coefficient;
+            }
+            block[j][j] = block[j][j].clone() + -<K as Field>::one();
+        }
+        rows.extend(block);
+    }
+    if rows.is_empty() {
+        rows.push(vec![K::zero(); monomials.len()]);
+    }
+    Matrix::new(rows)?
+        .kernel()
+        .into_iter()
+        .map(|coefficients| {
+            let terms = coefficients
+                .into_iter()
+                .zip(monomials.iter())
+                .filter(|(coeff, _)| *coeff != K::zero())
+                .map(|(coeff, mono)| Monomial::new(coeff, mono.powers()))
+                .collect();
+            Ok(HomogeneousPolynomial::new(terms)?.with_dim(num_variables))
+        })
+        .collect()
+}
+
+impl Permutation {
+    /// the linear automorphism of P^(n-1) moving coordinate i to position self(i)
+    /// the permutation has to fix all points from n on
+    pub fn automorphism<K: Field + Clone>(&self, n: usize) -> Result<ProjectiveMorphism<K>, Error> {
+        if self.degree() > n {
+            return Err(Error::DimensionMismatch {
+                found: n,
+                expected: self.degree(),
+            });
+        }
+        let mut matrix = Matrix::zero(n, n);
+        for i in 0..n {
+            matrix.set_entry(self.apply(i), i, <K as Field>::one());
+        }
+        ProjectiveMorphism::from_matrix(&matrix)
+    }
+}
+
+/// permutes the coordinates, [x_0 : ... : x_(n-1)] -> y with y_(g(i)) = x_i
+impl<K: Field + Clone> GroupAction<ProjectivePoint<K>> for Permutation {
+    fn act(&self, pt: &ProjectivePoint<K>) -> Result<ProjectivePoint<K>, Error> {
+        self.automorphism(pt.dim())?.eval(pt.clone())
+    }
+}
+
+/// change of variables X_i -> X_(g(i)), that is f -> f o g^-1
+/// so V(g.f) = g.V(f)
+impl<K: Field + Clone> GroupAction<HomogeneousPolynomial<K>> for Permutation {
+    fn act(&self, f: &HomogeneousPolynomial<K>) -> Result<HomogeneousPolynomial<K>, Error> {
+        let inverse = self.clone().inverse().automorphism(f.dim())?;
+        f.clone().compose_morphism(&inverse)
+    }
+}
+
+/// [x] -> [Ax]
+impl<K: FiniteField, const N: usize> GroupAction<ProjectivePoint<K>> for GeneralLinear<K, N> {
+    fn act(&self, pt: &ProjectivePoint<K>) -> Result<ProjectivePoint<K>, Error> {
+        self.automorphism().eval(pt.clone())
+    }
+}
+
+/// change of variables f -> f o A^-1, so V(A.f) = A.V(f)
+impl<K: FiniteField, const N: usize> GroupAction<HomogeneousPolynomial<K>> for GeneralLinear<K, N> {
+    fn act(&self, f: &HomogeneousPolynomial<K>) -> Result<HomogeneousPolynomial<K>, Error> {
+        let inverse = self.clone().inverse().automorphism();
+        f.clone().with_dim(N).compose_morphism(&inverse)
+    }
+}
+
+/// [x] -> [Ax], independent of the representative A
+impl<K: FiniteField, const N: usize> GroupAction<ProjectivePoint<K>> for ProjectiveLinear<K, N> {
+    fn act(&self, pt: &ProjectivePoint<K>) -> Result<ProjectivePoint<K>, Error> {
+        self.automorphism().eval(pt.clone())
+    }
+}
+
+/// f -> f o A^-1 scaled to leading coefficient one
+/// scalars only rescale f, so this is the action on the hypersurfaces V(f)
+/// the polynomials should be scaled the same way, e.g. for orbits
+impl<K: FiniteField, const N: usize> GroupAction<HomogeneousPolynomial<K>>
+    for ProjectiveLinear<K, N>
+{
+    fn act(&self, f: &HomogeneousPolynomial<K>) -> Result<HomogeneousPolynomial<K>, Error> {
+        let inverse = self.clone().inverse().automorphism();
+        let image = f.clone().with_dim(N).compose_morphism(&inverse)?;
+        let lead = match image.monomials().first() {
+            Some(mono) => mono.coefficient.clone(),
+            None => return Ok(image),
+        };
+        Ok(image.map_coefficients(|c| c / lead.clone()))
+    }
+}
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// the image g.X, cut out by the transformed generators
+    pub fn transform<G>(&self, g: &G) -> Result<ProjectiveScheme<K>, Error>
+    where
+        G: GroupAction<HomogeneousPolynomial<K>>,
+    {
+        let generators = self
+            .ideal_generators()
+            .iter()
+            .map(|gen| g.act(gen))
+            .collect::<Result<Vec<HomogeneousPolynomial<K>>, Error>>()?;
+        ProjectiveScheme::in_space(self.num_variables(), generators)
+    }
+
+    /// checks if g.X = X for all generators g
+    /// it suffices that the transformed generators lie in the ideal
+    pub fn is_invariant_under<G>(&self, generators: &[G]) -> Result<bool, Error>
+    where
+        G: GroupAction<HomogeneousPolynomial<K>>,
+    {
+        for g in generators.iter() {
+            for gen in self.ideal_generators().iter() {
+                if !self.ideal_contains(&g.act(gen)?) {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod action_tests {
+    use super::{invariant_polynomials, orbit, point_orbits, stabilizer, GroupAction};
+    use crate::{
+        finite_group::{GeneralLinear, Permutation, PermutationGroup, ProjectiveLinear},
+        polynomials::{HomogeneousPolynomial, Monomial},
+        prime_field::PrimeField,
+        projective::{ProjectiveMorphism, ProjectivePoint, ProjectiveScheme},
+        rational::Rational,
+    };
+
+    type F2 = PrimeField<2>;
+    type F3 = PrimeField<3>;
+
+    fn point(coordinates: Vec<u64>) -> ProjectivePoint<F2> {
+        ProjectivePoint::new(coordinates.into_iter().map(PrimeField::new).collect()).unwrap()
+    }
+
+    /// z_01 <-> z_10 on the Segre coordinates z_00, z_01, z_10, z_11
+    fn swap() -> Permutation {
+        Permutation::transposition(1, 2)
+    }
+
+    #[test]
+    fn actions_are_compatible() {
+        // V(g.f) = g.V(f): g.f vanishes at g.x if f vanishes at x
+        let f: HomogeneousPolynomial<F2> = HomogeneousPolynomial::new(vec![
+            Monomial::new(1.into(), vec![1, 1, 0]),
+            Monomial::new(1.into(), vec![0, 0, 2]),
+        ])
+        .unwrap();
+        let x = point(vec![1, 1, 1]);
+        let g: Permutation = "(0 2 1)".parse().unwrap();
+        assert_eq!(f.eval(x.clone().as_arr()).unwrap(), 0.into());
+        let (gf, gx) = (g.act(&f).unwrap(), g.act(&x).unwrap());
+        assert_eq!(gf.eval(gx.as_arr()).unwrap(), 0.into());
+        assert_eq!(g.act(&point(vec![1, 0, 0])).unwrap(), point(vec![0, 0, 1]))
+    }
+
+    #[test]
+    fn orbits_and_stabilizers() {
+        let s4 = PermutationGroup::symmetric(4);
+        let generators = s4.generators();
+        let x = point(vec![1, 1, 0, 0]);
+        assert_eq!(orbit(generators, &x).unwrap().len(), 6);
+        // stabilizer of {0, 1} is S_2 x S_2
+        assert_eq!(stabilizer(generators, &x).unwrap().order(), 4);
+        // P^3(F_2) splits by the number of nonzero coordinates
+        assert_eq!(
+            point_orbits::<Permutation, F2>(generators, 4)
+                .unwrap()
+                .len(),
+            4
+        )
+    }
+
+    #[test]
+    fn swap_symmetry_of_segre_product() {
+        let segre = ProjectiveScheme::<F2>::projective_space(2)
+            .product(&ProjectiveScheme::projective_space(2))
+            .unwrap();
+        assert!(segre.is_invariant_under(&[swap()]).unwrap());
+        // over F_2 the 9 points form 3 fixed points and 3 swapped pairs
+        let points = segre.rational_points();
+        assert_eq!(super::orbits(&[swap()], &points).unwrap().len(), 6);
+        let twisted = ProjectiveMorphism::<F2>::veronese_embedding(2, 3).image();
+        assert!(!twisted.is_invariant_under(&[swap()]).unwrap())
+    }
+
+    #[test]
+    fn invariants_of_swap() {
+        // quadrics in z_00, z_01, z_10, z_11 fixed by the swap: 10 monomials in 7 orbits
+        let invariants = invariant_polynomials::<Permutation, Rational>(&[swap()], 4, 2).unwrap();
+        assert_eq!(invariants.len(), 7);
+        assert!(invariants.iter().all(|f| swap().act(f).unwrap() == *f));
+        // only the symmetric polynomials are invariant under S_3
+        let s3 = PermutationGroup::symmetric(3);
+        let cubics = invariant_polynomials::<Permutation, Rational>(s3.generators(), 3, 3);
+        assert_eq!(cubics.unwrap().len(), 3)
+    }
+
+    #[test]
+    fn linear_groups_on_points() {
+        // GL_3(F_2) is transitive on the 7 points of the Fano plane
+        let generators = GeneralLinear::<F2, 3>::generators();
+        let x = point(vec![1, 0, 0]);
+        assert_eq!(orbit(&generators, &x).unwrap().len(), 7);
+        assert_eq!(stabilizer(&generators, &x).unwrap().order(), 24);
+        assert_eq!(point_orbits(&generators, 3).unwrap().len(), 1);
+        // PGL_2(F_3) acts on the 4 points of P^1(F_3), the stabilizer is the affine group
+        let generators = ProjectiveLinear::<F3, 2>::generators();
+        let x = ProjectivePoint::new(vec![F3::new(1), F3::new(0)]).unwrap();
+        assert_eq!(orbit(&generators, &x).unwrap().len(), 4);
+        assert_eq!(stabilizer(&generators, &x).unwrap().order(), 6)
+    }
+
+    #[test]
+    fn linear_groups_on_polynomials() {
+        fn quadric<const P: u64>(
+            terms: Vec<(u64, Vec<usize>)>,
+        ) -> HomogeneousPolynomial<PrimeField<P>> {
+            HomogeneousPolynomial::new(
+                terms
+                    .into_iter()
+                    .map(|(c, powers)| Monomial::new(PrimeField::new(c), powers))
+                    .collect(),
+            )
+            .unwrap()
+        }
+        // over F_2 the binary quadrics x_0 x_1 split into 3 pairs of points,
+        // x_0^2 + x_0 x_1 + x_1^2 has no rational zero and is invariant
+        let generators = GeneralLinear::<F2, 2>::generators();
+        let split: HomogeneousPolynomial<F2> = quadric(vec![(1, vec![1, 1])]);
+        assert_eq!(orbit(&generators, &split).unwrap().len(), 3);
+        assert_eq!(stabilizer(&generators, &split).unwrap().order(), 2);
+        let irreducible: HomogeneousPolynomial<F2> =
+            quadric(vec![(1, vec![2, 0]), (1, vec![1, 1]), (1, vec![0, 2])]);
+        assert_eq!(orbit(&generators, &irreducible).unwrap().len(), 1);
+        // PGL_2(F_3) permutes the 6 pairs of points of P^1(F_3)
+        let generators = ProjectiveLinear::<F3, 2>::generators();
+        let split: HomogeneousPolynomial<F3> = quadric(vec![(1, vec![1, 1])]);
+        let pairs = orbit(&generators, &split).unwrap();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(stabilizer(&generators, &split).unwrap().order(), 4);
+        // V(g.f) = g.V(f)
+        let g = &generators[1];
+        let zero = ProjectivePoint::new(vec![F3::new(1), F3::new(0)]).unwrap();
+        let image = g.act(&split).unwrap();
+        assert_eq!(
+            image.eval(g.act(&zero).unwrap().as_arr()).unwrap(),
+            F3::new(0)
+        )
+    }
+}
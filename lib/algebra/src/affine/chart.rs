@@ -0,0 +1,126 @@
+use super::{AffinePoint, AffineScheme};
+use crate::{
+    errors::Error,
+    field::Field,
+    group::AbelianGroup,
+    polynomials::{Monomial, Polynomial},
+    ring::Ring,
+};
+
+/// Gluing data between the standard affine charts X_from != 0 and X_to != 0 of P^n
+/// chart i has coordinates X_k/X_i for k != i, in increasing order of k
+/// on the overlap (where X_to/X_from != 0) the transition is the rational map
+/// given by numerators/denominator in the coordinates of chart from
+#[derive(Clone, Debug)]
+pub struct ChartTransition<K: Field> {
+    from: usize,
+    to: usize,
+    numerators: Vec<Polynomial<K>>,
+    denominator: Polynomial<K>,
+}
+
+impl<K> ChartTransition<K>
+where
+    K: Field + Clone,
+{
+    /// transition between charts of P^(num_variables-1)
+    pub fn new(num_variables: usize, from: usize, to: usize) -> Result<ChartTransition<K>, Error> {
+        if let Some(ind) = [from, to].into_iter().find(|ind| *ind >= num_variables) {
+            return Err(Error::DimensionMismatch {
+                found: ind,
+                expected: num_variables,
+            });
+        }
+        let dim = num_variables - 1;
+        // X_k as function on chart from
+        let homogeneous_coordinate = |k: usize| -> Polynomial<K> {
+            if k == from {
+                Polynomial::one().with_dim(dim)
+            } else {
+                Polynomial::variable(chart_index(from, k), dim)
+            }
+        };
+        Ok(ChartTransition {
+            from,
+            to,
+            numerators: (0..num_variables)
+                .filter(|k| *k != to)
+                .map(homogeneous_coordinate)
+                .collect(),
+            denominator: homogeneous_coordinate(to),
+        })
+    }
+
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    pub fn numerators(&self) -> Vec<Polynomial<K>> {
+        self.numerators.clone()
+    }
+
+    /// the overlap of the charts is where the denominator does not vanish
+    pub fn denominator(&self) -> Polynomial<K> {
+        self.denominator.clone()
+    }
+
+    /// the transition in the other direction
+    pub fn inverse(&self) -> ChartTransition<K> {
+        ChartTransition::new(self.numerators.len() + 1, self.to, self.from).unwrap()
+    }
+
+    /// image of a point in chart from
+    /// None if the point is not in the overlap
+    pub fn eval(&self, pt: AffinePoint<K>) -> Result<Option<AffinePoint<K>>, Error> {
+        let coordinates = pt.as_arr();
+        let denominator = self.denominator.eval(coordinates.clone())?;
+        if denominator == K::zero() {
+            return Ok(None);
+        }
+        let new_coordinates = self
+            .numerators
+            .iter()
+            .map(|num| Ok(num.eval(coordinates.clone())? / denominator.clone()))
+            .collect::<Result<Vec<K>, Error>>()?;
+        Ok(Some(AffinePoint::new(new_coordinates)))
+    }
+
+    /// moves a subscheme of chart to into chart from
+    /// substitutes the transition and clears denominators
+    /// the result agrees with the scheme on the overlap
+    pub fn pullback(&self, scheme: &AffineScheme<K>) -> Result<AffineScheme<K>, Error> {
+        let generators = scheme
+            .ideal_generators()
+            .iter()
+            .map(|gen| self.clear_denominators(gen))
+            .collect::<Result<Vec<Polynomial<K>>, Error>>()?;
+        AffineScheme::new(self.numerators.len(), generators)
+    }
+
+    /// den^deg(f) f(num/den)
+    fn clear_denominators(&self, f: &Polynomial<K>) -> Result<Polynomial<K>, Error> {
+        let deg = f.total_degree();
+        let mut res = Polynomial::zero();
+        for mono in f.terms() {
+            let term: Polynomial<K> = Monomial::new(mono.coefficient.clone(), mono.powers()).into();
+            let substituted = term.substitute(&self.numerators)?;
+            res = res
+                + substituted
+                    * <Polynomial<K> as Ring>::pow(self.denominator.clone(), deg - mono.deg());
+        }
+        Ok(res.with_dim(self.numerators.len()))
+    }
+}
+
+/// index of the coordinate X_k/X_i in chart i
+fn chart_index(i: usize, k: usize) -> usize {
+    if k < i {
+        k
+    } else {
+        k - 1
+    }
+}
@@ -0,0 +1,9 @@
+pub mod chart;
+pub mod morphism;
+pub mod point;
+pub mod scheme;
+
+pub use chart::ChartTransition;
+pub use morphism::AffineMorphism;
+pub use point::AffinePoint;
+pub use scheme::AffineScheme;
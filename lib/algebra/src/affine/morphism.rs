@@ -0,0 +1,89 @@
+use super::AffinePoint;
+use crate::{errors::Error, field::Field, polynomials::Polynomial};
+
+/// Polynomial map A^n -> A^m
+/// given by m polynomials in n variables
+#[derive(Clone, Debug)]
+pub struct AffineMorphism<K: Field> {
+    dim_domain: usize,
+    coordinate_functions: Vec<Polynomial<K>>,
+}
+
+impl<K> AffineMorphism<K>
+where
+    K: Field + Clone,
+{
+    pub fn new(
+        dim_domain: usize,
+        coordinate_functions: Vec<Polynomial<K>>,
+    ) -> Result<AffineMorphism<K>, Error> {
+        if let Some(fun) = coordinate_functions
+            .iter()
+            .find(|fun| fun.dim() > dim_domain)
+        {
+            return Err(Error::DimensionMismatch {
+                found: fun.dim(),
+                expected: dim_domain,
+            });
+        }
+        Ok(AffineMorphism {
+            dim_domain,
+            coordinate_functions: coordinate_functions
+                .into_iter()
+                .map(|fun| fun.with_dim(dim_domain))
+                .collect(),
+        })
+    }
+
+    /// the identity A^n -> A^n
+    pub fn identity(n: usize) -> AffineMorphism<K> {
+        AffineMorphism {
+            dim_domain: n,
+            coordinate_functions: (0..n).map(|i| Polynomial::variable(i, n)).collect(),
+        }
+    }
+
+    pub fn dim_domain(&self) -> usize {
+        self.dim_domain
+    }
+
+    pub fn dim_codomain(&self) -> usize {
+        self.coordinate_functions.len()
+    }
+
+    pub fn coordinate_functions(&self) -> Vec<Polynomial<K>> {
+        self.coordinate_functions.clone()
+    }
+
+    pub fn eval(&self, pt: AffinePoint<K>) -> Result<AffinePoint<K>, Error> {
+        if pt.dim() != self.dim_domain {
+            return Err(Error::DimensionMismatch {
+                found: pt.dim(),
+                expected: self.dim_domain,
+            });
+        }
+        let coordinates = pt.as_arr();
+        let new_coordinates = self
+            .coordinate_functions
+            .iter()
+            .map(|fun| fun.eval(coordinates.clone()))
+            .collect::<Result<Vec<K>, Error>>()?;
+        Ok(AffinePoint::new(new_coordinates))
+    }
+
+    /// self ∘ other, first other then self
+    pub fn compose(&self, other: &AffineMorphism<K>) -> Result<AffineMorphism<K>, Error> {
+        if other.dim_codomain() != self.dim_domain {
+            return Err(Error::DimensionMismatch {
+                found: other.dim_codomain(),
+                expected: self.dim_domain,
+            });
+        }
+        let coordinate_functions = self
+            .coordinate_functions
+            .iter()
+            .map(|fun| fun.substitute(&other.coordinate_functions))
+            .collect::<Result<Vec<Polynomial<K>>, Error>>()?;
+        AffineMorphism::new(other.dim_domain, coordinate_functions)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::field::Field;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AffinePoint<K: Field> {
+    dim: usize,
+    coordinates: Vec<K>,
+}
+
+impl<K: Field> AffinePoint<K> {
+    pub fn new(coordinates: Vec<K>) -> AffinePoint<K> {
+        AffinePoint {
+            dim: coordinates.len(),
+            coordinates,
+        }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn as_arr(self) -> Vec<K> {
+        self.coordinates
+    }
+}
@@ -0,0 +1,283 @@
+use super::{AffineMorphism, AffinePoint};
+use crate::{
+    errors::Error,
+    field::Field,
+    ideal::PolynomialIdeal,
+    polynomials::{HomogeneousPolynomial, Polynomial},
+    projective::ProjectiveScheme,
+};
+use std::fmt;
+
+/// Closed subscheme of A^n given by the vanishing of polynomials
+#[derive(Clone, Debug)]
+pub struct AffineScheme<K: Field> {
+    num_variables: usize,
+    ideal_generators: Vec<Polynomial<K>>,
+}
+
+impl<K> AffineScheme<K>
+where
+    K: Field + Clone,
+{
+    /// subscheme of A^num_variables
+    pub fn new(
+        num_variables: usize,
+        ideal_generators: Vec<Polynomial<K>>,
+    ) -> Result<AffineScheme<K>, Error> {
+        if let Some(gen) = ideal_generators
+            .iter()
+            .find(|gen| gen.dim() > num_variables)
+        {
+            return Err(Error::DimensionMismatch {
+                found: gen.dim(),
+                expected: num_variables,
+            });
+        }
+        Ok(AffineScheme {
+            num_variables,
+            ideal_generators: ideal_generators
+                .into_iter()
+                .map(|gen| gen.with_dim(num_variables))
+                .collect(),
+        })
+    }
+
+    /// the whole space A^num_variables
+    pub fn affine_space(num_variables: usize) -> AffineScheme<K> {
+        AffineScheme {
+            num_variables,
+            ideal_generators: vec![],
+        }
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    pub fn ideal_generators(&self) -> Vec<Polynomial<K>> {
+        self.ideal_generators.clone()
+    }
+
+    pub fn ideal(&self) -> PolynomialIdeal<K> {
+        PolynomialIdeal::with_dim(self.ideal_generators(), self.num_variables)
+    }
+
+    /// Krull dimension of the coordinate ring
+    /// None for the empty scheme
+    pub fn dim(&self) -> Option<usize> {
+        self.ideal().hilbert_series().krull_dimension()
+    }
+
+    pub fn contains(&self, pt: &AffinePoint<K>) -> Result<bool, Error> {
+        if pt.dim() != self.num_variables {
+            return Err(Error::DimensionMismatch {
+                expected: self.num_variables,
+                found: pt.dim(),
+            });
+        }
+        for poly in self.ideal_generators.iter() {
+            if poly.eval(pt.clone().as_arr())? != K::zero() {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// preimage of the scheme under a morphism, given by substitution
+    pub fn pullback(&self, morphism: &AffineMorphism<K>) -> Result<AffineScheme<K>, Error> {
+        if morphism.dim_codomain() != self.num_variables {
+            return Err(Error::DimensionMismatch {
+                found: morphism.dim_codomain(),
+                expected: self.num_variables,
+            });
+        }
+        let values = morphism.coordinate_functions();
+        let generators = self
+            .ideal_generators
+            .iter()
+            .map(|gen| gen.substitute(&values))
+            .collect::<Result<Vec<Polynomial<K>>, Error>>()?;
+        AffineScheme::new(morphism.dim_domain(), generators)
+    }
+
+    /// projective closure in P^num_variables
+    /// the homogenizing variable is inserted at position i
+    /// so the scheme is the affine chart X_i != 0 of its closure
+    /// homogenizing a Groebner basis for a degree order generates the homogenized ideal
+    pub fn projective_closure(&self, i: usize) -> Result<ProjectiveScheme<K>, Error> {
+        if i > self.num_variables {
+            return Err(Error::DimensionMismatch {
+                found: i,
+                expected: self.num_variables,
+            });
+        }
+        let generators: Vec<HomogeneousPolynomial<K>> = self
+            .ideal()
+            .groebner_basis()
+            .basis()
+            .iter()
+            .map(|gen| gen.homogenize(i))
+            .collect();
+        ProjectiveScheme::in_space(self.num_variables + 1, generators)
+    }
+}
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// restriction to the affine chart X_i != 0, i.e. setting X_i = 1
+    pub fn affine_chart(&self, i: usize) -> Result<AffineScheme<K>, Error> {
+        if i >= self.num_variables() {
+            return Err(Error::DimensionMismatch {
+                found: i,
+                expected: self.num_variables(),
+            });
+        }
+        let generators = self
+            .ideal_generators()
+            .into_iter()
+            .map(|gen| gen.dehomogenize(i))
+            .collect();
+        AffineScheme::new(self.num_variables() - 1, generators)
+    }
+
+    /// restrictions to all standard affine charts
+    pub fn affine_charts(&self) -> Vec<AffineScheme<K>> {
+        (0..self.num_variables())
+            .map(|i| self.affine_chart(i).unwrap())
+            .collect()
+    }
+}
+
+/// Ideal generators are only compared up to the generated ideal
+impl<K> PartialEq for AffineScheme<K>
+where
+    K: Field + Clone,
+{
+    fn eq(&self, other: &AffineScheme<K>) -> bool {
+        self.num_variables == other.num_variables && self.ideal() == other.ideal()
+    }
+}
+
+impl<K> fmt::Display for AffineScheme<K>
+where
+    K: Field,
+    K: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ideal_str: Vec<String> = self
+            .ideal_generators
+            .iter()
+            .map(|poly| format!("{}", poly))
+            .collect();
+        write!(f, "A^{}/<{}>", self.num_variables, ideal_str.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod affine_scheme_tests {
+    use super::super::{AffineMorphism, AffinePoint, ChartTransition};
+    use super::AffineScheme;
+    use crate::{
+        projective::ProjectiveScheme,
+        rational::Rational,
+        test_helpers::{homogeneous, poly},
+    };
+
+    fn affine_cubic() -> AffineScheme<Rational> {
+        AffineScheme::new(
+            3,
+            vec![
+                poly(vec![(1, vec![0, 1, 0]), (-1, vec![2, 0, 0])]),
+                poly(vec![(1, vec![0, 0, 1]), (-1, vec![3, 0, 0])]),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn projective_cubic() -> ProjectiveScheme<Rational> {
+        ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0, 1, 0]), (-1, vec![0, 2, 0, 0])]),
+            homogeneous(vec![(1, vec![1, 0, 0, 1]), (-1, vec![0, 1, 1, 0])]),
+            homogeneous(vec![(1, vec![0, 1, 0, 1]), (-1, vec![0, 0, 2, 0])]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn chart_of_twisted_cubic() {
+        let chart = projective_cubic().affine_chart(0).unwrap();
+        assert_eq!(chart, affine_cubic());
+        assert_eq!(chart.dim(), Some(1))
+    }
+
+    #[test]
+    fn closure_of_twisted_cubic() {
+        let closure = affine_cubic().projective_closure(0).unwrap();
+        assert_eq!(closure.ideal(), projective_cubic().ideal())
+    }
+
+    #[test]
+    fn pullback_along_parametrization() {
+        // t -> (t, t^2, t^3) maps A^1 into the cubic
+        let param = AffineMorphism::new(
+            1,
+            vec![
+                poly(vec![(1, vec![1])]),
+                poly(vec![(1, vec![2])]),
+                poly(vec![(1, vec![3])]),
+            ],
+        )
+        .unwrap();
+        let preimage = affine_cubic().pullback(&param).unwrap();
+        assert_eq!(preimage, AffineScheme::affine_space(1));
+        let image = param.eval(AffinePoint::new(vec![2.into()])).unwrap();
+        assert!(affine_cubic().contains(&image).unwrap())
+    }
+
+    #[test]
+    fn compose_morphisms() {
+        let square = AffineMorphism::new(1, vec![poly(vec![(1, vec![2])])]).unwrap();
+        let fourth = square.compose(&square).unwrap();
+        assert_eq!(
+            fourth.coordinate_functions(),
+            vec![poly(vec![(1, vec![4])])]
+        )
+    }
+
+    #[test]
+    fn transition_round_trip() {
+        let transition = ChartTransition::<Rational>::new(4, 0, 2).unwrap();
+        let pt = AffinePoint::new(vec![2.into(), 4.into(), 8.into()]);
+        let moved = transition.eval(pt.clone()).unwrap().unwrap();
+        let expected_moved = AffinePoint::new(vec![
+            Rational::new(1, 4).unwrap(),
+            Rational::new(1, 2).unwrap(),
+            2.into(),
+        ]);
+        assert_eq!(moved, expected_moved);
+        let back = transition.inverse().eval(moved).unwrap().unwrap();
+        assert_eq!(back, pt)
+    }
+
+    #[test]
+    fn transition_outside_overlap() {
+        let transition = ChartTransition::<Rational>::new(3, 0, 1).unwrap();
+        let pt = AffinePoint::new(vec![0.into(), 1.into()]);
+        assert_eq!(transition.eval(pt).unwrap(), None)
+    }
+
+    #[test]
+    fn charts_glue() {
+        // chart 3 moved to chart 0 agrees with chart 0 away from X_3 = 0
+        let cubic = projective_cubic();
+        let transition = ChartTransition::new(4, 0, 3).unwrap();
+        let moved = transition
+            .pullback(&cubic.affine_chart(3).unwrap())
+            .unwrap();
+        let den = transition.denominator();
+        let chart = cubic.affine_chart(0).unwrap();
+        assert_eq!(
+            moved.ideal().saturation_element(&den),
+            chart.ideal().saturation_element(&den)
+        )
+    }
+}
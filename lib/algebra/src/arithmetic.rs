@@ -1,100 +1,123 @@
-pub fn gcd(n: i32, m: i32) -> u32 {
-    let n_abs = n.wrapping_abs() as u32;
-    let m_abs = m.wrapping_abs() as u32;
-    let euclid = euclidean_algorithm(n_abs, m_abs, vec![n_abs, m_abs]);
-    *euclid.get(euclid.len() - 2).unwrap()
-}
+use super::domain::EuclideanDomain;
 
-pub fn gcd_all(ns: Vec<i32>) -> u32 {
-    if ns.is_empty() {
-        return 0;
-    }
-    let mut current_gcd = *ns.first().unwrap();
-    for next_n in ns.iter() {
-        current_gcd = gcd(*next_n, current_gcd) as i32;
+/// greatest common divisor in normal form
+/// the gcd of 0 and 0 is 0
+pub fn gcd<R: EuclideanDomain>(a: &R, b: &R) -> R {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while b != R::zero() {
+        let r = a.div_rem(&b).unwrap().1;
+        a = b;
+        b = r;
     }
-    current_gcd as u32
+    a.normalize()
 }
 
-fn euclidean_algorithm(n: u32, m: u32, previous_remainders: Vec<u32>) -> Vec<u32> {
-    if n < m {
-        let mut new_remainders = previous_remainders;
-        new_remainders.reverse();
-        return euclidean_algorithm(m, n, new_remainders);
-    }
+/// gcd of all elements, 0 for no elements
+pub fn gcd_all<R: EuclideanDomain>(elems: &[R]) -> R {
+    elems
+        .iter()
+        .fold(R::zero(), |current_gcd, next| gcd(&current_gcd, next))
+}
 
-    if m == 0 {
-        return previous_remainders;
+/// least common multiple in normal form
+/// zero if one of a and b is zero
+pub fn lcm<R: EuclideanDomain>(a: &R, b: &R) -> R {
+    if *a == R::zero() || *b == R::zero() {
+        return R::zero();
     }
+    let quotient = b.div_rem(&gcd(a, b)).unwrap().0;
+    (a.clone() * quotient).normalize()
+}
 
-    let quot = n / m;
-    let rem = n % m;
-    let new_n = n - quot * m;
-    let mut new_remainders = previous_remainders;
-    new_remainders.push(rem);
-    if rem == 0 {
-        return new_remainders;
+/// extended Euclidean algorithm
+/// returns (g, s, t) with g = s * a + t * b the gcd in normal form
+pub fn xgcd<R: EuclideanDomain>(a: &R, b: &R) -> (R, R, R) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (R::one(), R::zero());
+    let (mut t0, mut t1) = (R::zero(), R::one());
+    while r1 != R::zero() {
+        let (q, r) = r0.div_rem(&r1).unwrap();
+        let s = s0 + -(q.clone() * s1.clone());
+        let t = t0 + -(q * t1.clone());
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+        (t0, t1) = (t1, t);
     }
-    euclidean_algorithm(m, new_n, new_remainders)
+    let unit = r0.normal_unit();
+    (unit.clone() * r0, unit.clone() * s0, unit * t0)
 }
 
 #[cfg(test)]
 mod arithmetic_tests {
-    use super::{euclidean_algorithm, gcd, gcd_all};
-
-    #[test]
-    fn euclid_1() {
-        let result = euclidean_algorithm(1, 2, vec![1, 2]);
-        let expected = vec![2, 1, 0];
-        assert_eq!(result, expected)
-    }
-
-    #[test]
-    fn euclid_2() {
-        let result = euclidean_algorithm(10, 18, vec![10, 18]);
-        let expected = vec![18, 10, 8, 2, 0];
-        assert_eq!(result, expected)
-    }
+    use super::{gcd, gcd_all, lcm, xgcd};
+    use crate::{
+        gaussian_integer::GaussianInteger, polynomials::UnivariatePolynomial, rational::Rational,
+    };
 
     #[test]
     fn gcd_coprime() {
-        let result = gcd(13, 17);
-        let expected = 1;
-        assert_eq!(result, expected)
+        assert_eq!(gcd(&13i64, &17), 1)
     }
 
     #[test]
     fn gcd_10() {
-        let result = gcd(10, 20);
-        let expected = 10;
-        assert_eq!(result, expected)
+        assert_eq!(gcd(&10i64, &20), 10)
     }
 
     #[test]
     fn gcd_neg() {
-        let result = gcd(-5, 5);
-        let expected = 5;
-        assert_eq!(result, expected)
+        assert_eq!(gcd(&-5i64, &5), 5);
+        assert_eq!(gcd(&-6i64, &0), 6)
     }
 
     #[test]
     fn gcd_2() {
-        let result = gcd(26, 10);
-        let expected = 2;
-        assert_eq!(result, expected)
+        assert_eq!(gcd(&26i128, &10), 2)
     }
 
     #[test]
     fn gcd_all_1() {
-        let result = gcd_all(vec![5, 6, 7]);
-        let expected = 1;
-        assert_eq!(result, expected)
+        assert_eq!(gcd_all(&[5i64, 6, 7]), 1)
     }
 
     #[test]
     fn gcd_all_2() {
-        let result = gcd_all(vec![2, 4, 6]);
-        let expected = 2;
-        assert_eq!(result, expected)
+        assert_eq!(gcd_all(&[2i64, 4, 6]), 2)
+    }
+
+    #[test]
+    fn lcm_integers() {
+        assert_eq!(lcm(&4i64, &-6), 12);
+        assert_eq!(lcm(&0i64, &3), 0)
+    }
+
+    #[test]
+    fn bezout_integers() {
+        let (g, s, t) = xgcd(&240i64, &46);
+        assert_eq!(g, 2);
+        assert_eq!(s * 240 + t * 46, 2)
+    }
+
+    #[test]
+    fn gaussian_gcd() {
+        // 5 = (2 + i)(2 - i) and 3 + 4i = (2 + i)^2
+        let a = GaussianInteger::new(5, 0);
+        let b = GaussianInteger::new(3, 4);
+        let (g, s, t) = xgcd(&a, &b);
+        assert_eq!(g.norm(), 5);
+        assert_eq!(s * a + t * b, g);
+        assert_eq!(gcd(&a, &b), g)
+    }
+
+    #[test]
+    fn polynomial_gcd() {
+        // (x - 1)(x + 2) and (x - 1)(x - 3)
+        let poly = |coefficients: Vec<i64>| {
+            UnivariatePolynomial::new(coefficients.into_iter().map(Rational::from).collect())
+        };
+        let a = poly(vec![-2, 1, 1]);
+        let b = poly(vec![3, -4, 1]);
+        assert_eq!(gcd(&a, &b), poly(vec![-1, 1]));
+        assert_eq!(lcm(&a, &b), poly(vec![6, -5, -2, 1]))
     }
 }
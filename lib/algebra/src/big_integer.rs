@@ -0,0 +1,656 @@
+use super::{
+    arithmetic::gcd,
+    domain::{EuclideanDomain, IntegralDomain, UniqueFactorizationDomain},
+    errors::Error,
+    group::AbelianGroup,
+    ring::{CommutativeRing, Ring},
+};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Integers of arbitrary size
+/// values that fit into i64 are stored inline, larger ones by sign and magnitude in base 2^32
+/// the representation is unique, so derived equality is equality of integers
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigInteger {
+    repr: Repr,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Repr {
+    Small(i64),
+    /// the magnitude has no leading zero limbs and does not fit into i64
+    Large {
+        negative: bool,
+        magnitude: Vec<u32>,
+    },
+}
+
+impl BigInteger {
+    fn small(value: i64) -> BigInteger {
+        BigInteger {
+            repr: Repr::Small(value),
+        }
+    }
+
+    /// the integer with the given sign and magnitude, stored inline if possible
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> BigInteger {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        if magnitude.len() <= 2 {
+            let value = magnitude
+                .iter()
+                .rev()
+                .fold(0u64, |value, limb| (value << 32) | *limb as u64);
+            let fits = value <= i64::MAX as u64 || (negative && value == 1 << 63);
+            if fits {
+                let value = value as i64;
+                return BigInteger::small(if negative {
+                    value.wrapping_neg()
+                } else {
+                    value
+                });
+            }
+        }
+        BigInteger {
+            repr: Repr::Large {
+                negative,
+                magnitude,
+            },
+        }
+    }
+
+    /// sign and magnitude, the sign of zero is positive
+    fn parts(&self) -> (bool, Cow<'_, [u32]>) {
+        match &self.repr {
+            Repr::Small(value) => {
+                let abs = value.unsigned_abs();
+                let magnitude = trim(vec![abs as u32, (abs >> 32) as u32]);
+                (*value < 0, Cow::Owned(magnitude))
+            }
+            Repr::Large {
+                negative,
+                magnitude,
+            } => (*negative, Cow::Borrowed(magnitude)),
+        }
+    }
+
+    fn into_parts(self) -> (bool, Vec<u32>) {
+        match self.repr {
+            Repr::Large {
+                negative,
+                magnitude,
+            } => (negative, magnitude),
+            Repr::Small(_) => {
+                let (negative, magnitude) = self.parts();
+                (negative, magnitude.into_owned())
+            }
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.repr == Repr::Small(0)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        match &self.repr {
+            Repr::Small(value) => *value < 0,
+            Repr::Large { negative, .. } => *negative,
+        }
+    }
+
+    pub fn abs(self) -> BigInteger {
+        if self.is_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// None if the value does not fit into i64
+    pub fn to_i64(&self) -> Option<i64> {
+        match &self.repr {
+            Repr::Small(value) => Some(*value),
+            Repr::Large { .. } => None,
+        }
+    }
+
+    /// None if the value does not fit into i128
+    pub fn to_i128(&self) -> Option<i128> {
+        match &self.repr {
+            Repr::Small(value) => Some(*value as i128),
+            Repr::Large {
+                negative,
+                magnitude,
+            } => {
+                if magnitude.len() > 4 {
+                    return None;
+                }
+                let value = magnitude
+                    .iter()
+                    .rev()
+                    .fold(0u128, |value, limb| (value << 32) | *limb as u128);
+                if *negative {
+                    0i128.checked_sub_unsigned(value)
+                } else {
+                    i128::try_from(value).ok()
+                }
+            }
+        }
+    }
+
+    /// nearest floating point number, infinite if the value is too large
+    pub fn to_f64(&self) -> f64 {
+        match &self.repr {
+            Repr::Small(value) => *value as f64,
+            Repr::Large {
+                negative,
+                magnitude,
+            } => {
+                let value = magnitude
+                    .iter()
+                    .rev()
+                    .fold(0.0, |value, limb| value * 4294967296.0 + *limb as f64);
+                if *negative {
+                    -value
+                } else {
+                    value
+                }
+            }
+        }
+    }
+
+    /// non-negative greatest common divisor
+    /// by Lehmer's algorithm, which is much faster than arithmetic::gcd for large values
+    pub fn gcd(&self, other: &BigInteger) -> BigInteger {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.repr, &other.repr) {
+            return BigInteger::from(gcd(&(*a as i128), &(*b as i128)));
+        }
+        BigInteger::from_parts(false, gcd_magnitude(&self.parts().1, &other.parts().1))
+    }
+
+    /// quotient rounded towards zero and remainder with the sign of self, as for i64
+    /// panics when dividing by zero
+    pub fn div_rem_truncated(&self, divisor: &BigInteger) -> (BigInteger, BigInteger) {
+        if divisor.is_zero() {
+            panic!("{}", Error::DivisionByZero)
+        }
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.repr, &divisor.repr) {
+            let (a, b) = (*a as i128, *b as i128);
+            return (BigInteger::from(a / b), BigInteger::from(a % b));
+        }
+        let (negative, magnitude) = self.parts();
+        let (divisor_negative, divisor_magnitude) = divisor.parts();
+        let (quotient, remainder) = div_rem_magnitude(&magnitude, &divisor_magnitude);
+        (
+            BigInteger::from_parts(negative != divisor_negative, quotient),
+            BigInteger::from_parts(negative, remainder),
+        )
+    }
+}
+
+fn trim(mut magnitude: Vec<u32>) -> Vec<u32> {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+    magnitude
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let limb = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(limb as u32);
+        carry = limb >> 32;
+    }
+    sum.push(carry as u32);
+    trim(sum)
+}
+
+/// a - b for a >= b
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, x) in a.iter().enumerate() {
+        let limb = *x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        difference.push(limb as u32);
+        borrow = if limb < 0 { 1 } else { 0 };
+    }
+    trim(difference)
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let limb = product[i + j] as u64 + *x as u64 * *y as u64 + carry;
+            product[i + j] = limb as u32;
+            carry = limb >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    trim(product)
+}
+
+/// shifts left by fewer than 32 bits, the result has one more limb
+fn shift_left(a: &[u32], shift: u32) -> Vec<u32> {
+    let mut shifted = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u32;
+    for limb in a.iter() {
+        shifted.push((limb << shift) | carry);
+        carry = if shift == 0 { 0 } else { limb >> (32 - shift) };
+    }
+    shifted.push(carry);
+    shifted
+}
+
+/// quotient and remainder of magnitudes, b is nonzero
+/// long division in base 2^32, Knuth's algorithm D
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let divisor = b[0] as u64;
+        let mut quotient = vec![0u32; a.len()];
+        let mut remainder = 0u64;
+        for i in (0..a.len()).rev() {
+            let current = (remainder << 32) | a[i] as u64;
+            quotient[i] = (current / divisor) as u32;
+            remainder = current % divisor;
+        }
+        return (trim(quotient), trim(vec![remainder as u32]));
+    }
+    // normalize so that the leading limb of the divisor has its top bit set
+    let shift = b.last().unwrap().leading_zeros();
+    let divisor = trim(shift_left(b, shift));
+    let mut rest = shift_left(a, shift);
+    let n = divisor.len();
+    let mut quotient = vec![0u32; rest.len() - n];
+    let base = 1u64 << 32;
+    for j in (0..quotient.len()).rev() {
+        let top = ((rest[j + n] as u64) << 32) | rest[j + n - 1] as u64;
+        let mut estimate = top / divisor[n - 1] as u64;
+        let mut remainder = top % divisor[n - 1] as u64;
+        while estimate >= base
+            || estimate * divisor[n - 2] as u64 > ((remainder << 32) | rest[j + n - 2] as u64)
+        {
+            estimate -= 1;
+            remainder += divisor[n - 1] as u64;
+            if remainder >= base {
+                break;
+            }
+        }
+        // rest -= estimate * divisor, shifted by j limbs
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let product = estimate * divisor[i] as u64 + carry;
+            carry = product >> 32;
+            let limb = rest[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            rest[i + j] = limb as u32;
+            borrow = if limb < 0 { 1 } else { 0 };
+        }
+        let limb = rest[j + n] as i64 - borrow - carry as i64;
+        rest[j + n] = limb as u32;
+        // the estimate was one too large, add the divisor back
+        if limb < 0 {
+            estimate -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = rest[i + j] as u64 + divisor[i] as u64 + carry;
+                rest[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            rest[j + n] = rest[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = estimate as u32;
+    }
+    let remainder = (0..n)
+        .map(|i| {
+            let high = if shift == 0 || i + 1 >= n {
+                0
+            } else {
+                rest[i + 1] << (32 - shift)
+            };
+            (rest[i] >> shift) | high
+        })
+        .collect();
+    (trim(quotient), trim(remainder))
+}
+
+fn bit_length(a: &[u32]) -> usize {
+    a.last()
+        .map_or(0, |top| 32 * a.len() - top.leading_zeros() as usize)
+}
+
+/// the 32 bits of a starting at bit position shift
+fn bits_at(a: &[u32], shift: usize) -> i64 {
+    let (index, offset) = (shift / 32, shift % 32);
+    let low = *a.get(index).unwrap_or(&0) as u64;
+    let high = *a.get(index + 1).unwrap_or(&0) as u64;
+    (((high << 32) | low) >> offset) as u32 as i64
+}
+
+/// x a + y b for a at least as long as b, the result is known to be non-negative
+fn combine(a: &[u32], x: i64, b: &[u32], y: i64) -> Vec<u32> {
+    let mut combination = Vec::with_capacity(a.len());
+    let mut carry = 0i128;
+    for (i, limb) in a.iter().enumerate() {
+        let limb = x as i128 * *limb as i128 + y as i128 * *b.get(i).unwrap_or(&0) as i128 + carry;
+        combination.push(limb as u32);
+        carry = limb >> 32;
+    }
+    trim(combination)
+}
+
+/// gcd of magnitudes by Lehmer's algorithm
+/// the leading 32 bits of a and b determine several Euclidean steps at once,
+/// which are applied to the full numbers as one linear combination
+fn gcd_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (mut a, mut b) = match compare_magnitude(a, b) {
+        Ordering::Less => (b.to_vec(), a.to_vec()),
+        _ => (a.to_vec(), b.to_vec()),
+    };
+    while b.len() > 2 {
+        let shift = bit_length(&a) - 32;
+        let (mut x, mut y) = (bits_at(&a, shift), bits_at(&b, shift));
+        // the steps so far replace (a, b) by (p a + q b, r a + s b)
+        let (mut p, mut q, mut r, mut s) = (1i64, 0i64, 0i64, 1i64);
+        while y + r != 0 && y + s != 0 {
+            let quotient = (x + p) / (y + r);
+            if quotient != (x + q) / (y + s) {
+                break;
+            }
+            (p, r) = (r, p - quotient * r);
+            (q, s) = (s, q - quotient * s);
+            (x, y) = (y, x - quotient * y);
+        }
+        if q == 0 {
+            let remainder = div_rem_magnitude(&a, &b).1;
+            a = std::mem::replace(&mut b, remainder);
+        } else {
+            (a, b) = (combine(&a, p, &b, q), combine(&a, r, &b, s));
+        }
+    }
+    let to_u64 = |v: &[u32]| {
+        v.iter()
+            .rev()
+            .fold(0u64, |value, limb| (value << 32) | *limb as u64)
+    };
+    let mut y = to_u64(&b);
+    if y == 0 {
+        return a;
+    }
+    let mut x = to_u64(&div_rem_magnitude(&a, &b).1);
+    while x != 0 {
+        (x, y) = (y % x, x);
+    }
+    trim(vec![y as u32, (y >> 32) as u32])
+}
+
+impl From<i64> for BigInteger {
+    fn from(value: i64) -> BigInteger {
+        BigInteger::small(value)
+    }
+}
+
+impl From<i128> for BigInteger {
+    fn from(value: i128) -> BigInteger {
+        match i64::try_from(value) {
+            Ok(value) => BigInteger::small(value),
+            Err(_) => {
+                let abs = value.unsigned_abs();
+                let magnitude = (0..4).map(|i| (abs >> (32 * i)) as u32).collect();
+                BigInteger::from_parts(value < 0, magnitude)
+            }
+        }
+    }
+}
+
+impl Ord for BigInteger {
+    fn cmp(&self, other: &BigInteger) -> Ordering {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.repr, &other.repr) {
+            return a.cmp(b);
+        }
+        let (negative, magnitude) = self.parts();
+        let (other_negative, other_magnitude) = other.parts();
+        match (negative, other_negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&magnitude, &other_magnitude),
+            (true, true) => compare_magnitude(&other_magnitude, &magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInteger {
+    fn partial_cmp(&self, other: &BigInteger) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigInteger {
+    type Output = BigInteger;
+    fn add(self, other: BigInteger) -> BigInteger {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.repr, &other.repr) {
+            return BigInteger::from(*a as i128 + *b as i128);
+        }
+        let (negative, magnitude) = self.into_parts();
+        let (other_negative, other_magnitude) = other.into_parts();
+        if negative == other_negative {
+            return BigInteger::from_parts(negative, add_magnitude(&magnitude, &other_magnitude));
+        }
+        match compare_magnitude(&magnitude, &other_magnitude) {
+            Ordering::Less => {
+                BigInteger::from_parts(other_negative, sub_magnitude(&other_magnitude, &magnitude))
+            }
+            _ => BigInteger::from_parts(negative, sub_magnitude(&magnitude, &other_magnitude)),
+        }
+    }
+}
+
+impl Sub for BigInteger {
+    type Output = BigInteger;
+    fn sub(self, other: BigInteger) -> BigInteger {
+        self + (-other)
+    }
+}
+
+impl Neg for BigInteger {
+    type Output = BigInteger;
+    fn neg(self) -> BigInteger {
+        match self.repr {
+            Repr::Small(value) => match value.checked_neg() {
+                Some(negated) => BigInteger::small(negated),
+                None => BigInteger::from(-(value as i128)),
+            },
+            Repr::Large {
+                negative,
+                magnitude,
+            } => BigInteger::from_parts(!negative, magnitude),
+        }
+    }
+}
+
+impl Mul for BigInteger {
+    type Output = BigInteger;
+    fn mul(self, other: BigInteger) -> BigInteger {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.repr, &other.repr) {
+            return BigInteger::from(*a as i128 * *b as i128);
+        }
+        let (negative, magnitude) = self.into_parts();
+        let (other_negative, other_magnitude) = other.into_parts();
+        BigInteger::from_parts(
+            negative != other_negative,
+            mul_magnitude(&magnitude, &other_magnitude),
+        )
+    }
+}
+
+impl AbelianGroup for BigInteger {
+    fn zero() -> BigInteger {
+        BigInteger::small(0)
+    }
+}
+
+impl Ring for BigInteger {
+    fn one() -> BigInteger {
+        BigInteger::small(1)
+    }
+}
+
+impl CommutativeRing for BigInteger {}
+
+impl IntegralDomain for BigInteger {}
+
+impl UniqueFactorizationDomain for BigInteger {}
+
+/// the remainder is non-negative and smaller than |divisor|
+/// normal forms are non-negative
+impl EuclideanDomain for BigInteger {
+    fn div_rem(&self, divisor: &BigInteger) -> Result<(BigInteger, BigInteger), Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        let (quotient, remainder) = self.div_rem_truncated(divisor);
+        if !remainder.is_negative() {
+            return Ok((quotient, remainder));
+        }
+        if divisor.is_negative() {
+            Ok((quotient + BigInteger::one(), remainder - divisor.clone()))
+        } else {
+            Ok((quotient - BigInteger::one(), remainder + divisor.clone()))
+        }
+    }
+
+    fn normal_unit(&self) -> BigInteger {
+        if self.is_negative() {
+            BigInteger::small(-1)
+        } else {
+            BigInteger::one()
+        }
+    }
+}
+
+impl fmt::Display for BigInteger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Repr::Small(value) = &self.repr {
+            return write!(f, "{}", value);
+        }
+        // digits in blocks of 9, least significant first
+        let (negative, magnitude) = self.parts();
+        let mut magnitude = magnitude.into_owned();
+        let mut blocks = vec![];
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_magnitude(&magnitude, &[1_000_000_000]);
+            blocks.push(remainder.first().copied().unwrap_or(0));
+            magnitude = quotient;
+        }
+        if negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", blocks.last().unwrap())?;
+        for block in blocks.iter().rev().skip(1) {
+            write!(f, "{:09}", block)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod big_integer_tests {
+    use super::BigInteger;
+    use crate::{arithmetic::gcd, domain::EuclideanDomain};
+
+    fn big(value: i128) -> BigInteger {
+        BigInteger::from(value)
+    }
+
+    /// 10^exponent
+    fn power_of_ten(exponent: usize) -> BigInteger {
+        (0..exponent).fold(big(1), |power, _| power * big(10))
+    }
+
+    #[test]
+    fn small_values_stay_inline() {
+        let large = big(i64::MAX as i128) + big(1);
+        assert_eq!(large.to_i64(), None);
+        assert_eq!(large.to_i128(), Some(i64::MAX as i128 + 1));
+        assert_eq!((large - big(1)).to_i64(), Some(i64::MAX));
+        assert_eq!(big(i64::MIN as i128).to_i64(), Some(i64::MIN));
+        assert_eq!((-big(i64::MIN as i128)).to_i64(), None)
+    }
+
+    #[test]
+    fn arithmetic_beyond_i128() {
+        let a = power_of_ten(30) + big(7);
+        let b = power_of_ten(25) - big(3);
+        let product = a.clone() * b.clone();
+        assert_eq!(product.to_i128(), None);
+        let (quotient, remainder) = product.div_rem(&b).unwrap();
+        assert_eq!(quotient, a);
+        assert!(remainder.is_zero());
+        let (quotient, remainder) = (product.clone() + big(5)).div_rem(&a).unwrap();
+        assert_eq!((quotient, remainder), (b.clone(), big(5)));
+        assert_eq!(format!("{}", -power_of_ten(20)), "-100000000000000000000");
+        assert!(-product.clone() < b && b < product)
+    }
+
+    #[test]
+    fn euclidean_division() {
+        let a = -(power_of_ten(20) + big(3));
+        let (quotient, remainder) = a.div_rem(&big(10)).unwrap();
+        assert_eq!(remainder, big(7));
+        assert_eq!(quotient * big(10) + remainder, a);
+        assert!(big(1).div_rem(&big(0)).is_err())
+    }
+
+    #[test]
+    fn gcd_of_large_values() {
+        let common = power_of_ten(18) + big(9);
+        let a = common.clone() * (power_of_ten(21) + big(1));
+        let b = common.clone() * (power_of_ten(21) + big(2));
+        assert_eq!(gcd(&a, &b), common);
+        assert_eq!(gcd(&-a.clone(), &b), gcd(&a, &b));
+        assert_eq!(a.gcd(&b), common);
+        assert_eq!((-a.clone()).gcd(&b), common);
+        let product = a.clone() * b.clone() * b.clone();
+        assert_eq!(product.gcd(&a), a);
+        assert_eq!(a.gcd(&big(0)), a);
+        assert_eq!(big(-12).gcd(&big(18)), big(6))
+    }
+
+    #[test]
+    fn lehmer_agrees_with_euclid() {
+        let mut state = 12345u64;
+        let mut random = |limbs: usize| {
+            (0..limbs).fold(big(0), |value, _| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                value * big(1 << 32) + big((state >> 32) as i128)
+            })
+        };
+        for limbs in 1..12 {
+            let common = random(limbs / 2 + 1);
+            let a = common.clone() * random(limbs);
+            let b = common * random(limbs + 3);
+            assert_eq!(a.gcd(&b), gcd(&a, &b));
+            assert_eq!(b.gcd(&-a.clone()), gcd(&a, &b))
+        }
+    }
+}
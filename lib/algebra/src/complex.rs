@@ -1,40 +1,115 @@
 use super::{field::Field, group::AbelianGroup, ring::Ring};
 use std::{
     fmt,
-    ops::{Add, Div, Mul, Neg},
+    ops::{Add, Div, Mul, Neg, Sub},
 };
 
-#[derive(PartialEq, Clone)]
+/// default tolerance for approximate comparisons
+pub const EPSILON: f64 = 1e-9;
+
+/// Equality up to a tolerance, for types built on floating point numbers
+/// the exact PartialEq is only meaningful for exactly representable values
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+/// |a - b| <= epsilon max(1, |a|, |b|)
+/// absolute near zero and relative for large values
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &f64, epsilon: f64) -> bool {
+        (self - other).abs() <= epsilon * self.abs().max(other.abs()).max(1.0)
+    }
+}
+
+/// entrywise, the lengths have to agree
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, other: &[T], epsilon: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Vec<T>, epsilon: f64) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), epsilon)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
 pub struct Complex {
     pub re: f64,
     pub im: f64,
 }
 
 impl Complex {
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    /// the imaginary unit
+    pub fn i() -> Complex {
+        Complex { re: 0.0, im: 1.0 }
+    }
+
+    /// angle in (-pi, pi], zero for z = 0
     pub fn arg(&self) -> f64 {
-        if self.im == 0.0 {
-            0.0
-        } else {
-            (self.re / self.im).atan()
-        }
+        self.im.atan2(self.re)
     }
 
     pub fn abs(&self) -> f64 {
-        (self.re * self.re + self.im * self.im).sqrt()
+        self.re.hypot(self.im)
+    }
+
+    /// |z|^2
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
     }
 
     pub fn from_polar(abs: f64, arg: f64) -> Complex {
         Complex {
-            re: arg.sin() * abs,
-            im: arg.cos() * abs,
+            re: abs * arg.cos(),
+            im: abs * arg.sin(),
+        }
+    }
+
+    pub fn conj(&self) -> Complex {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// e^z = e^re (cos(im) + i sin(im))
+    pub fn exp(&self) -> Complex {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    /// principal branch of the natural logarithm
+    /// ln|z| + i arg(z), the imaginary part lies in (-pi, pi]
+    pub fn ln(&self) -> Complex {
+        Complex {
+            re: self.abs().ln(),
+            im: self.arg(),
         }
     }
 }
 
+/// |a - b| <= epsilon max(1, |a|, |b|)
+impl ApproxEq for Complex {
+    fn approx_eq(&self, other: &Complex, epsilon: f64) -> bool {
+        (self.clone() - other.clone()).abs() <= epsilon * self.abs().max(other.abs()).max(1.0)
+    }
+}
+
 impl fmt::Display for Complex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.im == 0.0 {
             self.re.fmt(f)
+        } else if self.im < 0.0 {
+            write!(f, "{}-i{}", self.re, -self.im)
         } else {
             write!(f, "{}+i{}", self.re, self.im)
         }
@@ -51,21 +126,37 @@ impl Add for Complex {
     }
 }
 
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, other: Complex) -> Complex {
+        Complex {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
 impl Mul for Complex {
     type Output = Complex;
     fn mul(self, other: Complex) -> Complex {
-        let new_abs = self.abs() * other.abs();
-        let new_arg = self.arg() + other.arg();
-        Complex::from_polar(new_abs, new_arg)
+        Complex {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
     }
 }
 
+/// a / b = a conj(b) / |b|^2
 impl Div for Complex {
     type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: Self) -> Self {
-        let res_abs = self.abs() / other.abs();
-        let res_arg = self.arg() - other.arg();
-        Complex::from_polar(res_abs, res_arg)
+        let norm = other.norm_sqr();
+        let numerator = self * other.conj();
+        Complex {
+            re: numerator.re / norm,
+            im: numerator.im / norm,
+        }
     }
 }
 
@@ -101,9 +192,67 @@ impl Field for Complex {
     fn one() -> Self {
         Complex { re: 1.0, im: 0.0 }
     }
+    /// conj(z) / |z|^2
     fn inverse(self) -> Self {
-        let inv_abs = 1.0 / self.abs();
-        let inv_arg = -self.arg();
-        Complex::from_polar(inv_abs, inv_arg)
+        let norm = self.norm_sqr();
+        Complex {
+            re: self.re / norm,
+            im: -self.im / norm,
+        }
+    }
+}
+
+#[cfg(test)]
+mod complex_tests {
+    use super::{ApproxEq, Complex, EPSILON};
+    use crate::field::Field;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn polar_round_trip() {
+        for z in [
+            Complex::new(1.0, 2.0),
+            Complex::new(-3.0, 0.5),
+            Complex::new(-1.0, -1.0),
+            Complex::new(0.0, -2.0),
+        ] {
+            let w = Complex::from_polar(z.abs(), z.arg());
+            assert!(w.approx_eq(&z, EPSILON))
+        }
+        assert!(Complex::new(-1.0, 0.0).arg().approx_eq(&PI, EPSILON));
+        assert!(Complex::new(0.0, -1.0)
+            .arg()
+            .approx_eq(&(-PI / 2.0), EPSILON))
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a.clone() * b.clone(), Complex::new(5.0, 5.0));
+        assert_eq!(a.clone() - b.clone(), Complex::new(-2.0, 3.0));
+        assert!((a.clone() * b.clone() / b.clone()).approx_eq(&a, EPSILON));
+        assert!((a.clone() * a.clone().inverse()).approx_eq(&Complex::new(1.0, 0.0), EPSILON));
+        assert_eq!(a.clone() * a.conj(), Complex::new(5.0, 0.0))
+    }
+
+    #[test]
+    fn exp_and_log() {
+        // e^(i pi) = -1
+        let z = Complex::new(0.0, PI).exp();
+        assert!(z.approx_eq(&Complex::new(-1.0, 0.0), EPSILON));
+        let w = Complex::new(0.3, -2.0);
+        assert!(w.ln().exp().approx_eq(&w, EPSILON));
+        assert!(w.exp().ln().approx_eq(&w, EPSILON))
+    }
+
+    #[test]
+    fn tolerance() {
+        let a = Complex::new(1.0, 1.0);
+        let b = Complex::new(1.0 + 1e-12, 1.0);
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, EPSILON));
+        assert!(!a.approx_eq(&b, 1e-14));
+        assert!(vec![a.clone(), b.clone()].approx_eq(&vec![b, a], EPSILON))
     }
 }
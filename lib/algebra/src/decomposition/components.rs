@@ -0,0 +1,204 @@
+use super::PrimaryComponent;
+use crate::{
+    errors::Error,
+    field::Field,
+    ideal::PolynomialIdeal,
+    polynomials::{Factorization, UnivariatePolynomial},
+    projective::ProjectiveScheme,
+};
+
+impl<K> ProjectiveScheme<K>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    /// primary decomposition of the saturated ideal
+    /// components supported on the irrelevant ideal do not contribute to the scheme and are dropped
+    pub fn primary_decomposition(&self) -> Result<Vec<PrimaryComponent<K>>, Error> {
+        let irrelevant = PolynomialIdeal::maximal_at_origin(self.num_variables());
+        Ok(self
+            .saturated_ideal()
+            .primary_decomposition()?
+            .into_iter()
+            .filter(|component| !component.prime().contains_ideal(&irrelevant))
+            .collect())
+    }
+
+    /// the integral subschemes V(P) for the associated primes P
+    pub fn associated_primes(&self) -> Result<Vec<ProjectiveScheme<K>>, Error> {
+        self.primary_decomposition()?
+            .iter()
+            .map(|component| ProjectiveScheme::from_ideal(self.num_variables(), component.prime()))
+            .collect()
+    }
+
+    /// the irreducible components V(P) for the minimal primes P
+    /// with their multiplicities, the lengths of the local rings at their generic points
+    pub fn irreducible_components(&self) -> Result<Vec<(ProjectiveScheme<K>, usize)>, Error> {
+        self.primary_decomposition()?
+            .iter()
+            .filter_map(|component| {
+                let multiplicity = component.multiplicity()?;
+                Some(
+                    ProjectiveScheme::from_ideal(self.num_variables(), component.prime())
+                        .map(|scheme| (scheme, multiplicity)),
+                )
+            })
+            .collect()
+    }
+
+    /// the subschemes V(Q) for the primary components Q with embedded primes
+    /// their supports lie in the irreducible components
+    pub fn embedded_components(&self) -> Result<Vec<ProjectiveScheme<K>>, Error> {
+        self.primary_decomposition()?
+            .iter()
+            .filter(|component| component.is_embedded())
+            .map(|component| {
+                ProjectiveScheme::from_ideal(self.num_variables(), component.primary())
+            })
+            .collect()
+    }
+
+    /// the reduced scheme with the same points, cut out by the radical
+    pub fn reduced(&self) -> Result<ProjectiveScheme<K>, Error> {
+        ProjectiveScheme::from_ideal(self.num_variables(), &self.saturated_ideal().radical()?)
+    }
+
+    /// the saturated ideal is radical
+    pub fn is_reduced(&self) -> Result<bool, Error> {
+        Ok(self
+            .primary_decomposition()?
+            .iter()
+            .all(|component| component.primary() == component.prime()))
+    }
+
+    /// exactly one minimal prime
+    pub fn is_irreducible(&self) -> Result<bool, Error> {
+        Ok(self
+            .primary_decomposition()?
+            .iter()
+            .filter(|component| !component.is_embedded())
+            .count()
+            == 1)
+    }
+}
+
+#[cfg(test)]
+mod components_tests {
+    use crate::{
+        prime_field::PrimeField,
+        projective::ProjectiveMorphism,
+        rational::Rational,
+        test_helpers::{scheme, scheme_over},
+    };
+
+    #[test]
+    fn line_and_conic() {
+        // x (yz - x^2) is the union of a line and a conic in P^2
+        let cubic = scheme(vec![vec![(1, vec![1, 1, 1]), (-1, vec![3, 0, 0])]]);
+        let components = cubic.irreducible_components().unwrap();
+        assert_eq!(components.len(), 2);
+        let mut degrees: Vec<i64> = components
+            .iter()
+            .map(|(component, mult)| {
+                assert_eq!(*mult, 1);
+                component.degree()
+            })
+            .collect();
+        degrees.sort();
+        assert_eq!(degrees, vec![1, 2]);
+        assert!(cubic.is_reduced().unwrap());
+        assert!(!cubic.is_irreducible().unwrap());
+        assert!(cubic.embedded_components().unwrap().is_empty())
+    }
+
+    #[test]
+    fn embedded_point() {
+        // <x^2, xy> is the line x = 0 with an embedded point at [0:0:1]
+        let line = scheme(vec![vec![(1, vec![2, 0, 0])], vec![(1, vec![1, 1, 0])]]);
+        assert_eq!(line.associated_primes().unwrap().len(), 2);
+        let components = line.irreducible_components().unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].1, 1);
+        let embedded = line.embedded_components().unwrap();
+        assert_eq!(embedded.len(), 1);
+        assert_eq!(embedded[0].dim(), Some(0));
+        assert!(!line.is_reduced().unwrap());
+        assert!(line.is_irreducible().unwrap());
+        let reduced = line.reduced().unwrap();
+        assert_eq!(reduced.ideal_generators().len(), 1);
+        assert_eq!(reduced.degree(), 1)
+    }
+
+    #[test]
+    fn fat_line() {
+        // <x, y>^2 in P^3 is a line of multiplicity 3
+        let fat = scheme(vec![
+            vec![(1, vec![2, 0, 0, 0])],
+            vec![(1, vec![1, 1, 0, 0])],
+            vec![(1, vec![0, 2, 0, 0])],
+        ]);
+        let components = fat.irreducible_components().unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].1, 3);
+        assert_eq!(components[0].0.degree(), 1)
+    }
+
+    #[test]
+    fn components_depend_on_the_field() {
+        // x^2 + y^2 is a pair of lines over F_5 and an irreducible conic over F_7
+        let conic = vec![vec![(1, vec![2, 0, 0]), (1, vec![0, 2, 0])]];
+        let over_f5 = scheme_over::<PrimeField<5>>(conic.clone());
+        assert_eq!(over_f5.irreducible_components().unwrap().len(), 2);
+        let over_f7 = scheme_over::<PrimeField<7>>(conic);
+        assert!(over_f7.is_irreducible().unwrap());
+        assert!(over_f7.is_reduced().unwrap())
+    }
+
+    #[test]
+    fn quadric_and_sphere() {
+        // (xy - zw)(x^2 + y^2 + z^2 + w^2) in P^3 over Q, as over F_101
+        let quartic = vec![vec![
+            (1, vec![3, 1, 0, 0]),
+            (1, vec![1, 3, 0, 0]),
+            (1, vec![1, 1, 2, 0]),
+            (1, vec![1, 1, 0, 2]),
+            (-1, vec![2, 0, 1, 1]),
+            (-1, vec![0, 2, 1, 1]),
+            (-1, vec![0, 0, 3, 1]),
+            (-1, vec![0, 0, 1, 3]),
+        ]];
+        let rational = scheme(quartic.clone()).irreducible_components().unwrap();
+        assert_eq!(rational.len(), 2);
+        assert!(rational
+            .iter()
+            .all(|(component, mult)| *mult == 1 && component.degree() == 2));
+        let over_f101 = scheme_over::<PrimeField<101>>(quartic)
+            .irreducible_components()
+            .unwrap();
+        assert_eq!(over_f101.len(), 2);
+        assert!(over_f101
+            .iter()
+            .all(|(component, mult)| *mult == 1 && component.degree() == 2))
+    }
+
+    #[test]
+    fn twisted_cubic_and_secant() {
+        // the twisted cubic is integral, adding a secant line gives two components
+        let twisted = ProjectiveMorphism::<Rational>::veronese_embedding(1, 3).image();
+        assert!(twisted.is_irreducible().unwrap());
+        assert!(twisted.is_reduced().unwrap());
+        let secant = scheme(vec![
+            vec![(1, vec![0, 1, 0, 0])],
+            vec![(1, vec![0, 0, 1, 0])],
+        ]);
+        let union = twisted.union(&secant).unwrap();
+        assert!(union.is_reduced().unwrap());
+        // a rational curve and a line meeting in two points
+        assert_eq!(union.arithmetic_genus(), Some(1.into()));
+        let components = union.irreducible_components().unwrap();
+        assert_eq!(components.len(), 2);
+        assert!(components.iter().all(|(_, mult)| *mult == 1));
+        assert_eq!(components.iter().map(|(c, _)| c.degree()).sum::<i64>(), 4)
+    }
+}
@@ -0,0 +1,645 @@
+use crate::{
+    errors::Error,
+    field::Field,
+    groebner::{divide, make_monic},
+    group::AbelianGroup,
+    ideal::PolynomialIdeal,
+    polynomials::{Factorization, Monomial, MonomialOrder, Polynomial, UnivariatePolynomial},
+    projective::minors::subsets,
+    ring::Ring,
+};
+use std::collections::BTreeMap;
+
+/// largest degree of the univariate image in Kronecker's method
+pub const MAX_KRONECKER_DEGREE: usize = 256;
+
+/// changes of coordinates and evaluation points tried before falling back to Kronecker's method
+const MAX_TRIALS: usize = 16;
+
+impl<K> Polynomial<K>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    /// irreducible factors, monic for GRevLex, with multiplicities
+    /// such that f = c prod g^e for a constant c
+    /// forms are dehomogenized at X_0 first, so their factors are forms again
+    pub fn factor(&self) -> Result<Vec<(Polynomial<K>, usize)>, Error> {
+        let dim = self.dim();
+        let mut factors: Vec<(Polynomial<K>, usize)> = vec![];
+        if self.is_zero() {
+            return Ok(factors);
+        }
+        let content: Vec<usize> = (0..dim)
+            .map(|i| {
+                self.terms()
+                    .iter()
+                    .map(|term| term.exponents().get(i).copied().unwrap_or(0))
+                    .min()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for (i, power) in content.iter().enumerate() {
+            if *power > 0 {
+                factors.push((Polynomial::variable(i, dim), *power));
+            }
+        }
+        let f = Polynomial::new(
+            self.terms()
+                .iter()
+                .map(|term| {
+                    let powers = (0..dim)
+                        .map(|i| term.exponents().get(i).copied().unwrap_or(0) - content[i])
+                        .collect();
+                    Monomial::new(term.coefficient.clone(), powers)
+                })
+                .collect(),
+        )
+        .with_dim(dim);
+        let degrees: Vec<usize> = f.terms().iter().map(term_degree).collect();
+        let homogeneous = degrees.iter().all(|deg| *deg == degrees[0]);
+        let irreducible = if homogeneous && dim > 1 {
+            irreducible_factors(&dehomogenize(&f))?
+                .iter()
+                .map(rehomogenize)
+                .collect()
+        } else {
+            irreducible_factors(&f)?
+        };
+        for g in irreducible {
+            let g = make_monic(g, MonomialOrder::GRevLex);
+            match factors.iter_mut().find(|(factor, _)| *factor == g) {
+                Some((_, mult)) => *mult += 1,
+                None => factors.push((g, 1)),
+            }
+        }
+        Ok(factors)
+    }
+
+    /// product of the distinct irreducible factors
+    pub fn squarefree_part(&self) -> Result<Polynomial<K>, Error> {
+        Ok(self.factor()?.into_iter().fold(
+            Polynomial::constant(<K as Field>::one()).with_dim(self.dim()),
+            |product, (g, _)| product * g,
+        ))
+    }
+}
+
+fn term_degree<K: Field>(term: &Monomial<K>) -> usize {
+    term.exponents().iter().sum()
+}
+
+/// f(1, X_1, ..., X_(n-1)), keeping X_0 as a variable of power 0
+fn dehomogenize<K: Field + Clone>(f: &Polynomial<K>) -> Polynomial<K> {
+    let monomials = f
+        .terms()
+        .iter()
+        .map(|term| {
+            let mut powers = term.clone().with_dim(f.dim()).powers();
+            powers[0] = 0;
+            Monomial::new(term.coefficient.clone(), powers)
+        })
+        .collect();
+    Polynomial::new(monomials).with_dim(f.dim())
+}
+
+/// the form X_0^deg g(X_1/X_0, ..., X_(n-1)/X_0)
+fn rehomogenize<K: Field + Clone>(g: &Polynomial<K>) -> Polynomial<K> {
+    let deg = g.total_degree();
+    let monomials = g
+        .terms()
+        .iter()
+        .map(|term| {
+            let mut powers = term.clone().with_dim(g.dim()).powers();
+            powers[0] = deg - term_degree(term);
+            Monomial::new(term.coefficient.clone(), powers)
+        })
+        .collect();
+    Polynomial::new(monomials).with_dim(g.dim())
+}
+
+/// irreducible factors repeated by multiplicity, f must not be divisible by a variable
+/// by Hensel lifting if the field has enough elements, by Kronecker's method otherwise
+fn irreducible_factors<K>(f: &Polynomial<K>) -> Result<Vec<Polynomial<K>>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    if f.total_degree() == 0 {
+        return Ok(vec![]);
+    }
+    if let Some(factors) = hensel_factors(f)? {
+        return Ok(factors);
+    }
+    kronecker_factors(f)
+}
+
+/// the distinct irreducible factors of the squarefree part f / gcd(f, df/dX_0, ..., df/dX_(n-1))
+/// are lifted, then their multiplicities are found by trial division
+/// None if the squarefree part is wrong, which can happen in positive characteristic,
+/// or if no change of coordinates or evaluation point was found
+fn hensel_factors<K>(f: &Polynomial<K>) -> Result<Option<Vec<Polynomial<K>>>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    let common = (0..f.dim())
+        .map(|i| f.partial_derivative(i))
+        .filter(|derivative| !derivative.is_zero())
+        .fold(f.clone(), |common, derivative| gcd(&common, &derivative));
+    let squarefree = exact_quotient(f, &common).expect("the gcd divides f");
+    if squarefree.total_degree() == 0 {
+        return Ok(None);
+    }
+    let Some(distinct) = lifted_factors(&squarefree)? else {
+        return Ok(None);
+    };
+    let mut remaining = f.clone();
+    let mut factors = vec![];
+    for g in distinct {
+        while let Some(quotient) = exact_quotient(&remaining, &g) {
+            factors.push(g.clone());
+            remaining = quotient;
+        }
+    }
+    Ok((remaining.total_degree() == 0).then_some(factors))
+}
+
+/// gcd up to a constant, from <f> ∩ <g> = <lcm(f, g)>
+fn gcd<K: Field + Clone>(f: &Polynomial<K>, g: &Polynomial<K>) -> Polynomial<K> {
+    let dim = f.dim().max(g.dim());
+    let intersection = PolynomialIdeal::with_dim(vec![f.clone()], dim)
+        .intersection(&PolynomialIdeal::with_dim(vec![g.clone()], dim));
+    let lcm = intersection
+        .groebner_basis()
+        .basis()
+        .iter()
+        .min_by_key(|h| h.total_degree())
+        .expect("nonzero polynomials have a nonzero lcm")
+        .clone();
+    exact_quotient(&(f.clone() * g.clone()), &lcm).expect("the lcm divides f g")
+}
+
+/// f / g if g divides f
+fn exact_quotient<K: Field + Clone>(f: &Polynomial<K>, g: &Polynomial<K>) -> Option<Polynomial<K>> {
+    let (mut quotients, remainder) = divide(f, std::slice::from_ref(g), MonomialOrder::GRevLex);
+    remainder.is_zero().then(|| quotients.remove(0))
+}
+
+/// the element 1 + ... + 1 with a summands
+fn small<K: Field + Clone>(a: usize) -> K {
+    (0..a).fold(K::zero(), |acc, _| acc + <K as Field>::one())
+}
+
+/// sum of the exponents of all variables except X_v
+fn other_degree<K: Field>(term: &Monomial<K>, v: usize) -> usize {
+    term_degree(term) - term.exponents().get(v).copied().unwrap_or(0)
+}
+
+/// the terms whose degree in the variables except X_v is at most deg
+fn truncate<K: Field + Clone>(f: Polynomial<K>, v: usize, deg: usize) -> Polynomial<K> {
+    let dim = f.dim();
+    Polynomial::new(
+        f.into_terms()
+            .into_iter()
+            .filter(|term| other_degree(term, v) <= deg)
+            .collect(),
+    )
+    .with_dim(dim)
+}
+
+/// g(X_v) as polynomial in dim variables
+fn from_univariate<K: Field + Clone>(
+    g: &UnivariatePolynomial<K>,
+    v: usize,
+    dim: usize,
+) -> Polynomial<K> {
+    let monomials = g
+        .coefficients()
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| **c != K::zero())
+        .map(|(deg, c)| {
+            let mut powers = vec![0; dim];
+            powers[v] = deg;
+            Monomial::new(c.clone(), powers)
+        })
+        .collect();
+    Polynomial::new(monomials).with_dim(dim)
+}
+
+/// the coefficients of f in K[X_v] of the monomials in the other variables of degree deg
+fn coefficients_of_degree<K: Field + Clone>(
+    f: &Polynomial<K>,
+    v: usize,
+    deg: usize,
+) -> BTreeMap<Vec<usize>, UnivariatePolynomial<K>> {
+    let mut coefficients: BTreeMap<Vec<usize>, UnivariatePolynomial<K>> = BTreeMap::new();
+    for term in f.terms().iter().filter(|term| other_degree(term, v) == deg) {
+        let mut powers = term.clone().with_dim(f.dim()).powers();
+        let power = std::mem::replace(&mut powers[v], 0);
+        let monomial = UnivariatePolynomial::monomial(term.coefficient.clone(), power);
+        let coefficient = coefficients
+            .entry(powers)
+            .or_insert_with(UnivariatePolynomial::zero);
+        *coefficient = coefficient.clone() + monomial;
+    }
+    coefficients
+}
+
+/// Hensel lifting of the factors of a squarefree f
+/// after X_i -> X_i + c_i X_v the form of top degree d gives a nonzero multiple of X_v^d,
+/// so f is monic of degree d in X_v and every factor is monic in X_v as well
+/// after X_i -> X_i + a_i the image f(X_v, 0) is squarefree,
+/// its irreducible factors are lifted modulo the powers of <X_i | i != v>
+/// and recombined by trial division as in Zassenhaus' algorithm
+fn lifted_factors<K>(f: &Polynomial<K>) -> Result<Option<Vec<Polynomial<K>>>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    let dim = f.dim();
+    let d = f.total_degree();
+    let v = (0..dim)
+        .find(|i| f.degree_in(*i) > 0)
+        .expect("nonconstant polynomials have a variable");
+    let variables: Vec<Polynomial<K>> = (0..dim).map(|i| Polynomial::variable(i, dim)).collect();
+    let substitution = |shifts: &[Polynomial<K>]| -> Vec<Polynomial<K>> {
+        variables
+            .iter()
+            .zip(shifts.iter())
+            .enumerate()
+            .map(|(i, (var, shift))| match i == v {
+                true => var.clone(),
+                false => var.clone() + shift.clone(),
+            })
+            .collect()
+    };
+    let substitute = |g: &Polynomial<K>, values: &[Polynomial<K>]| {
+        g.substitute(values)
+            .expect("one value for each variable")
+            .with_dim(dim)
+    };
+
+    // X_i -> X_i + c_i X_v and X_i -> X_i + a_i with c_i = c^(i+1) and a_i = a^(i+1)
+    // for c, a = 0, 1, 2, ..., 0 first
+    let powers = |trial: usize| -> Vec<K> {
+        let base: K = small(trial);
+        let mut power = base.clone();
+        (0..dim)
+            .map(|_| {
+                let res = power.clone();
+                power = power.clone() * base.clone();
+                res
+            })
+            .collect()
+    };
+    let found = (0..MAX_TRIALS).find_map(|trial| {
+        let change: Vec<Polynomial<K>> = powers(trial)
+            .into_iter()
+            .map(|c| variables[v].clone().scale(c))
+            .collect();
+        let changed = substitute(f, &substitution(&change));
+        let mut top = vec![0; dim];
+        top[v] = d;
+        let lead = changed
+            .terms()
+            .iter()
+            .find(|term| (*term).clone().with_dim(dim).powers() == top)?
+            .coefficient
+            .clone();
+        let monic = changed.scale(lead.inverse());
+        (0..MAX_TRIALS).find_map(|trial| {
+            let point: Vec<Polynomial<K>> = powers(trial)
+                .into_iter()
+                .map(|a| Polynomial::constant(a).with_dim(dim))
+                .collect();
+            let mut values = point.clone();
+            values[v] = variables[v].clone();
+            let image =
+                coefficients_of_degree(&substitute(&monic, &values), v, 0).remove(&vec![0; dim])?;
+            (image.gcd(&image.derivative()) == UnivariatePolynomial::one())
+                .then(|| (change.clone(), point, monic.clone(), image))
+        })
+    });
+    let Some((change, point, monic, image)) = found else {
+        return Ok(None);
+    };
+    let shifted = substitute(&monic, &substitution(&point));
+
+    let modular: Vec<UnivariatePolynomial<K>> =
+        image.factor()?.into_iter().map(|(g, _)| g).collect();
+    if modular.len() == 1 {
+        return Ok(Some(vec![f.clone()]));
+    }
+    // t_j prod_(i != j) g_i = 1 mod g_j, so that sum_j t_j prod_(i != j) g_i = 1
+    let inverses: Vec<UnivariatePolynomial<K>> = (0..modular.len())
+        .map(|j| {
+            let others = modular
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != j)
+                .fold(UnivariatePolynomial::one(), |product, (_, g)| {
+                    product * g.clone()
+                });
+            let (_, t, _) = others.xgcd(&modular[j]);
+            t
+        })
+        .collect();
+
+    // f = prod G_j modulo the degree k + 1 part, corrected by the degree k part of the error
+    let bound = shifted
+        .terms()
+        .iter()
+        .map(|term| other_degree(term, v))
+        .max()
+        .unwrap_or(0);
+    let mut lifted: Vec<Polynomial<K>> =
+        modular.iter().map(|g| from_univariate(g, v, dim)).collect();
+    for k in 1..=bound {
+        let product = lifted.iter().fold(
+            Polynomial::constant(<K as Field>::one()).with_dim(dim),
+            |product, g| truncate(product * g.clone(), v, k),
+        );
+        let error = coefficients_of_degree(&(shifted.clone() - product), v, k);
+        for (j, g) in lifted.iter_mut().enumerate() {
+            for (powers, coefficient) in error.iter() {
+                let correction = (inverses[j].clone() * coefficient.clone())
+                    .rem(&modular[j])
+                    .expect("irreducible factors are nonzero");
+                *g = g.clone()
+                    + from_univariate(&correction, v, dim)
+                        .mul_monomial(&Monomial::new(<K as Field>::one(), powers.clone()));
+            }
+        }
+    }
+
+    let mut remaining = shifted;
+    let mut factors = vec![];
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let found = subsets(lifted.len(), size).into_iter().find_map(|subset| {
+            let candidate = subset.iter().fold(
+                Polynomial::constant(<K as Field>::one()).with_dim(dim),
+                |product, j| truncate(product * lifted[*j].clone(), v, bound),
+            );
+            let quotient = exact_quotient(&remaining, &candidate)?;
+            Some((subset, candidate, quotient))
+        });
+        match found {
+            Some((subset, candidate, quotient)) => {
+                for j in subset.into_iter().rev() {
+                    lifted.remove(j);
+                }
+                factors.push(candidate);
+                remaining = quotient;
+            }
+            None => size += 1,
+        }
+    }
+    factors.push(remaining);
+
+    // undo X_i -> X_i + a_i and X_i -> X_i + c_i X_v
+    let negate = |shifts: &[Polynomial<K>]| -> Vec<Polynomial<K>> {
+        substitution(
+            &shifts
+                .iter()
+                .map(|shift| -shift.clone())
+                .collect::<Vec<_>>(),
+        )
+    };
+    Ok(Some(
+        factors
+            .iter()
+            .map(|g| substitute(&substitute(g, &negate(&point)), &negate(&change)))
+            .collect(),
+    ))
+}
+
+/// Kronecker's method: the substitution X_i -> t^(w_i) with w_i = prod_(j < i) (deg_j f + 1)
+/// is injective on polynomials of smaller degrees, so every factor of f is the preimage
+/// of a product of irreducible factors of the univariate image
+/// the candidates are tried by trial division, which is exponential in the number of factors
+/// returns the irreducible factors repeated by multiplicity, f must not be divisible by a variable
+/// fails if the univariate image has degree above MAX_KRONECKER_DEGREE
+fn kronecker_factors<K>(f: &Polynomial<K>) -> Result<Vec<Polynomial<K>>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    if f.total_degree() == 0 {
+        return Ok(vec![]);
+    }
+    let dim = f.dim();
+    let bounds: Vec<usize> = (0..dim).map(|i| f.degree_in(i) + 1).collect();
+    let weights: Vec<usize> = (0..dim)
+        .map(|i| bounds[..i].iter().product::<usize>())
+        .collect();
+    let degree = bounds.iter().product::<usize>() - 1;
+    if degree > MAX_KRONECKER_DEGREE {
+        return Err(Error::DegreeTooLarge {
+            found: degree,
+            max: MAX_KRONECKER_DEGREE,
+        });
+    }
+    let image = f
+        .terms()
+        .iter()
+        .fold(UnivariatePolynomial::zero(), |image, term| {
+            let deg = (0..dim)
+                .map(|i| term.exponents().get(i).copied().unwrap_or(0) * weights[i])
+                .sum();
+            image + UnivariatePolynomial::monomial(term.coefficient.clone(), deg)
+        });
+    let mut pieces: Vec<UnivariatePolynomial<K>> = image
+        .factor()?
+        .into_iter()
+        .flat_map(|(g, mult)| std::iter::repeat_n(g, mult))
+        .collect();
+    let mut remaining = f.clone();
+    let mut factors = vec![];
+    let mut size = 1;
+    while 2 * size <= pieces.len() {
+        let found = subsets(pieces.len(), size).into_iter().find_map(|subset| {
+            let product = subset
+                .iter()
+                .fold(UnivariatePolynomial::one(), |product, i| {
+                    product * pieces[*i].clone()
+                });
+            let candidate = inverse_substitution(&product, &bounds, &weights)?;
+            let (mut quotients, remainder) = divide(
+                &remaining,
+                std::slice::from_ref(&candidate),
+                MonomialOrder::GRevLex,
+            );
+            remainder
+                .is_zero()
+                .then(|| (subset, candidate, quotients.remove(0)))
+        });
+        match found {
+            Some((subset, candidate, quotient)) => {
+                for i in subset.into_iter().rev() {
+                    pieces.remove(i);
+                }
+                factors.push(candidate);
+                remaining = quotient;
+            }
+            None => size += 1,
+        }
+    }
+    if remaining.total_degree() > 0 {
+        factors.push(remaining);
+    }
+    Ok(factors)
+}
+
+/// preimage of the Kronecker substitution, None if a power exceeds its bound
+fn inverse_substitution<K: Field + Clone>(
+    g: &UnivariatePolynomial<K>,
+    bounds: &[usize],
+    weights: &[usize],
+) -> Option<Polynomial<K>> {
+    let dim = bounds.len();
+    let mut monomials = vec![];
+    for (deg, coefficient) in g.coefficients().iter().enumerate() {
+        if *coefficient == K::zero() {
+            continue;
+        }
+        let powers: Vec<usize> = (0..dim).map(|i| deg / weights[i] % bounds[i]).collect();
+        if dim == 0 || deg / weights[dim - 1] >= bounds[dim - 1] {
+            return None;
+        }
+        monomials.push(Monomial::new(coefficient.clone(), powers));
+    }
+    Some(Polynomial::new(monomials).with_dim(dim))
+}
+
+#[cfg(test)]
+mod factor_tests {
+    use crate::{
+        groebner::make_monic,
+        polynomials::{Monomial, MonomialOrder, Polynomial},
+        prime_field::PrimeField,
+        rational::Rational,
+        test_helpers::poly,
+    };
+
+    #[test]
+    fn multivariate_factors() {
+        // x^2 y - y^3 = y (x - y) (x + y)
+        let f = poly(vec![(1, vec![2, 1]), (-1, vec![0, 3])]);
+        let factors = f.factor().unwrap();
+        assert_eq!(factors.len(), 3);
+        assert!(factors.iter().all(|(_, mult)| *mult == 1));
+        let product = factors
+            .iter()
+            .fold(poly(vec![(1, vec![0, 0])]), |acc, (g, _)| acc * g.clone());
+        assert_eq!(product, f);
+        // (y z - x)^2 (x + 1) is not homogeneous
+        let g = poly(vec![(-1, vec![1, 0, 0]), (1, vec![0, 1, 1])]);
+        let h = poly(vec![(1, vec![1, 0, 0]), (1, vec![0, 0, 0])]);
+        let factors = (g.clone() * g.clone() * h.clone()).factor().unwrap();
+        assert_eq!(factors.len(), 2);
+        assert!(factors.contains(&(g.clone(), 2)));
+        assert!(factors.contains(&(h, 1)));
+        assert_eq!(
+            (g.clone() * g.clone() * g)
+                .squarefree_part()
+                .unwrap()
+                .total_degree(),
+            2
+        )
+    }
+
+    #[test]
+    fn factors_depend_on_the_field() {
+        // x^2 + y^2 is irreducible over Q and F_7, but splits over F_5
+        let rational = poly(vec![(1, vec![2, 0]), (1, vec![0, 2])]);
+        assert_eq!(rational.factor().unwrap().len(), 1);
+        let over = |terms: Vec<(i64, Vec<usize>)>| -> usize {
+            Polynomial::<PrimeField<5>>::new(
+                terms
+                    .into_iter()
+                    .map(|(coeff, powers)| Monomial::new(coeff.into(), powers))
+                    .collect(),
+            )
+            .factor()
+            .unwrap()
+            .len()
+        };
+        assert_eq!(over(vec![(1, vec![2, 0]), (1, vec![0, 2])]), 2);
+        let seven = Polynomial::<PrimeField<7>>::new(vec![
+            Monomial::new(1.into(), vec![2, 0]),
+            Monomial::new(1.into(), vec![0, 2]),
+        ]);
+        assert_eq!(seven.factor().unwrap().len(), 1)
+    }
+
+    #[test]
+    fn several_variables_over_the_rationals() {
+        // (xy - zw)(x^2 + y^2 + z^2 + w^2)(x + 2y - w)^2 in four variables
+        let quadric = poly(vec![(1, vec![1, 1, 0, 0]), (-1, vec![0, 0, 1, 1])]);
+        let sphere = poly(vec![
+            (1, vec![2, 0, 0, 0]),
+            (1, vec![0, 2, 0, 0]),
+            (1, vec![0, 0, 2, 0]),
+            (1, vec![0, 0, 0, 2]),
+        ]);
+        let plane = poly(vec![
+            (1, vec![1, 0, 0, 0]),
+            (2, vec![0, 1, 0, 0]),
+            (-1, vec![0, 0, 0, 1]),
+        ]);
+        let f = quadric.clone() * sphere.clone() * plane.clone() * plane.clone();
+        let factors = f.factor().unwrap();
+        assert_eq!(factors.len(), 3);
+        let monic = |g: Polynomial<Rational>| make_monic(g, MonomialOrder::GRevLex);
+        assert!(factors.contains(&(monic(quadric), 1)));
+        assert!(factors.contains(&(monic(sphere), 1)));
+        assert!(factors.contains(&(monic(plane), 2)));
+        // (x - y + 1)(x z - y^2 + 3 z)^2 is not homogeneous
+        let g = poly(vec![
+            (1, vec![1, 0, 0]),
+            (-1, vec![0, 1, 0]),
+            (1, vec![0, 0, 0]),
+        ]);
+        let h = poly(vec![
+            (1, vec![1, 0, 1]),
+            (-1, vec![0, 2, 0]),
+            (3, vec![0, 0, 1]),
+        ]);
+        let factors = (g.clone() * h.clone() * h.clone()).factor().unwrap();
+        assert_eq!(factors.len(), 2);
+        assert!(factors.contains(&(monic(g), 1)));
+        assert!(factors.contains(&(monic(h), 2)))
+    }
+
+    #[test]
+    fn small_fields() {
+        let over = |terms: Vec<(i64, Vec<usize>)>| {
+            Polynomial::<PrimeField<2>>::new(
+                terms
+                    .into_iter()
+                    .map(|(coeff, powers)| Monomial::new(coeff.into(), powers))
+                    .collect(),
+            )
+        };
+        // (x + y)^2 = x^2 + y^2 has no nonzero partial derivative over F_2
+        let square = over(vec![(1, vec![2, 0]), (1, vec![0, 2])]);
+        assert_eq!(
+            square.factor().unwrap(),
+            vec![(over(vec![(1, vec![1, 0]), (1, vec![0, 1])]), 2)]
+        );
+        // (x y + z)(x + y + z + 1) with only two points to evaluate at
+        let g = over(vec![(1, vec![1, 1, 0]), (1, vec![0, 0, 1])]);
+        let h = over(vec![
+            (1, vec![1, 0, 0]),
+            (1, vec![0, 1, 0]),
+            (1, vec![0, 0, 1]),
+            (1, vec![0, 0, 0]),
+        ]);
+        let factors = (g * h).factor().unwrap();
+        assert_eq!(factors.len(), 2);
+        assert!(factors.iter().all(|(_, mult)| *mult == 1))
+    }
+}
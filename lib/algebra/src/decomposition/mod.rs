@@ -0,0 +1,5 @@
+pub mod components;
+pub mod factor;
+pub mod primary;
+
+pub use primary::PrimaryComponent;
@@ -0,0 +1,493 @@
+use crate::{
+    errors::Error,
+    field::Field,
+    groebner::GroebnerBasis,
+    group::AbelianGroup,
+    ideal::PolynomialIdeal,
+    polynomials::{Factorization, Monomial, MonomialOrder, Polynomial, UnivariatePolynomial},
+    projective::minors::subsets,
+    ring::Ring,
+};
+
+/// number of linear forms tried before giving up on a zero dimensional ideal
+const MAX_TRIALS: i64 = 16;
+
+/// Primary ideal Q of a primary decomposition together with its associated prime P = rad Q
+#[derive(Clone, Debug)]
+pub struct PrimaryComponent<K: Field> {
+    primary: PolynomialIdeal<K>,
+    prime: PolynomialIdeal<K>,
+    embedded: bool,
+}
+
+impl<K: Field + Clone> PrimaryComponent<K> {
+    pub fn primary(&self) -> &PolynomialIdeal<K> {
+        &self.primary
+    }
+
+    pub fn prime(&self) -> &PolynomialIdeal<K> {
+        &self.prime
+    }
+
+    /// P strictly contains the prime of another component
+    pub fn is_embedded(&self) -> bool {
+        self.embedded
+    }
+
+    /// length of the local ring at P for minimal primes, deg Q / deg P
+    /// None for embedded components, whose length is not well defined
+    pub fn multiplicity(&self) -> Option<usize> {
+        if self.embedded {
+            return None;
+        }
+        let degree = |ideal: &PolynomialIdeal<K>| ideal.hilbert_series().degree();
+        Some((degree(&self.primary) / degree(&self.prime)) as usize)
+    }
+}
+
+impl<K: Field + Clone> PolynomialIdeal<K> {
+    /// a maximal set U of variables with I ∩ K[U] = 0, so K[X]/I is finite over K(U)
+    /// found among the sets avoiding all leading monomials, its size is the Krull dimension
+    /// None for the unit ideal
+    pub fn independent_variables(&self) -> Option<Vec<usize>> {
+        let leading: Vec<Vec<usize>> = self
+            .groebner_basis()
+            .leading_terms()
+            .iter()
+            .map(|term| term.powers())
+            .collect();
+        (0..=self.dim()).rev().find_map(|size| {
+            subsets(self.dim(), size).into_iter().find(|subset| {
+                leading.iter().all(|powers| {
+                    powers
+                        .iter()
+                        .enumerate()
+                        .any(|(i, power)| *power > 0 && !subset.contains(&i))
+                })
+            })
+        })
+    }
+}
+
+impl<K> PolynomialIdeal<K>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    /// irredundant primary decomposition I = Q_1 ∩ ... ∩ Q_r with distinct associated primes
+    /// by the algorithm of Gianni, Trager and Zacharias:
+    /// for independent variables U, I K(U)[X] is zero dimensional and decomposes
+    /// by factoring the minimal polynomial of a generic linear form,
+    /// the rest I + <h^s> with h in K[U] is decomposed recursively
+    /// in characteristic p inseparable extensions of K(U) are not handled
+    /// fails over small finite fields if no linear form separates the points
+    pub fn primary_decomposition(&self) -> Result<Vec<PrimaryComponent<K>>, Error> {
+        let mut components: Vec<(PolynomialIdeal<K>, PolynomialIdeal<K>)> = vec![];
+        for (primary, prime) in decompose(self)? {
+            match components.iter_mut().find(|(_, other)| *other == prime) {
+                Some((existing, _)) => *existing = existing.intersection(&primary),
+                None => components.push((primary, prime)),
+            }
+        }
+        // minimal components are never redundant, the embedded ones tend to come last
+        for i in (0..components.len()).rev() {
+            let others = components
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, (primary, _))| primary.clone())
+                .reduce(|acc, primary| acc.intersection(&primary));
+            if others.is_some_and(|others| components[i].0.contains_ideal(&others)) {
+                components.remove(i);
+            }
+        }
+        let primes: Vec<PolynomialIdeal<K>> =
+            components.iter().map(|(_, prime)| prime.clone()).collect();
+        Ok(components
+            .into_iter()
+            .enumerate()
+            .map(|(i, (primary, prime))| PrimaryComponent {
+                embedded: primes
+                    .iter()
+                    .enumerate()
+                    .any(|(j, other)| j != i && prime.contains_ideal(other)),
+                primary,
+                prime,
+            })
+            .collect())
+    }
+
+    /// the primes P_i = rad Q_i of a primary decomposition
+    pub fn associated_primes(&self) -> Result<Vec<PolynomialIdeal<K>>, Error> {
+        Ok(self
+            .primary_decomposition()?
+            .into_iter()
+            .map(|component| component.prime)
+            .collect())
+    }
+
+    /// the associated primes that are minimal over I
+    pub fn minimal_primes(&self) -> Result<Vec<PolynomialIdeal<K>>, Error> {
+        Ok(self
+            .primary_decomposition()?
+            .into_iter()
+            .filter(|component| !component.embedded)
+            .map(|component| component.prime)
+            .collect())
+    }
+
+    /// rad I as the intersection of the minimal primes
+    pub fn radical(&self) -> Result<PolynomialIdeal<K>, Error> {
+        Ok(self
+            .minimal_primes()?
+            .into_iter()
+            .reduce(|acc, prime| acc.intersection(&prime))
+            .unwrap_or_else(|| PolynomialIdeal::unit(self.dim())))
+    }
+
+    pub fn is_primary(&self) -> Result<bool, Error> {
+        Ok(self.primary_decomposition()?.len() == 1)
+    }
+
+    pub fn is_prime(&self) -> Result<bool, Error> {
+        let components = self.primary_decomposition()?;
+        Ok(components.len() == 1 && components[0].primary == components[0].prime)
+    }
+}
+
+/// primary ideals with their primes, possibly redundant, whose intersection is I
+type Components<K> = Vec<(PolynomialIdeal<K>, PolynomialIdeal<K>)>;
+
+fn decompose<K>(ideal: &PolynomialIdeal<K>) -> Result<Components<K>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    let independent = match ideal.independent_variables() {
+        Some(independent) => independent,
+        None => return Ok(vec![]),
+    };
+    let n = ideal.dim();
+    // the dependent variables come first, so the block order eliminates them
+    let mut order: Vec<usize> = (0..n).filter(|i| !independent.contains(i)).collect();
+    let k = order.len();
+    order.extend(independent.iter());
+    let reordered = permute_ideal(ideal, &order);
+    let (saturated, h) = contract(&reordered, k);
+    let mut components = zero_dimensional(&saturated, k)?;
+    if h.total_degree() > 0 {
+        // I = (I : h^∞) ∩ (I + <h^s>) as soon as I : h^s = I : h^∞
+        let mut power = h.clone();
+        while !reordered
+            .quotient_element(&power)
+            .contains_ideal(&saturated)
+        {
+            power = power * h.clone();
+        }
+        let rest = reordered.sum(&PolynomialIdeal::with_dim(vec![power], n));
+        components.extend(decompose(&rest)?);
+    }
+    let mut inverse = vec![0; n];
+    for (j, i) in order.iter().enumerate() {
+        inverse[*i] = j;
+    }
+    Ok(components
+        .into_iter()
+        .map(|(primary, prime)| {
+            (
+                permute_ideal(&primary, &inverse),
+                permute_ideal(&prime, &inverse),
+            )
+        })
+        .collect())
+}
+
+/// decomposition of an ideal J = J K(U)[X] ∩ K[X] which is zero dimensional over K(U)
+/// for the dependent variables X_0, ..., X_(k-1) and the independent ones after them
+/// if a linear form l separates the points, its minimal polynomial prod p_i^e_i
+/// gives the primary components J + <p_i(l)^e_i> and the primes rad J + <p_i(l)>
+fn zero_dimensional<K>(ideal: &PolynomialIdeal<K>, k: usize) -> Result<Components<K>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    let n = ideal.dim();
+    if k == 0 {
+        return Ok(vec![(ideal.clone(), ideal.clone())]);
+    }
+    // Seidenberg: adding the squarefree parts of the eliminants of all variables gives the radical
+    let squarefree: Vec<Polynomial<K>> = (0..k)
+        .map(|i| {
+            let var = Polynomial::variable(i, n);
+            let factors = eliminant_factors(ideal, k, &var)?;
+            let product = factors.into_iter().fold(
+                Polynomial::constant(<K as Field>::one()).with_dim(n - k + 1),
+                |product, (p, _)| product * p,
+            );
+            Ok(in_terms_of(&product, &var, k))
+        })
+        .collect::<Result<_, Error>>()?;
+    let (radical, _) = contract(&ideal.sum(&PolynomialIdeal::with_dim(squarefree, n)), k);
+    let points = residue_dimension(&radical, k);
+    for trial in 0..MAX_TRIALS {
+        let form = linear_form(trial, k, n);
+        let factors = eliminant_factors(ideal, k, &form)?;
+        let separated: usize = factors.iter().map(|(p, _)| p.degree_in(n - k)).sum();
+        if separated != points {
+            continue;
+        }
+        return Ok(factors
+            .into_iter()
+            .map(|(p, mult)| {
+                let p = in_terms_of(&p, &form, k);
+                let power = PolynomialIdeal::with_dim(vec![p.clone().pow(mult)], n);
+                let prime = PolynomialIdeal::with_dim(vec![p], n);
+                (
+                    contract(&ideal.sum(&power), k).0,
+                    contract(&radical.sum(&prime), k).0,
+                )
+            })
+            .collect());
+    }
+    Err(Error::NoGenericLinearForm)
+}
+
+/// L K(U)[X] ∩ K[X] = L : h^∞ for the product h of the leading coefficients in K[U]
+/// of a Groebner basis for the block order with the dependent variables X_0, ..., X_(k-1) first
+fn contract<K: Field + Clone>(
+    ideal: &PolynomialIdeal<K>,
+    k: usize,
+) -> (PolynomialIdeal<K>, Polynomial<K>) {
+    let n = ideal.dim();
+    let order = MonomialOrder::Elimination(k);
+    let basis = GroebnerBasis::new(ideal.generators(), order);
+    let mut coefficients: Vec<Polynomial<K>> = vec![];
+    for g in basis.basis() {
+        let lead = g.leading_term(order).expect("Groebner bases have no zeros");
+        let dependent = &lead.exponents()[..k.min(lead.exponents().len())];
+        let coefficient = Polynomial::new(
+            g.terms()
+                .iter()
+                .filter(|term| {
+                    (0..k).all(|i| {
+                        term.exponents().get(i).copied().unwrap_or(0)
+                            == dependent.get(i).copied().unwrap_or(0)
+                    })
+                })
+                .map(|term| {
+                    let mut powers = term.clone().with_dim(n).powers();
+                    powers[..k].iter_mut().for_each(|power| *power = 0);
+                    Monomial::new(term.coefficient.clone(), powers)
+                })
+                .collect(),
+        )
+        .with_dim(n);
+        if coefficient.total_degree() > 0 && !coefficients.contains(&coefficient) {
+            coefficients.push(coefficient);
+        }
+    }
+    let h = coefficients.into_iter().fold(
+        Polynomial::constant(<K as Field>::one()).with_dim(n),
+        |h, coefficient| h * coefficient,
+    );
+    if h.total_degree() == 0 {
+        return (ideal.clone(), h);
+    }
+    (ideal.saturation_element(&h), h)
+}
+
+/// irreducible factors p(U, t) with t-degree > 0 of the generator of (J + <t - l>) ∩ K[U, t]
+/// this elimination ideal is principal, since J is the contraction of J K(U)[X],
+/// so it is generated by the minimal polynomial of l over K(U) with the denominators cleared
+fn eliminant_factors<K>(
+    ideal: &PolynomialIdeal<K>,
+    k: usize,
+    form: &Polynomial<K>,
+) -> Result<Vec<(Polynomial<K>, usize)>, Error>
+where
+    K: Field + Clone,
+    UnivariatePolynomial<K>: Factorization,
+{
+    let n = ideal.dim();
+    let mut generators: Vec<Polynomial<K>> = ideal
+        .generators()
+        .into_iter()
+        .map(|g| g.with_dim(n + 1))
+        .collect();
+    generators.push(Polynomial::variable(n, n + 1) - form.clone().with_dim(n + 1));
+    let eliminant = PolynomialIdeal::with_dim(generators, n + 1)
+        .eliminate(k)
+        .generators()
+        .into_iter()
+        .min_by_key(|g| g.total_degree())
+        .expect("zero dimensional ideals have eliminants");
+    Ok(eliminant
+        .with_dim(n - k + 1)
+        .factor()?
+        .into_iter()
+        .filter(|(p, _)| p.degree_in(n - k) > 0)
+        .collect())
+}
+
+/// p(X_k, ..., X_(n-1), l) for p in K[U, t]
+fn in_terms_of<K: Field + Clone>(
+    p: &Polynomial<K>,
+    form: &Polynomial<K>,
+    k: usize,
+) -> Polynomial<K> {
+    let n = form.dim();
+    let mut values: Vec<Polynomial<K>> = (k..n).map(|i| Polynomial::variable(i, n)).collect();
+    values.push(form.clone());
+    p.clone()
+        .with_dim(n - k + 1)
+        .substitute(&values)
+        .expect("one value for each variable")
+        .with_dim(n)
+}
+
+/// dimension of K(U)[X_0, ..., X_(k-1)] / J K(U)[X] for J zero dimensional over K(U)
+/// the dependent parts of the leading terms of a block order Groebner basis
+/// form a Groebner basis over K(U), so the standard monomials in X_0, ..., X_(k-1) are counted
+fn residue_dimension<K: Field + Clone>(ideal: &PolynomialIdeal<K>, k: usize) -> usize {
+    let basis = GroebnerBasis::new(ideal.generators(), MonomialOrder::Elimination(k));
+    let leading: Vec<Vec<usize>> = basis
+        .leading_terms()
+        .iter()
+        .map(|term| term.clone().with_dim(ideal.dim()).powers()[..k].to_vec())
+        .collect();
+    let mut count = 0;
+    for deg in 0.. {
+        let standard = Monomial::<K>::all_of_degree(k, deg)
+            .into_iter()
+            .filter(|mono| {
+                !leading.iter().any(|powers| {
+                    powers
+                        .iter()
+                        .zip(mono.exponents())
+                        .all(|(lead, power)| lead <= power)
+                })
+            })
+            .count();
+        if standard == 0 {
+            break;
+        }
+        count += standard;
+    }
+    count
+}
+
+/// X_0 + a X_1 + a^2 X_2 + ... + a^(k-1) X_(k-1), points on the moment curve
+/// avoid every proper linear subspace eventually
+fn linear_form<K: Field + Clone>(a: i64, k: usize, n: usize) -> Polynomial<K> {
+    let a = (0..a).fold(K::zero(), |acc, _| acc + <K as Field>::one());
+    let mut coefficient = <K as Field>::one();
+    let mut form = Polynomial::zero().with_dim(n);
+    for i in 0..k {
+        form = form + Polynomial::variable(i, n).scale(coefficient.clone());
+        coefficient = coefficient * a.clone();
+    }
+    form
+}
+
+/// substitutes X_order[j] -> X_j
+fn permute_ideal<K: Field + Clone>(
+    ideal: &PolynomialIdeal<K>,
+    order: &[usize],
+) -> PolynomialIdeal<K> {
+    let n = ideal.dim();
+    let generators = ideal
+        .generators()
+        .into_iter()
+        .map(|g| {
+            Polynomial::new(
+                g.into_terms()
+                    .into_iter()
+                    .map(|term| {
+                        let powers = term.clone().with_dim(n).powers();
+                        let permuted = order.iter().map(|i| powers[*i]).collect();
+                        Monomial::new(term.coefficient, permuted)
+                    })
+                    .collect(),
+            )
+            .with_dim(n)
+        })
+        .collect();
+    PolynomialIdeal::with_dim(generators, n)
+}
+
+#[cfg(test)]
+mod primary_tests {
+    use crate::{
+        ideal::PolynomialIdeal,
+        rational::Rational,
+        test_helpers::{ideal, poly},
+    };
+
+    #[test]
+    fn independent_variables() {
+        // <xy, xz> is the union of the plane x = 0 and the line y = z = 0
+        let planes = ideal(vec![vec![(1, vec![1, 1, 0])], vec![(1, vec![1, 0, 1])]]);
+        assert_eq!(planes.independent_variables().unwrap().len(), 2);
+        assert!(PolynomialIdeal::<Rational>::unit(2)
+            .independent_variables()
+            .is_none())
+    }
+
+    #[test]
+    fn zero_dimensional_splitting() {
+        // x^2 = y^2 = 2 are the four points (±√2, ±√2), two conjugate pairs over Q
+        let points = ideal(vec![
+            vec![(1, vec![2, 0]), (-2, vec![0, 0])],
+            vec![(1, vec![0, 2]), (-2, vec![0, 0])],
+        ]);
+        let components = points.primary_decomposition().unwrap();
+        assert_eq!(components.len(), 2);
+        let diagonal = poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])]);
+        assert!(components
+            .iter()
+            .any(|component| component.prime().contains(&diagonal)));
+        for component in components.iter() {
+            assert_eq!(component.primary(), component.prime());
+            assert_eq!(component.multiplicity(), Some(1));
+        }
+        assert!(points.is_prime().map(|prime| !prime).unwrap());
+        assert_eq!(points.radical().unwrap(), points)
+    }
+
+    #[test]
+    fn embedded_primes() {
+        // <x^2, xy> = <x> ∩ <x^2, y> with the origin embedded in the line x = 0
+        let lines = ideal(vec![vec![(1, vec![2, 0])], vec![(1, vec![1, 1])]]);
+        let components = lines.primary_decomposition().unwrap();
+        assert_eq!(components.len(), 2);
+        let line = ideal(vec![vec![(1, vec![1, 0])]]);
+        assert_eq!(lines.minimal_primes().unwrap(), vec![line.clone()]);
+        assert_eq!(lines.associated_primes().unwrap().len(), 2);
+        let embedded: Vec<_> = components.iter().filter(|c| c.is_embedded()).collect();
+        assert_eq!(embedded.len(), 1);
+        assert_eq!(embedded[0].prime(), &PolynomialIdeal::maximal_at_origin(2));
+        assert_eq!(embedded[0].multiplicity(), None);
+        assert_eq!(lines.radical().unwrap(), line)
+    }
+
+    #[test]
+    fn multiplicities() {
+        // x^2 y (x + y - 1) has the double line x = 0
+        let curves = ideal(vec![vec![
+            (1, vec![3, 1]),
+            (1, vec![2, 2]),
+            (-1, vec![2, 1]),
+        ]]);
+        let mut multiplicities: Vec<usize> = curves
+            .primary_decomposition()
+            .unwrap()
+            .iter()
+            .map(|component| component.multiplicity().unwrap())
+            .collect();
+        multiplicities.sort();
+        assert_eq!(multiplicities, vec![1, 1, 2]);
+        let parabola = ideal(vec![vec![(1, vec![0, 1]), (-1, vec![2, 0])]]);
+        assert!(parabola.is_prime().unwrap());
+        assert!(parabola.is_primary().unwrap())
+    }
+}
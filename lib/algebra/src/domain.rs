@@ -0,0 +1,28 @@
+use super::{errors::Error, ring::CommutativeRing};
+
+/// Commutative rings without zero divisors
+/// ab = 0 implies a = 0 or b = 0
+pub trait IntegralDomain: CommutativeRing {}
+
+/// Integral domains where every nonzero element is a unit times
+/// a product of primes, unique up to order and units
+pub trait UniqueFactorizationDomain: IntegralDomain {}
+
+/// Integral domains with division with remainder
+/// every Euclidean domain is a principal ideal domain and hence a UFD
+pub trait EuclideanDomain: UniqueFactorizationDomain + Clone {
+    /// q, r with self = q * divisor + r
+    /// r is zero or smaller than divisor with respect to a Euclidean function
+    fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error>;
+
+    /// unit u such that u * self is the chosen representative among the associates of self
+    /// e.g. the sign for integers, the inverse leading coefficient for polynomials
+    /// one for zero
+    fn normal_unit(&self) -> Self;
+
+    /// the chosen representative among the associates
+    fn normalize(self) -> Self {
+        let unit = self.normal_unit();
+        unit * self
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    errors::Error,
+    field::Field,
+    matrix::Matrix,
+    polynomials::{HomogeneousPolynomial, Monomial, Polynomial},
+};
+use std::collections::BTreeMap;
+
+/// Macaulay matrix of n forms f_i of degrees d_i in n variables in degree D = sum (d_i - 1) + 1
+/// rows and columns are indexed by the monomials m of degree D, where the row of m is
+/// (m / X_i^d_i) f_i for the first i with X_i^d_i | m
+/// the second matrix is the extraneous minor of the monomials divisible by two such powers
+pub fn macaulay_matrices<K: Field + Clone>(
+    forms: &[HomogeneousPolynomial<K>],
+) -> Result<(Matrix<K>, Matrix<K>), Error> {
+    let n = forms.len();
+    if let Some(form) = forms.iter().find(|form| form.dim() != n) {
+        return Err(Error::DimensionMismatch {
+            found: form.dim(),
+            expected: n,
+        });
+    }
+    let degrees: Vec<usize> = forms.iter().map(|form| form.deg()).collect();
+    let total = degrees.iter().map(|d| d.saturating_sub(1)).sum::<usize>() + 1;
+    let monomials: Vec<Vec<usize>> = Monomial::<K>::all_of_degree(n, total)
+        .into_iter()
+        .map(|mono| mono.powers())
+        .collect();
+    let index: BTreeMap<&Vec<usize>, usize> = monomials
+        .iter()
+        .enumerate()
+        .map(|(i, powers)| (powers, i))
+        .collect();
+    let reducible = |powers: &Vec<usize>| -> Vec<usize> {
+        (0..n).filter(|i| powers[*i] >= degrees[*i]).collect()
+    };
+
+    let mut matrix = Matrix::zero(monomials.len(), monomials.len());
+    for (row, powers) in monomials.iter().enumerate() {
+        let i = reducible(powers)[0];
+        let mut factor = powers.clone();
+        factor[i] -= degrees[i];
+        let shifted = Polynomial::from(forms[i].clone())
+            .mul_monomial(&Monomial::new(<K as Field>::one(), factor));
+        for term in shifted.into_terms() {
+            matrix.set_entry(row, index[&term.powers()], term.coefficient);
+        }
+    }
+    let extraneous: Vec<usize> = (0..monomials.len())
+        .filter(|i| reducible(&monomials[*i]).len() > 1)
+        .collect();
+    let rows = extraneous
+        .iter()
+        .map(|i| {
+            extraneous
+                .iter()
+                .map(|j| matrix.entry(*i, *j).clone())
+                .collect()
+        })
+        .collect();
+    Ok((matrix, Matrix::new(rows)?))
+}
+
+/// resultant of n forms in n variables, normalized by Res(X_0^d_0, ..., X_(n-1)^d_(n-1)) = 1
+/// it vanishes iff the forms have a common zero in P^(n-1) over the algebraic closure
+/// Macaulay's formula Res = det M / det A needs det A != 0, otherwise the variables are
+/// permuted, which changes the resultant by sign(sigma)^(d_0 ... d_(n-1))
+pub fn macaulay_resultant<K: Field + Clone>(
+    forms: &[HomogeneousPolynomial<K>],
+) -> Result<K, Error> {
+    if forms.iter().any(|form| form.is_zero()) {
+        return Ok(K::zero());
+    }
+    let n = forms.len();
+    let product: usize = forms.iter().map(|form| form.deg()).product();
+    for permutation in permutations(n) {
+        let permuted: Vec<HomogeneousPolynomial<K>> = forms
+            .iter()
+            .map(|form| permute_variables(form, &permutation))
+            .collect();
+        let (matrix, extraneous) = macaulay_matrices(&permuted)?;
+        let denominator = extraneous_determinant(&extraneous)?;
+        if denominator == K::zero() {
+            continue;
+        }
+        let res = matrix.determinant()? / denominator;
+        let odd = inversions(&permutation) % 2 == 1 && product % 2 == 1;
+        return Ok(if odd { -res } else { res });
+    }
+    Err(Error::DivisionByZero)
+}
+
+/// discriminant of a form f of degree d in n variables
+/// Res(df/dX_0, ..., df/dX_(n-1)) / d^(((d-1)^n - (-1)^n) / d)
+/// it vanishes iff the hypersurface V(f) is singular
+pub fn discriminant<K: Field + Clone>(form: &HomogeneousPolynomial<K>) -> Result<K, Error> {
+    let (n, d) = (form.dim(), form.deg());
+    if d < 2 {
+        return Err(Error::WrongDegree {
+            found: d,
+            expected: 2,
+        });
+    }
+    let exponent = ((d as i64 - 1).pow(n as u32) - (-1i64).pow(n as u32)) / d as i64;
+    let d_in_k = (0..d).fold(K::zero(), |acc, _| acc + <K as Field>::one());
+    if d_in_k == K::zero() {
+        return Err(Error::DivisionByZero);
+    }
+    let normalization = (0..exponent).fold(<K as Field>::one(), |acc, _| acc * d_in_k.clone());
+    Ok(macaulay_resultant(&form.gradient())? / normalization)
+}
+
+fn extraneous_determinant<K: Field + Clone>(extraneous: &Matrix<K>) -> Result<K, Error> {
+    if extraneous.num_rows() == 0 {
+        return Ok(<K as Field>::one());
+    }
+    extraneous.determinant()
+}
+
+/// X_i -> X_permutation[i]
+fn permute_variables<K: Field + Clone>(
+    form: &HomogeneousPolynomial<K>,
+    permutation: &[usize],
+) -> HomogeneousPolynomial<K> {
+    let monomials = Polynomial::from(form.clone())
+        .into_terms()
+        .into_iter()
+        .map(|term| {
+            let mut powers = vec![0; permutation.len()];
+            for (i, power) in term.exponents().iter().enumerate() {
+                powers[permutation[i]] = *power;
+            }
+            Monomial::new(term.coefficient, powers)
+        })
+        .collect();
+    HomogeneousPolynomial::new(monomials)
+        .expect("permuting variables keeps the degree")
+        .with_dim(permutation.len())
+}
+
+/// all permutations of 0..n, starting with the identity
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    permutations(n - 1)
+        .into_iter()
+        .flat_map(|permutation| {
+            (0..n).rev().map(move |position| {
+                let mut extended = permutation.clone();
+                extended.insert(position, n - 1);
+                extended
+            })
+        })
+        .collect()
+}
+
+fn inversions(permutation: &[usize]) -> usize {
+    (0..permutation.len())
+        .map(|i| {
+            (i + 1..permutation.len())
+                .filter(|j| permutation[i] > permutation[*j])
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod macaulay_tests {
+    use super::{discriminant, macaulay_resultant};
+    use crate::{
+        polynomials::{HomogeneousPolynomial, Monomial, UnivariatePolynomial},
+        prime_field::PrimeField,
+        test_helpers::homogeneous,
+    };
+
+    #[test]
+    fn linear_forms() {
+        // the resultant of linear forms is the determinant of their coefficients
+        let forms = vec![
+            homogeneous(vec![(2, vec![1, 0, 0]), (1, vec![0, 1, 0])]),
+            homogeneous(vec![(1, vec![0, 1, 0]), (3, vec![0, 0, 1])]),
+            homogeneous(vec![(1, vec![1, 0, 0]), (1, vec![0, 0, 1])]),
+        ];
+        assert_eq!(macaulay_resultant(&forms).unwrap(), 5.into())
+    }
+
+    #[test]
+    fn binary_forms_agree_with_sylvester() {
+        // x^2 - 3xy + 2y^2 and x^2 + y^2 dehomogenize to t^2 - 3t + 2 and t^2 + 1
+        let forms = vec![
+            homogeneous(vec![(1, vec![2, 0]), (-3, vec![1, 1]), (2, vec![0, 2])]),
+            homogeneous(vec![(1, vec![2, 0]), (1, vec![0, 2])]),
+        ];
+        let f = UnivariatePolynomial::new(vec![2.into(), (-3).into(), 1.into()]);
+        let g = UnivariatePolynomial::new(vec![1.into(), 0.into(), 1.into()]);
+        assert_eq!(macaulay_resultant(&forms).unwrap(), f.resultant(&g));
+        let powers = vec![
+            homogeneous(vec![(1, vec![2, 0])]),
+            homogeneous(vec![(1, vec![0, 3])]),
+        ];
+        assert_eq!(macaulay_resultant(&powers).unwrap(), 1.into())
+    }
+
+    #[test]
+    fn common_zeros() {
+        // three conics through [1:1:1] over F_7
+        let conic = |terms: Vec<(i64, Vec<usize>)>| {
+            HomogeneousPolynomial::new(
+                terms
+                    .into_iter()
+                    .map(|(coeff, powers)| Monomial::<PrimeField<7>>::new(coeff.into(), powers))
+                    .collect(),
+            )
+            .unwrap()
+        };
+        let forms = vec![
+            conic(vec![(1, vec![2, 0, 0]), (-1, vec![0, 1, 1])]),
+            conic(vec![(1, vec![0, 2, 0]), (-1, vec![1, 0, 1])]),
+            conic(vec![(1, vec![1, 1, 0]), (-1, vec![0, 0, 2])]),
+        ];
+        assert_eq!(macaulay_resultant(&forms).unwrap(), 0.into());
+        let squares = vec![
+            conic(vec![(1, vec![2, 0, 0])]),
+            conic(vec![(1, vec![0, 2, 0])]),
+            conic(vec![(1, vec![0, 0, 2]), (1, vec![1, 1, 0])]),
+        ];
+        assert_eq!(macaulay_resultant(&squares).unwrap(), 1.into())
+    }
+
+    #[test]
+    fn conic_discriminants() {
+        // x^2 + y^2 + z^2 is smooth, x y is the union of two lines
+        let smooth = homogeneous(vec![
+            (1, vec![2, 0, 0]),
+            (1, vec![0, 2, 0]),
+            (1, vec![0, 0, 2]),
+        ]);
+        assert_ne!(discriminant(&smooth).unwrap(), 0.into());
+        let lines = homogeneous(vec![(1, vec![1, 1, 0])]);
+        assert_eq!(discriminant(&lines).unwrap(), 0.into());
+        // for binary quadrics a x^2 + b x y + c y^2 this is 4ac - b^2
+        let binary = homogeneous(vec![(1, vec![2, 0]), (3, vec![1, 1]), (1, vec![0, 2])]);
+        assert_eq!(discriminant(&binary).unwrap(), (-5).into())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod macaulay;
+pub mod projection;
+pub mod resultant;
+
+pub use macaulay::{discriminant, macaulay_resultant};
+pub use projection::{eliminate_variables, EliminationMethod};
+pub use resultant::sylvester_matrix;
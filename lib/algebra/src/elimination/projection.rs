@@ -0,0 +1,271 @@
+use crate::{
+    affine::AffineScheme,
+    errors::Error,
+    field::Field,
+    ideal::PolynomialIdeal,
+    polynomials::{HomogeneousPolynomial, Monomial, Polynomial},
+    projective::{ProjectiveMorphism, ProjectiveScheme},
+};
+
+/// How variables are eliminated from an ideal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EliminationMethod {
+    /// I ∩ K[remaining variables] from a Groebner basis for an elimination order
+    Groebner,
+    /// pairwise resultants of the generators, one variable after the other
+    /// they vanish on the projection and cut it out for two generators
+    /// away from the common zeros of their leading coefficients,
+    /// but in general they only generate a subideal of the elimination ideal
+    /// falls back to Groebner when a variable occurs in exactly one generator
+    Resultant,
+}
+
+/// polynomials in the remaining variables, in increasing order, that vanish on the projection
+/// of V(generators) away from the eliminated variables
+/// the eliminated variables have to be distinct and smaller than num_variables
+pub fn eliminate_variables<K: Field + Clone>(
+    generators: &[Polynomial<K>],
+    num_variables: usize,
+    eliminated: &[usize],
+    method: EliminationMethod,
+) -> Result<Vec<Polynomial<K>>, Error> {
+    if let Some(var) = eliminated.iter().find(|var| **var >= num_variables) {
+        return Err(Error::DimensionMismatch {
+            found: var + 1,
+            expected: num_variables,
+        });
+    }
+    if let Some((_, var)) = eliminated
+        .iter()
+        .enumerate()
+        .find(|(i, var)| eliminated[..*i].contains(var))
+    {
+        return Err(Error::RepeatedVariable(*var));
+    }
+    let remaining: Vec<usize> = (0..num_variables)
+        .filter(|var| !eliminated.contains(var))
+        .collect();
+    let generators: Vec<Polynomial<K>> = generators
+        .iter()
+        .map(|gen| gen.clone().with_dim(num_variables))
+        .collect();
+    match method {
+        EliminationMethod::Groebner => {
+            // the eliminated variables come first in the elimination order
+            let mut order: Vec<usize> = eliminated.to_vec();
+            order.extend(remaining.iter());
+            let reordered = generators
+                .iter()
+                .map(|gen| reorder_variables(gen, &order))
+                .collect();
+            Ok(PolynomialIdeal::with_dim(reordered, num_variables)
+                .eliminate(eliminated.len())
+                .generators())
+        }
+        EliminationMethod::Resultant => {
+            let mut current = generators.clone();
+            for var in eliminated.iter() {
+                let (involved, free): (Vec<Polynomial<K>>, Vec<Polynomial<K>>) =
+                    current.into_iter().partition(|gen| gen.degree_in(*var) > 0);
+                // there are no resultants with a single generator
+                if involved.len() == 1 {
+                    return eliminate_variables(
+                        &generators,
+                        num_variables,
+                        eliminated,
+                        EliminationMethod::Groebner,
+                    );
+                }
+                current = free;
+                for (i, f) in involved.iter().enumerate() {
+                    for g in involved[i + 1..].iter() {
+                        let res = f.resultant(g, *var);
+                        if !res.is_zero() && !current.contains(&res) {
+                            current.push(res);
+                        }
+                    }
+                }
+            }
+            Ok(current
+                .iter()
+                .map(|gen| reorder_variables(gen, &remaining))
+                .collect())
+        }
+    }
+}
+
+/// substitutes X_order[k] -> X_k, the other variables have to be absent
+fn reorder_variables<K: Field + Clone>(poly: &Polynomial<K>, order: &[usize]) -> Polynomial<K> {
+    let monomials = poly
+        .terms()
+        .iter()
+        .map(|term| {
+            let powers = order
+                .iter()
+                .map(|var| term.exponents().get(*var).copied().unwrap_or(0))
+                .collect();
+            Monomial::new(term.coefficient.clone(), powers)
+        })
+        .collect();
+    Polynomial::new(monomials).with_dim(order.len())
+}
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// closure of the image under the projection (x_0 : ... : x_n) -> (x_j)_(j not eliminated)
+    /// from the linear space where the remaining coordinates vanish
+    pub fn project(
+        &self,
+        eliminated: &[usize],
+        method: EliminationMethod,
+    ) -> Result<ProjectiveScheme<K>, Error> {
+        let generators: Vec<Polynomial<K>> = self
+            .ideal_generators()
+            .into_iter()
+            .map(Polynomial::from)
+            .collect();
+        let projected = eliminate_variables(&generators, self.num_variables(), eliminated, method)?;
+        let num_variables = self.num_variables() - eliminated.len();
+        let forms = projected
+            .into_iter()
+            .map(HomogeneousPolynomial::try_from)
+            .collect::<Result<Vec<HomogeneousPolynomial<K>>, Error>>()?;
+        ProjectiveScheme::in_space(num_variables, forms)
+    }
+}
+
+impl<K: Field + Clone> AffineScheme<K> {
+    /// closure of the image under the coordinate projection forgetting the eliminated variables
+    pub fn project(
+        &self,
+        eliminated: &[usize],
+        method: EliminationMethod,
+    ) -> Result<AffineScheme<K>, Error> {
+        let projected = eliminate_variables(
+            &self.ideal_generators(),
+            self.num_variables(),
+            eliminated,
+            method,
+        )?;
+        AffineScheme::new(self.num_variables() - eliminated.len(), projected)
+    }
+}
+
+impl<K: Field + Clone> ProjectiveMorphism<K> {
+    /// closure of the image, the projection of the graph
+    /// {(x, y) | y_j = f_j(x)} in the affine cone away from the domain variables
+    pub fn image_by(&self, method: EliminationMethod) -> Result<ProjectiveScheme<K>, Error> {
+        let num_variables = self.dim_domain() + self.dim_codomain();
+        let graph: Vec<Polynomial<K>> = self
+            .coordinate_functions()
+            .into_iter()
+            .enumerate()
+            .map(|(j, fun)| {
+                Polynomial::variable(self.dim_domain() + j, num_variables)
+                    - Polynomial::from(fun).with_dim(num_variables)
+            })
+            .collect();
+        let domain: Vec<usize> = (0..self.dim_domain()).collect();
+        let projected = eliminate_variables(&graph, num_variables, &domain, method)?;
+        let forms = projected
+            .into_iter()
+            .map(HomogeneousPolynomial::try_from)
+            .collect::<Result<Vec<HomogeneousPolynomial<K>>, Error>>()?;
+        ProjectiveScheme::in_space(self.dim_codomain(), forms)
+    }
+}
+
+#[cfg(test)]
+mod projection_tests {
+    use super::{eliminate_variables, EliminationMethod};
+    use crate::{
+        affine::AffineScheme,
+        errors::Error,
+        polynomials::{HomogeneousPolynomial, Monomial},
+        projective::{ProjectiveMorphism, ProjectiveScheme},
+        rational::Rational,
+        test_helpers::poly,
+    };
+
+    #[test]
+    fn affine_projection() {
+        // the circle x^2 + y^2 = 1 and the line x = y meet where 2 x^2 = 1
+        let scheme = AffineScheme::new(
+            2,
+            vec![
+                poly(vec![(1, vec![2, 0]), (1, vec![0, 2]), (-1, vec![0, 0])]),
+                poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])]),
+            ],
+        )
+        .unwrap();
+        let expected = poly(vec![(2, vec![2]), (-1, vec![0])]);
+        let points = AffineScheme::new(1, vec![expected.clone()]).unwrap();
+        for method in [EliminationMethod::Groebner, EliminationMethod::Resultant] {
+            let projection = scheme.project(&[1], method).unwrap();
+            assert_eq!(projection.num_variables(), 1);
+            assert!(projection.ideal().contains(&expected));
+            assert!(points.ideal().contains_ideal(&projection.ideal()));
+        }
+    }
+
+    #[test]
+    fn twisted_cubic_projects_to_cuspidal_cubic() {
+        // projecting the twisted cubic from [0:0:1:0] gives (s^3 : s^2 t : t^3)
+        let twisted = ProjectiveMorphism::<Rational>::veronese_embedding(1, 3).image();
+        let cuspidal = twisted.project(&[2], EliminationMethod::Groebner).unwrap();
+        assert_eq!(cuspidal.num_variables(), 3);
+        assert_eq!(cuspidal.degree(), 3);
+        let expected = HomogeneousPolynomial::new(vec![
+            Monomial::new(1.into(), vec![0, 3, 0]),
+            Monomial::new((-1).into(), vec![2, 0, 1]),
+        ])
+        .unwrap();
+        assert!(cuspidal.ideal_contains(&expected))
+    }
+
+    #[test]
+    fn images_of_morphisms() {
+        // (s^2 : s t : t^2) parametrizes the conic y^2 = x z
+        let morphism = ProjectiveMorphism::<Rational>::veronese_embedding(1, 2);
+        let conic = poly(vec![(1, vec![0, 2, 0]), (-1, vec![1, 0, 1])]);
+        let by_groebner = morphism.image_by(EliminationMethod::Groebner).unwrap();
+        assert_eq!(by_groebner.ideal_generators().len(), 1);
+        assert!(by_groebner.ideal().contains(&conic));
+        // the resultants vanish on the conic and define it up to multiplicity
+        let by_resultants = morphism.image_by(EliminationMethod::Resultant).unwrap();
+        assert!(!by_resultants.ideal_generators().is_empty());
+        assert!(by_resultants.ideal().radical_contains(&conic));
+        let conic_scheme =
+            ProjectiveScheme::new(vec![HomogeneousPolynomial::try_from(conic).unwrap()]).unwrap();
+        assert!(conic_scheme.ideal().contains_ideal(&by_resultants.ideal()))
+    }
+
+    #[test]
+    fn invalid_eliminated_variables() {
+        let generators = vec![poly(vec![(1, vec![1, 0, 0]), (-1, vec![0, 1, 1])])];
+        for method in [EliminationMethod::Groebner, EliminationMethod::Resultant] {
+            assert!(eliminate_variables(&generators, 3, &[3], method).is_err());
+            assert!(matches!(
+                eliminate_variables(&generators, 3, &[1, 1], method),
+                Err(Error::RepeatedVariable(1))
+            ));
+            assert!(matches!(
+                eliminate_variables(&generators, 3, &[2, 0, 2], method),
+                Err(Error::RepeatedVariable(2))
+            ));
+            assert!(eliminate_variables(&generators, 3, &[2, 0], method).is_ok())
+        }
+    }
+
+    #[test]
+    fn variable_in_a_single_generator() {
+        // c x - 1 and c have no common zero, but only c x - 1 involves x
+        let generators = vec![
+            poly(vec![(1, vec![1, 1]), (-1, vec![0, 0])]),
+            poly(vec![(1, vec![0, 1])]),
+        ];
+        for method in [EliminationMethod::Groebner, EliminationMethod::Resultant] {
+            let eliminated = eliminate_variables(&generators, 2, &[0], method).unwrap();
+            assert_eq!(eliminated, vec![poly(vec![(1, vec![0])])])
+        }
+    }
+}
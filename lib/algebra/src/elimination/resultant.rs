@@ -0,0 +1,217 @@
+use crate::{
+    errors::Error,
+    field::Field,
+    groebner::divide,
+    group::AbelianGroup,
+    matrix::Matrix,
+    polynomials::{Monomial, MonomialOrder, Polynomial, UnivariatePolynomial},
+    projective::minors::determinant,
+};
+
+/// Sylvester matrix of a_0 x^m + ... + a_m and b_0 x^n + ... + b_n
+/// given by their coefficients, highest degree first
+/// n rows of shifted a's followed by m rows of shifted b's
+pub fn sylvester_matrix<T: Clone>(left: &[T], right: &[T], zero: T) -> Vec<Vec<T>> {
+    let (m, n) = (left.len() - 1, right.len() - 1);
+    let shifted = |coefficients: &[T], shift: usize| {
+        let mut row = vec![zero.clone(); m + n];
+        row[shift..shift + coefficients.len()].clone_from_slice(coefficients);
+        row
+    };
+    let mut rows: Vec<Vec<T>> = (0..n).map(|i| shifted(left, i)).collect();
+    rows.extend((0..m).map(|i| shifted(right, i)));
+    rows
+}
+
+/// determinant of the Sylvester matrix of coefficient lists, highest degree first
+fn sylvester_resultant<K: Field + Clone>(left: &[K], right: &[K]) -> K {
+    if left.len() + right.len() == 2 {
+        return <K as Field>::one();
+    }
+    Matrix::new(sylvester_matrix(left, right, K::zero()))
+        .and_then(|matrix| matrix.determinant())
+        .expect("Sylvester matrices are square")
+}
+
+/// (-1)^(n(n-1)/2)
+fn discriminant_sign(n: usize) -> bool {
+    (n * (n - 1) / 2) % 2 == 1
+}
+
+impl<K: Field + Clone> UnivariatePolynomial<K> {
+    /// Res(f, g) = a^n b^m prod (x_i - y_j) for the roots x_i of f and y_j of g
+    /// zero if one of the polynomials is zero
+    pub fn resultant(&self, other: &UnivariatePolynomial<K>) -> K {
+        if self.is_zero() || other.is_zero() {
+            return K::zero();
+        }
+        let reversed = |f: &UnivariatePolynomial<K>| {
+            let mut coefficients = f.coefficients().to_vec();
+            coefficients.reverse();
+            coefficients
+        };
+        sylvester_resultant(&reversed(self), &reversed(other))
+    }
+
+    /// (-1)^(n(n-1)/2) Res(f, f') / a_n, it vanishes iff f has a multiple root
+    /// f' is treated as a polynomial of degree n - 1, also if p divides n
+    pub fn discriminant(&self) -> Result<K, Error> {
+        let n = match self.degree() {
+            Some(n) if n > 0 => n,
+            _ => {
+                return Err(Error::WrongDegree {
+                    found: 0,
+                    expected: 1,
+                })
+            }
+        };
+        let mut coefficients = self.coefficients().to_vec();
+        let mut derivative = self.derivative().coefficients().to_vec();
+        derivative.resize(n, K::zero());
+        coefficients.reverse();
+        derivative.reverse();
+        let leading = coefficients[0].clone();
+        let res = sylvester_resultant(&coefficients, &derivative) / leading;
+        Ok(if discriminant_sign(n) { -res } else { res })
+    }
+}
+
+impl<K: Field + Clone> Polynomial<K> {
+    /// degree in the variable X_var
+    pub fn degree_in(&self, var: usize) -> usize {
+        self.terms()
+            .iter()
+            .map(|term| term.exponents().get(var).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// coefficients c_k in K[X_j, j != var] with f = sum c_k X_var^k, lowest degree first
+    pub fn coefficients_in(&self, var: usize) -> Vec<Polynomial<K>> {
+        let mut coefficients =
+            vec![Polynomial::zero().with_dim(self.dim()); self.degree_in(var) + 1];
+        for term in self.terms() {
+            let power = term.exponents().get(var).copied().unwrap_or(0);
+            let mut powers = term.powers();
+            if var < powers.len() {
+                powers[var] = 0;
+            }
+            coefficients[power] = coefficients[power].clone()
+                + Monomial::new(term.coefficient.clone(), powers).into();
+        }
+        coefficients
+    }
+
+    /// resultant with respect to X_var, a polynomial in the remaining variables
+    /// computed as the determinant of the Sylvester matrix by cofactor expansion,
+    /// so only small degrees are feasible
+    pub fn resultant(&self, other: &Polynomial<K>, var: usize) -> Polynomial<K> {
+        let dim = self.dim().max(other.dim());
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero().with_dim(dim);
+        }
+        let reversed = |f: &Polynomial<K>| {
+            let mut coefficients = f.clone().with_dim(dim).coefficients_in(var);
+            coefficients.reverse();
+            coefficients
+        };
+        let (left, right) = (reversed(self), reversed(other));
+        let matrix = sylvester_matrix(&left, &right, Polynomial::zero().with_dim(dim));
+        determinant(&matrix).with_dim(dim)
+    }
+
+    /// discriminant with respect to X_var, (-1)^(n(n-1)/2) Res(f, df/dX_var) / a_n
+    pub fn discriminant(&self, var: usize) -> Result<Polynomial<K>, Error> {
+        let n = self.degree_in(var);
+        if n == 0 {
+            return Err(Error::WrongDegree {
+                found: 0,
+                expected: 1,
+            });
+        }
+        let mut coefficients = self.coefficients_in(var);
+        let mut derivative = self.partial_derivative(var).coefficients_in(var);
+        derivative.resize(n, Polynomial::zero().with_dim(self.dim()));
+        coefficients.reverse();
+        derivative.reverse();
+        let matrix = sylvester_matrix(
+            &coefficients,
+            &derivative,
+            Polynomial::zero().with_dim(self.dim()),
+        );
+        let res = determinant(&matrix).with_dim(self.dim());
+        let (mut quotients, remainder) = divide(&res, &coefficients[..1], MonomialOrder::GRevLex);
+        debug_assert!(remainder.is_zero());
+        let quotient = quotients.remove(0);
+        Ok(if discriminant_sign(n) {
+            -quotient
+        } else {
+            quotient
+        })
+    }
+}
+
+#[cfg(test)]
+mod resultant_tests {
+    use crate::{
+        polynomials::UnivariatePolynomial, prime_field::PrimeField, rational::Rational,
+        test_helpers::poly,
+    };
+
+    fn univariate(coefficients: Vec<i64>) -> UnivariatePolynomial<Rational> {
+        UnivariatePolynomial::new(coefficients.into_iter().map(Rational::from).collect())
+    }
+
+    #[test]
+    fn sylvester_resultants() {
+        // (x - 1)(x - 2) and x - 3 give (1 - 3)(2 - 3) = 2
+        let f = univariate(vec![2, -3, 1]);
+        let g = univariate(vec![-3, 1]);
+        assert_eq!(f.resultant(&g), 2.into());
+        assert_eq!(g.resultant(&f), 2.into());
+        // a common root makes the resultant vanish
+        assert_eq!(f.resultant(&univariate(vec![-2, 1])), 0.into());
+        assert_eq!(f.resultant(&univariate(vec![5])), 25.into())
+    }
+
+    #[test]
+    fn univariate_discriminants() {
+        // x^2 + b x + c has discriminant b^2 - 4c
+        assert_eq!(univariate(vec![3, 5, 1]).discriminant().unwrap(), 13.into());
+        // x^3 + p x + q has discriminant -4p^3 - 27q^2
+        assert_eq!(
+            univariate(vec![1, -2, 0, 1]).discriminant().unwrap(),
+            5.into()
+        );
+        // x^3 - x over F_3 is separable although its derivative has lower degree
+        let f = UnivariatePolynomial::<PrimeField<3>>::new(vec![
+            0.into(),
+            (-1).into(),
+            0.into(),
+            1.into(),
+        ]);
+        assert_eq!(f.discriminant().unwrap(), 4.into());
+        assert!(univariate(vec![5]).discriminant().is_err())
+    }
+
+    #[test]
+    fn multivariate_resultants() {
+        // eliminating y from x - y^2 and y - 2 gives x - 4
+        let f = poly(vec![(1, vec![1, 0]), (-1, vec![0, 2])]);
+        let g = poly(vec![(1, vec![0, 1]), (-2, vec![0, 0])]);
+        assert_eq!(
+            f.resultant(&g, 1),
+            poly(vec![(1, vec![1, 0]), (-4, vec![0, 0])])
+        );
+        // the discriminant of x^2 + b x + c in the variable x
+        let quadratic = poly(vec![
+            (1, vec![2, 0, 0]),
+            (1, vec![1, 1, 0]),
+            (1, vec![0, 0, 1]),
+        ]);
+        assert_eq!(
+            quadratic.discriminant(0).unwrap(),
+            poly(vec![(1, vec![0, 2, 0]), (-4, vec![0, 0, 1])])
+        )
+    }
+}
@@ -6,6 +6,22 @@ pub enum Error {
     DivisionByZero,
     DimensionMismatch { found: usize, expected: usize },
     WrongDegree { found: usize, expected: usize },
+    DifferentRings,
+    UnsupportedExtensionDegree { found: usize, max: usize },
+    PointNotOnScheme,
+    NotInvertible,
+    RationalOverflow,
+    HenselBoundTooLarge,
+    NoSuitablePrime,
+    NotASubgroup,
+    NotNormal,
+    InvalidPermutation,
+    IncompatibleGrading,
+    ImproperIntersection,
+    NotCompleteIntersection,
+    RepeatedVariable(usize),
+    NoGenericLinearForm,
+    DegreeTooLarge { found: usize, max: usize },
 }
 
 impl fmt::Display for Error {
@@ -21,6 +37,37 @@ impl fmt::Display for Error {
             Error::WrongDegree { found, expected } => {
                 write!(f, "Expected degree {expected}, got {found}")
             }
+            Error::DifferentRings => f.write_str("Elements belong to different quotient rings"),
+            Error::UnsupportedExtensionDegree { found, max } => {
+                write!(
+                    f,
+                    "Extension degree {found} is not supported, maximum is {max}"
+                )
+            }
+            Error::PointNotOnScheme => f.write_str("Point does not lie on the scheme"),
+            Error::NotInvertible => f.write_str("Morphism is not invertible"),
+            Error::RationalOverflow => {
+                f.write_str("Integer coefficients do not fit into machine integers")
+            }
+            Error::HenselBoundTooLarge => {
+                f.write_str("Coefficient bound too large for Hensel lifting")
+            }
+            Error::NoSuitablePrime => f.write_str("No small prime keeps the polynomial squarefree"),
+            Error::NotASubgroup => f.write_str("Not contained in the given group"),
+            Error::NotNormal => f.write_str("Subgroup is not normal"),
+            Error::InvalidPermutation => f.write_str("Not a permutation"),
+            Error::IncompatibleGrading => f.write_str("Degrees of the graded modules do not fit"),
+            Error::ImproperIntersection => {
+                f.write_str("Intersection does not have the expected dimension")
+            }
+            Error::NotCompleteIntersection => f.write_str("Scheme is not a complete intersection"),
+            Error::RepeatedVariable(var) => write!(f, "Variable {var} occurs more than once"),
+            Error::NoGenericLinearForm => {
+                f.write_str("No linear form in general position over this field")
+            }
+            Error::DegreeTooLarge { found, max } => {
+                write!(f, "Degree {found} is not supported, maximum is {max}")
+            }
         }
     }
 }
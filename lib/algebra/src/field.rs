@@ -1,4 +1,8 @@
-use super::ring::{Ring, SubRing};
+use super::{
+    domain::IntegralDomain,
+    group::FiniteAbelianGroup,
+    ring::{CommutativeRing, FiniteRing, Ring, SubRing},
+};
 use std::ops::Div;
 
 pub trait Field: Ring + Div<Output = Self> {
@@ -6,18 +10,151 @@ pub trait Field: Ring + Div<Output = Self> {
     fn inverse(self) -> Self;
 }
 
-pub struct SubField<F>
-where
-    F: Field,
-{
-    pub elem: Box<dyn Fn(&F) -> bool>,
+/// fields are commutative by definition
+impl<K: Field> CommutativeRing for K {}
+
+/// a nonzero element of a field is a unit, so there are no zero divisors
+impl<K: Field> IntegralDomain for K {}
+
+/// Fields with finitely many elements
+pub trait FiniteField: Field + Clone {
+    fn characteristic() -> u64;
+    /// number of elements
+    fn order() -> u64;
+    /// all elements, starting with zero
+    fn elements() -> Vec<Self>;
+}
+
+impl<F: FiniteField> FiniteAbelianGroup for F {}
+
+impl<F: FiniteField> FiniteRing for F {}
+
+/// Subfield of a finite field generated by finitely many elements
+/// a finite subring of a field is already a field
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubField<F: FiniteField> {
+    subring: SubRing<F>,
+}
+
+impl<F: FiniteField> SubField<F> {
+    pub fn generated_by(generators: Vec<F>) -> SubField<F> {
+        SubField {
+            subring: SubRing::generated_by(generators),
+        }
+    }
+
+    /// the prime field F_p
+    pub fn prime_field() -> SubField<F> {
+        SubField::generated_by(vec![])
+    }
+
+    pub fn generators(&self) -> &[F] {
+        self.subring.generators()
+    }
+
+    pub fn elements(&self) -> &[F] {
+        self.subring.elements()
+    }
+
+    /// number of elements
+    pub fn order(&self) -> usize {
+        self.subring.order()
+    }
+
+    /// degree over the prime field
+    pub fn degree(&self) -> usize {
+        let p = F::characteristic() as usize;
+        let mut degree = 0;
+        let mut order = self.order();
+        while order > 1 {
+            order /= p;
+            degree += 1;
+        }
+        degree
+    }
+
+    pub fn contains(&self, elem: &F) -> bool {
+        self.subring.contains(elem)
+    }
+
+    pub fn intersection(&self, other: &SubField<F>) -> SubField<F> {
+        SubField {
+            subring: self.subring.intersection(&other.subring),
+        }
+    }
 }
 
-impl<F> From<SubField<F>> for SubRing<F>
-where
-    F: Field,
-{
+impl<F: FiniteField> From<SubField<F>> for SubRing<F> {
     fn from(f: SubField<F>) -> SubRing<F> {
-        SubRing { elem: f.elem }
+        f.subring
+    }
+}
+
+/// Subfield given only by a membership test
+/// for subfields of infinite fields, which can not be enumerated
+pub struct PredicateSubField<F: Field> {
+    pub elem: Box<dyn Fn(&F) -> bool>,
+}
+
+impl<F: Field + 'static> PredicateSubField<F> {
+    pub fn contains(&self, elem: &F) -> bool {
+        (self.elem)(elem)
+    }
+
+    /// elements satisfying both predicates
+    pub fn intersection(self, other: PredicateSubField<F>) -> PredicateSubField<F> {
+        PredicateSubField {
+            elem: Box::new(move |x| (self.elem)(x) && (other.elem)(x)),
+        }
+    }
+}
+
+impl<F: FiniteField + 'static> From<SubField<F>> for PredicateSubField<F> {
+    fn from(subfield: SubField<F>) -> PredicateSubField<F> {
+        PredicateSubField {
+            elem: Box::new(move |x| subfield.contains(x)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod field_tests {
+    use super::{FiniteField, PredicateSubField, SubField};
+    use crate::{complex::Complex, galois_field::GaloisField};
+
+    #[test]
+    fn subfields_of_f64() {
+        type F64 = GaloisField<2, 6>;
+        let a = F64::primitive_element();
+        // F_4 and F_8 are generated by elements of order 3 and 7
+        let f4 = SubField::generated_by(vec![a.pow_mod(21)]);
+        let f8 = SubField::generated_by(vec![a.pow_mod(9)]);
+        assert_eq!(f4.degree(), 2);
+        assert_eq!(f8.degree(), 3);
+        assert_eq!(f4.intersection(&f8), SubField::prime_field());
+        assert!(f8.contains(&a.pow_mod(18)))
+    }
+
+    #[test]
+    fn predicate_subfields() {
+        let reals = PredicateSubField {
+            elem: Box::new(|z: &Complex| z.im == 0.0),
+        };
+        assert!(reals.contains(&Complex::new(2.5, 0.0)));
+        assert!(!reals.contains(&Complex::i()));
+
+        type F64 = GaloisField<2, 6>;
+        let a = F64::primitive_element();
+        // F_4 consists of the roots of x^4 - x
+        let f4 = PredicateSubField {
+            elem: Box::new(|x: &F64| x.pow_mod(4) == *x),
+        };
+        let f8: PredicateSubField<F64> = SubField::generated_by(vec![a.pow_mod(9)]).into();
+        assert!(f4.contains(&a.pow_mod(21)));
+        assert!(!f4.contains(&a));
+        let prime_field = f4.intersection(f8);
+        assert!(F64::elements()
+            .iter()
+            .all(|x| prime_field.contains(x) == SubField::prime_field().contains(x)))
     }
 }
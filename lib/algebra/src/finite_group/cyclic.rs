@@ -0,0 +1,113 @@
+use crate::group::{FiniteGroup, Group, SubGroup};
+use std::{fmt, ops::Mul};
+
+/// The cyclic group Z/nZ, written multiplicatively
+/// the element k stands for g^k with a fixed generator g
+/// elements are represented by 0 <= k < N, N has to be positive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cyclic<const N: u64> {
+    exponent: u64,
+}
+
+impl<const N: u64> Cyclic<N> {
+    /// g^k
+    pub fn new(exponent: u64) -> Cyclic<N> {
+        Cyclic {
+            exponent: exponent % N,
+        }
+    }
+
+    /// the generator g
+    pub fn generator() -> Cyclic<N> {
+        Cyclic::new(1)
+    }
+
+    pub fn exponent(&self) -> u64 {
+        self.exponent
+    }
+
+    /// all elements, starting with the identity
+    pub fn elements() -> Vec<Cyclic<N>> {
+        (0..N).map(Cyclic::new).collect()
+    }
+
+    /// smallest positive m with x^m = 1, that is n / gcd(k, n)
+    pub fn order(&self) -> u64 {
+        let (mut a, mut b) = (self.exponent, N);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        N / a
+    }
+
+    /// the unique subgroup of order d, None if d does not divide n
+    pub fn subgroup_of_order(d: u64) -> Option<SubGroup<Cyclic<N>>> {
+        if d == 0 || !N.is_multiple_of(d) {
+            return None;
+        }
+        Some(SubGroup::generated_by(vec![Cyclic::new(N / d)]))
+    }
+}
+
+impl<const N: u64> fmt::Display for Cyclic<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "g^{}", self.exponent)
+    }
+}
+
+/// g^a g^b = g^(a+b)
+impl<const N: u64> Mul for Cyclic<N> {
+    type Output = Cyclic<N>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: Cyclic<N>) -> Cyclic<N> {
+        Cyclic::new(self.exponent + other.exponent)
+    }
+}
+
+impl<const N: u64> Group for Cyclic<N> {
+    fn one() -> Cyclic<N> {
+        Cyclic { exponent: 0 }
+    }
+
+    fn inverse(self) -> Cyclic<N> {
+        Cyclic::new(N - self.exponent)
+    }
+}
+
+impl<const N: u64> FiniteGroup for Cyclic<N> {}
+
+#[cfg(test)]
+mod cyclic_tests {
+    use super::Cyclic;
+    use crate::group::{Group, SubGroup};
+
+    type Z12 = Cyclic<12>;
+
+    #[test]
+    fn element_orders() {
+        assert_eq!(Z12::new(0).order(), 1);
+        assert_eq!(Z12::new(8).order(), 3);
+        assert_eq!(Z12::new(5).order(), 12);
+        assert_eq!(Z12::new(5) * Z12::new(5).inverse(), Z12::one())
+    }
+
+    #[test]
+    fn subgroup_lattice() {
+        let of_order_4 = Z12::subgroup_of_order(4).unwrap();
+        let of_order_6 = Z12::subgroup_of_order(6).unwrap();
+        assert_eq!(of_order_4.order(), 4);
+        assert_eq!(
+            of_order_4.intersection(&of_order_6),
+            Z12::subgroup_of_order(2).unwrap()
+        );
+        assert_eq!(
+            of_order_4.join(&of_order_6),
+            SubGroup::generated_by(vec![Z12::generator()])
+        );
+        assert!(Z12::subgroup_of_order(5).is_none());
+        let quotient = of_order_4
+            .quotient(&SubGroup::generated_by(vec![Z12::generator()]))
+            .unwrap();
+        assert_eq!(quotient.order(), 3)
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    errors::Error,
+    field::{Field, FiniteField},
+    group::{FiniteGroup, Group},
+    matrix::Matrix,
+    projective::ProjectiveMorphism,
+};
+use std::{fmt, ops::Mul};
+
+/// Element of the general linear group GL_N(K) over a finite field
+/// an invertible N x N matrix, the product is the matrix product
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneralLinear<K: FiniteField, const N: usize> {
+    matrix: Matrix<K>,
+}
+
+impl<K: FiniteField, const N: usize> GeneralLinear<K, N> {
+    /// fails if the matrix is not N x N or not invertible
+    pub fn new(matrix: Matrix<K>) -> Result<GeneralLinear<K, N>, Error> {
+        if matrix.num_rows() != N || matrix.num_cols() != N {
+            return Err(Error::DimensionMismatch {
+                found: matrix.num_rows().max(matrix.num_cols()),
+                expected: N,
+            });
+        }
+        if matrix.determinant()? == K::zero() {
+            return Err(Error::NotInvertible);
+        }
+        Ok(GeneralLinear { matrix })
+    }
+
+    pub fn matrix(&self) -> &Matrix<K> {
+        &self.matrix
+    }
+
+    /// the linear automorphism X -> AX of P^(N-1)
+    pub fn automorphism(&self) -> ProjectiveMorphism<K> {
+        ProjectiveMorphism::from_matrix(&self.matrix).unwrap()
+    }
+
+    /// the diagonal matrix diag(a, 1, ..., 1) for a primitive element a
+    /// and the transvections I + E_ij for i != j
+    /// the transvections generate SL_N, the determinants of the diagonal matrices all of K^*
+    pub fn generators() -> Vec<GeneralLinear<K, N>> {
+        let mut generators = vec![];
+        if N == 0 {
+            return generators;
+        }
+        let mut diagonal = Matrix::identity(N);
+        diagonal.set_entry(0, 0, primitive_element::<K>());
+        if diagonal != Matrix::identity(N) {
+            generators.push(GeneralLinear { matrix: diagonal });
+        }
+        for i in 0..N {
+            for j in (0..N).filter(|j| *j != i) {
+                let mut transvection = Matrix::identity(N);
+                transvection.set_entry(i, j, <K as Field>::one());
+                generators.push(GeneralLinear {
+                    matrix: transvection,
+                });
+            }
+        }
+        generators
+    }
+
+    /// |GL_N(F_q)| = (q^N - 1)(q^N - q)...(q^N - q^(N-1))
+    pub fn order() -> usize {
+        let q = K::order() as usize;
+        (0..N).map(|i| q.pow(N as u32) - q.pow(i as u32)).product()
+    }
+}
+
+impl<K: FiniteField, const N: usize> Mul for GeneralLinear<K, N> {
+    type Output = GeneralLinear<K, N>;
+    fn mul(self, other: GeneralLinear<K, N>) -> GeneralLinear<K, N> {
+        GeneralLinear {
+            matrix: self.matrix * other.matrix,
+        }
+    }
+}
+
+impl<K: FiniteField, const N: usize> Group for GeneralLinear<K, N> {
+    fn one() -> GeneralLinear<K, N> {
+        GeneralLinear {
+            matrix: Matrix::identity(N),
+        }
+    }
+
+    fn inverse(self) -> GeneralLinear<K, N> {
+        GeneralLinear {
+            matrix: self.matrix.inverse().unwrap(),
+        }
+    }
+}
+
+impl<K: FiniteField, const N: usize> FiniteGroup for GeneralLinear<K, N> {}
+
+impl<K, const N: usize> fmt::Display for GeneralLinear<K, N>
+where
+    K: FiniteField + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.matrix)
+    }
+}
+
+/// Element of the projective linear group PGL_N(K) = GL_N(K) / K^* over a finite field
+/// the matrix is scaled so that its first nonzero entry is one,
+/// so derived equality compares the automorphisms of P^(N-1)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectiveLinear<K: FiniteField, const N: usize> {
+    matrix: Matrix<K>,
+}
+
+impl<K: FiniteField, const N: usize> ProjectiveLinear<K, N> {
+    /// fails if the matrix is not N x N or not invertible
+    pub fn new(matrix: Matrix<K>) -> Result<ProjectiveLinear<K, N>, Error> {
+        Ok(GeneralLinear::<K, N>::new(matrix)?.into())
+    }
+
+    /// the normalized representative
+    pub fn matrix(&self) -> &Matrix<K> {
+        &self.matrix
+    }
+
+    /// the linear automorphism of P^(N-1) given by the normalized representative
+    pub fn automorphism(&self) -> ProjectiveMorphism<K> {
+        ProjectiveMorphism::from_matrix(&self.matrix).unwrap()
+    }
+
+    /// images of the generators of GL_N
+    pub fn generators() -> Vec<ProjectiveLinear<K, N>> {
+        let mut generators: Vec<ProjectiveLinear<K, N>> = vec![];
+        for gen in GeneralLinear::<K, N>::generators() {
+            let gen = ProjectiveLinear::from(gen);
+            if gen != ProjectiveLinear::one() && !generators.contains(&gen) {
+                generators.push(gen);
+            }
+        }
+        generators
+    }
+
+    /// |PGL_N(F_q)| = |GL_N(F_q)| / (q - 1)
+    pub fn order() -> usize {
+        GeneralLinear::<K, N>::order() / (K::order() as usize - 1)
+    }
+
+    fn normalized(matrix: Matrix<K>) -> ProjectiveLinear<K, N> {
+        let first = matrix
+            .rows()
+            .iter()
+            .flatten()
+            .find(|entry| **entry != K::zero())
+            .cloned()
+            .unwrap();
+        let rows = matrix
+            .into_rows()
+            .into_iter()
+            .map(|row| row.into_iter().map(|entry| entry / first.clone()).collect())
+            .collect();
+        ProjectiveLinear {
+            matrix: Matrix::new(rows).unwrap(),
+        }
+    }
+}
+
+/// the class of a matrix modulo scalars
+impl<K: FiniteField, const N: usize> From<GeneralLinear<K, N>> for ProjectiveLinear<K, N> {
+    fn from(g: GeneralLinear<K, N>) -> ProjectiveLinear<K, N> {
+        ProjectiveLinear::normalized(g.matrix)
+    }
+}
+
+impl<K: FiniteField, const N: usize> Mul for ProjectiveLinear<K, N> {
+    type Output = ProjectiveLinear<K, N>;
+    fn mul(self, other: ProjectiveLinear<K, N>) -> ProjectiveLinear<K, N> {
+        ProjectiveLinear::normalized(self.matrix * other.matrix)
+    }
+}
+
+impl<K: FiniteField, const N: usize> Group for ProjectiveLinear<K, N> {
+    fn one() -> ProjectiveLinear<K, N> {
+        ProjectiveLinear {
+            matrix: Matrix::identity(N),
+        }
+    }
+
+    fn inverse(self) -> ProjectiveLinear<K, N> {
+        ProjectiveLinear::normalized(self.matrix.inverse().unwrap())
+    }
+}
+
+impl<K: FiniteField, const N: usize> FiniteGroup for ProjectiveLinear<K, N> {}
+
+impl<K, const N: usize> fmt::Display for ProjectiveLinear<K, N>
+where
+    K: FiniteField + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.matrix)
+    }
+}
+
+/// an element generating the cyclic group K^*
+fn primitive_element<K: FiniteField>() -> K {
+    let units = K::order() as usize - 1;
+    K::elements()
+        .into_iter()
+        .find(|a| {
+            if *a == K::zero() {
+                return false;
+            }
+            let mut power = a.clone();
+            let mut order = 1;
+            while power != <K as Field>::one() {
+                power = power * a.clone();
+                order += 1;
+            }
+            order == units
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod linear_tests {
+    use super::{GeneralLinear, ProjectiveLinear};
+    use crate::{
+        galois_field::GaloisField,
+        group::{Group, SubGroup},
+        matrix::Matrix,
+        prime_field::PrimeField,
+    };
+
+    type F3 = PrimeField<3>;
+
+    #[test]
+    fn orders() {
+        assert_eq!(GeneralLinear::<PrimeField<2>, 3>::order(), 168);
+        assert_eq!(ProjectiveLinear::<F3, 2>::order(), 24);
+        let gl = SubGroup::generated_by(GeneralLinear::<F3, 2>::generators());
+        assert_eq!(gl.order(), GeneralLinear::<F3, 2>::order());
+        let pgl = SubGroup::generated_by(ProjectiveLinear::<F3, 2>::generators());
+        assert_eq!(pgl.order(), 24);
+        type F4 = GaloisField<2, 2>;
+        let gl = SubGroup::generated_by(GeneralLinear::<F4, 2>::generators());
+        assert_eq!(gl.order(), 180)
+    }
+
+    #[test]
+    fn scalars_are_trivial_in_pgl() {
+        let scalar = Matrix::new(vec![
+            vec![F3::new(2), F3::new(0)],
+            vec![F3::new(0), F3::new(2)],
+        ])
+        .unwrap();
+        assert!(ProjectiveLinear::<F3, 2>::new(scalar.clone()).unwrap() == ProjectiveLinear::one());
+        assert!(GeneralLinear::<F3, 2>::new(scalar).unwrap() != GeneralLinear::one());
+        let singular = Matrix::new(vec![
+            vec![F3::new(1), F3::new(2)],
+            vec![F3::new(2), F3::new(1)],
+        ])
+        .unwrap();
+        assert!(GeneralLinear::<F3, 2>::new(singular).is_err())
+    }
+}
@@ -0,0 +1,11 @@
+pub mod cyclic;
+pub mod linear;
+pub mod permutation;
+pub mod permutation_group;
+pub mod product;
+
+pub use cyclic::Cyclic;
+pub use linear::{GeneralLinear, ProjectiveLinear};
+pub use permutation::Permutation;
+pub use permutation_group::PermutationGroup;
+pub use product::DirectProduct;
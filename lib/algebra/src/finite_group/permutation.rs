@@ -0,0 +1,264 @@
+use crate::{
+    arithmetic::lcm,
+    errors::Error,
+    group::{FiniteGroup, Group},
+};
+use std::{fmt, ops::Mul, str::FromStr};
+
+/// Permutation of the points 0, 1, 2, ...
+/// only finitely many points are moved, images[i] is the image of i
+/// trailing fixed points are removed, so derived equality compares permutations
+/// and the identity is the same in every degree
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    images: Vec<usize>,
+}
+
+impl Permutation {
+    /// the permutation i -> images[i], images has to be a bijection of 0..n
+    pub fn new(images: Vec<usize>) -> Result<Permutation, Error> {
+        let mut seen = vec![false; images.len()];
+        for image in images.iter() {
+            if *image >= images.len() || seen[*image] {
+                return Err(Error::InvalidPermutation);
+            }
+            seen[*image] = true;
+        }
+        Ok(Permutation::trimmed(images))
+    }
+
+    fn trimmed(mut images: Vec<usize>) -> Permutation {
+        while images
+            .last()
+            .is_some_and(|image| *image == images.len() - 1)
+        {
+            images.pop();
+        }
+        Permutation { images }
+    }
+
+    pub fn identity() -> Permutation {
+        Permutation { images: vec![] }
+    }
+
+    /// the cycle c_0 -> c_1 -> ... -> c_(k-1) -> c_0
+    pub fn cycle(points: &[usize]) -> Result<Permutation, Error> {
+        Permutation::from_cycles(&[points.to_vec()])
+    }
+
+    /// the transposition swapping i and j
+    pub fn transposition(i: usize, j: usize) -> Permutation {
+        Permutation::cycle(&[i, j]).unwrap_or_else(|_| Permutation::identity())
+    }
+
+    /// product of disjoint cycles
+    /// points may not repeat within or across cycles
+    pub fn from_cycles(cycles: &[Vec<usize>]) -> Result<Permutation, Error> {
+        let degree = cycles.iter().flatten().map(|i| i + 1).max().unwrap_or(0);
+        let mut images: Vec<usize> = (0..degree).collect();
+        let mut seen = vec![false; degree];
+        for cycle in cycles.iter() {
+            for (k, point) in cycle.iter().enumerate() {
+                if seen[*point] {
+                    return Err(Error::InvalidPermutation);
+                }
+                seen[*point] = true;
+                images[*point] = cycle[(k + 1) % cycle.len()];
+            }
+        }
+        Ok(Permutation::trimmed(images))
+    }
+
+    /// image of a point, points beyond the degree are fixed
+    pub fn apply(&self, point: usize) -> usize {
+        self.images.get(point).copied().unwrap_or(point)
+    }
+
+    /// one more than the largest moved point, 0 for the identity
+    pub fn degree(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// images of 0..n, n has to be at least the degree
+    pub fn images(&self, n: usize) -> Vec<usize> {
+        (0..n).map(|i| self.apply(i)).collect()
+    }
+
+    /// the first moved point, None for the identity
+    pub fn first_moved_point(&self) -> Option<usize> {
+        (0..self.degree()).find(|i| self.apply(*i) != *i)
+    }
+
+    /// nontrivial cycles, each starting with its smallest point
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.degree()];
+        let mut cycles = vec![];
+        for start in 0..self.degree() {
+            if seen[start] || self.apply(start) == start {
+                continue;
+            }
+            let mut cycle = vec![start];
+            seen[start] = true;
+            let mut next = self.apply(start);
+            while next != start {
+                seen[next] = true;
+                cycle.push(next);
+                next = self.apply(next);
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    /// lcm of the cycle lengths
+    pub fn order(&self) -> usize {
+        self.cycles().iter().fold(1, |order, cycle| {
+            lcm(&(order as i64), &(cycle.len() as i64)) as usize
+        })
+    }
+
+    /// +1 for even, -1 for odd permutations
+    pub fn sign(&self) -> i64 {
+        let transpositions: usize = self.cycles().iter().map(|cycle| cycle.len() - 1).sum();
+        if transpositions.is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.sign() == 1
+    }
+
+    /// moves the entry at position i to position self(i)
+    pub fn permute<T: Clone>(&self, items: &[T]) -> Vec<T> {
+        let mut permuted = items.to_vec();
+        for (i, item) in items.iter().enumerate() {
+            permuted[self.apply(i)] = item.clone();
+        }
+        permuted
+    }
+}
+
+/// (a c_1 ... c_k)(b ...) with points separated by spaces, () for the identity
+impl fmt::Display for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return f.write_str("()");
+        }
+        for cycle in cycles.iter() {
+            let points: Vec<String> = cycle.iter().map(|point| point.to_string()).collect();
+            write!(f, "({})", points.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// parses cycle notation as printed by Display, e.g. (0 1 2)(3 4)
+impl FromStr for Permutation {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Permutation, Error> {
+        let s = s.trim();
+        if !s.starts_with('(') || !s.ends_with(')') {
+            return Err(Error::InvalidPermutation);
+        }
+        let cycles = s[1..s.len() - 1]
+            .split(')')
+            .map(|cycle| {
+                cycle
+                    .trim()
+                    .trim_start_matches('(')
+                    .split_whitespace()
+                    .map(|point| point.parse().map_err(|_| Error::InvalidPermutation))
+                    .collect::<Result<Vec<usize>, Error>>()
+            })
+            .collect::<Result<Vec<Vec<usize>>, Error>>()?;
+        Permutation::from_cycles(&cycles)
+    }
+}
+
+/// composition of maps, (self * other)(i) = self(other(i))
+/// so other is applied first
+impl Mul for Permutation {
+    type Output = Permutation;
+    fn mul(self, other: Permutation) -> Permutation {
+        let degree = self.degree().max(other.degree());
+        Permutation::trimmed((0..degree).map(|i| self.apply(other.apply(i))).collect())
+    }
+}
+
+impl Group for Permutation {
+    fn one() -> Permutation {
+        Permutation::identity()
+    }
+
+    fn inverse(self) -> Permutation {
+        let mut images = vec![0; self.degree()];
+        for (i, image) in self.images.iter().enumerate() {
+            images[*image] = i;
+        }
+        Permutation { images }
+    }
+}
+
+/// finitely many permutations move only finitely many points,
+/// so they generate a subgroup of a finite symmetric group
+impl FiniteGroup for Permutation {}
+
+#[cfg(test)]
+mod permutation_tests {
+    use super::Permutation;
+    use crate::group::Group;
+
+    #[test]
+    fn cycle_notation() {
+        let sigma: Permutation = "(0 2 1)(3 4)".parse().unwrap();
+        assert_eq!(sigma.apply(0), 2);
+        assert_eq!(sigma.apply(1), 0);
+        assert_eq!(sigma.apply(5), 5);
+        assert_eq!(sigma.to_string(), "(0 2 1)(3 4)");
+        assert_eq!(Permutation::identity().to_string(), "()");
+        assert_eq!(
+            "()".parse::<Permutation>().unwrap(),
+            Permutation::identity()
+        );
+        assert!("(0 1)(1 2)".parse::<Permutation>().is_err());
+        assert!(Permutation::new(vec![0, 0]).is_err())
+    }
+
+    #[test]
+    fn composition() {
+        // (0 1)(1 2) applies (1 2) first
+        let product = Permutation::transposition(0, 1) * Permutation::transposition(1, 2);
+        assert_eq!(product, Permutation::cycle(&[0, 1, 2]).unwrap());
+        assert_eq!(
+            product.clone() * product.clone().inverse(),
+            Permutation::one()
+        );
+        // fixed trailing points do not matter
+        assert_eq!(
+            Permutation::new(vec![1, 0, 2, 3]).unwrap(),
+            Permutation::transposition(0, 1)
+        )
+    }
+
+    #[test]
+    fn order_and_sign() {
+        let sigma = Permutation::from_cycles(&[vec![0, 1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(sigma.order(), 6);
+        assert_eq!(sigma.sign(), -1);
+        assert!(Permutation::cycle(&[0, 1, 2]).unwrap().is_even());
+        assert_eq!(
+            Permutation::cycle(&[0, 1, 2])
+                .unwrap()
+                .permute(&['a', 'b', 'c']),
+            vec!['c', 'a', 'b']
+        )
+    }
+}
@@ -0,0 +1,325 @@
+use super::Permutation;
+use crate::group::{Group, SubGroup};
+use std::fmt;
+
+/// One level of a stabilizer chain
+/// generators fix all earlier base points,
+/// transversal[b] maps the base point to b for every b in its orbit
+#[derive(Clone, Debug)]
+struct Level {
+    base_point: usize,
+    generators: Vec<Permutation>,
+    transversal: Vec<Option<Permutation>>,
+}
+
+impl Level {
+    fn new(base_point: usize, degree: usize) -> Level {
+        let mut transversal = vec![None; degree];
+        transversal[base_point] = Some(Permutation::identity());
+        Level {
+            base_point,
+            generators: vec![],
+            transversal,
+        }
+    }
+
+    fn orbit(&self) -> Vec<usize> {
+        (0..self.transversal.len())
+            .filter(|point| self.transversal[*point].is_some())
+            .collect()
+    }
+
+    /// adds a generator and extends the orbit and transversal
+    fn add_generator(&mut self, generator: Permutation) {
+        self.generators.push(generator);
+        let mut queue = self.orbit();
+        while let Some(point) = queue.pop() {
+            let to_point = self.transversal[point].clone().unwrap();
+            for gen in self.generators.iter() {
+                let image = gen.apply(point);
+                if self.transversal[image].is_none() {
+                    self.transversal[image] = Some(gen.clone() * to_point.clone());
+                    queue.push(image);
+                }
+            }
+        }
+    }
+}
+
+/// Permutation group on the points 0..degree given by generators
+/// a base and strong generating set are computed with the Schreier-Sims algorithm
+/// so order and membership do not need to enumerate the elements
+#[derive(Clone, Debug)]
+pub struct PermutationGroup {
+    degree: usize,
+    generators: Vec<Permutation>,
+    levels: Vec<Level>,
+}
+
+impl PermutationGroup {
+    /// the group generated by permutations of 0..degree
+    /// generators moving points outside of 0..degree are ignored
+    pub fn generated_by(degree: usize, generators: Vec<Permutation>) -> PermutationGroup {
+        let generators: Vec<Permutation> = generators
+            .into_iter()
+            .filter(|gen| gen.degree() <= degree && !gen.is_identity())
+            .collect();
+        let mut group = PermutationGroup {
+            degree,
+            generators: generators.clone(),
+            levels: vec![],
+        };
+        for gen in generators.into_iter() {
+            if let Some((residue, level)) = group.residue(&gen) {
+                group.extend(residue, level);
+            }
+        }
+        group
+    }
+
+    /// the symmetric group S_n, generated by (0 1) and (0 1 ... n-1)
+    pub fn symmetric(n: usize) -> PermutationGroup {
+        let all: Vec<usize> = (0..n).collect();
+        let generators = vec![
+            Permutation::transposition(0, 1),
+            Permutation::cycle(&all).unwrap(),
+        ];
+        PermutationGroup::generated_by(n, generators)
+    }
+
+    /// the alternating group A_n, generated by the 3-cycles (0 1 i)
+    pub fn alternating(n: usize) -> PermutationGroup {
+        let generators = (2..n)
+            .map(|i| Permutation::cycle(&[0, 1, i]).unwrap())
+            .collect();
+        PermutationGroup::generated_by(n, generators)
+    }
+
+    /// the cyclic group generated by (0 1 ... n-1)
+    pub fn cyclic(n: usize) -> PermutationGroup {
+        let all: Vec<usize> = (0..n).collect();
+        PermutationGroup::generated_by(n, vec![Permutation::cycle(&all).unwrap()])
+    }
+
+    /// G x H acting on 0..m+n, H moves the points m,...,m+n-1
+    pub fn direct_product(&self, other: &PermutationGroup) -> PermutationGroup {
+        let shift = self.degree;
+        let mut generators = self.generators.clone();
+        for gen in other.generators.iter() {
+            let images = (0..shift)
+                .chain(gen.images(other.degree).into_iter().map(|i| i + shift))
+                .collect();
+            generators.push(Permutation::new(images).unwrap());
+        }
+        PermutationGroup::generated_by(self.degree + other.degree, generators)
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn generators(&self) -> &[Permutation] {
+        &self.generators
+    }
+
+    /// the base points b_1,...,b_k, only the identity fixes all of them
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|level| level.base_point).collect()
+    }
+
+    /// generators of all stabilizers in the chain
+    pub fn strong_generators(&self) -> Vec<Permutation> {
+        let mut strong: Vec<Permutation> = vec![];
+        for level in self.levels.iter() {
+            for gen in level.generators.iter() {
+                if !strong.contains(gen) {
+                    strong.push(gen.clone());
+                }
+            }
+        }
+        strong
+    }
+
+    /// product of the lengths of the basic orbits
+    pub fn order(&self) -> u128 {
+        self.levels
+            .iter()
+            .map(|level| level.orbit().len() as u128)
+            .product()
+    }
+
+    /// sifting through the stabilizer chain ends in the identity
+    pub fn contains(&self, elem: &Permutation) -> bool {
+        elem.degree() <= self.degree && self.residue(elem).is_none()
+    }
+
+    /// the orbit of a point
+    pub fn orbit(&self, point: usize) -> Vec<usize> {
+        let mut orbit = vec![point];
+        let mut next = 0;
+        while next < orbit.len() {
+            for gen in self.generators.iter() {
+                let image = gen.apply(orbit[next]);
+                if !orbit.contains(&image) {
+                    orbit.push(image);
+                }
+            }
+            next += 1;
+        }
+        orbit
+    }
+
+    pub fn is_subgroup_of(&self, other: &PermutationGroup) -> bool {
+        self.generators.iter().all(|gen| other.contains(gen))
+    }
+
+    /// all elements, only sensible for small groups
+    pub fn to_subgroup(&self) -> SubGroup<Permutation> {
+        SubGroup::generated_by(self.generators.clone())
+    }
+
+    /// sifts elem through the chain
+    /// None if it lies in the group,
+    /// otherwise the remaining permutation and the level where sifting stopped
+    fn residue(&self, elem: &Permutation) -> Option<(Permutation, usize)> {
+        self.residue_from(elem, 0)
+    }
+
+    /// sifts elem through the levels from start on
+    fn residue_from(&self, elem: &Permutation, start: usize) -> Option<(Permutation, usize)> {
+        let mut residue = elem.clone();
+        for (i, level) in self.levels.iter().enumerate().skip(start) {
+            let image = residue.apply(level.base_point);
+            match &level.transversal[image] {
+                None => return Some((residue, i)),
+                Some(to_image) => residue = to_image.clone().inverse() * residue,
+            }
+        }
+        if residue.is_identity() {
+            None
+        } else {
+            Some((residue, self.levels.len()))
+        }
+    }
+
+    /// adds a new strong generator to the levels 0..=level
+    /// then completes the chain, Schreier generators that do not sift are added recursively
+    fn extend(&mut self, generator: Permutation, level: usize) {
+        if level == self.levels.len() {
+            let base_point = generator.first_moved_point().unwrap();
+            self.levels.push(Level::new(base_point, self.degree));
+        }
+        for l in 0..=level {
+            if !self.levels[l].generators.contains(&generator) {
+                self.levels[l].add_generator(generator.clone());
+            }
+        }
+        for l in (0..=level).rev() {
+            self.complete_level(l);
+        }
+    }
+
+    /// checks all Schreier generators u_(s(b))^-1 s u_b of a level
+    fn complete_level(&mut self, l: usize) {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            let level = self.levels[l].clone();
+            'search: for point in level.orbit() {
+                let to_point = level.transversal[point].clone().unwrap();
+                for gen in level.generators.iter() {
+                    let image = gen.apply(point);
+                    let to_image = level.transversal[image].clone().unwrap();
+                    let schreier = to_image.inverse() * gen.clone() * to_point.clone();
+                    if let Some((residue, stopped)) = self.residue_from(&schreier, l + 1) {
+                        self.extend(residue, stopped);
+                        changed = true;
+                        break 'search;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// groups are equal if they contain each others generators
+impl PartialEq for PermutationGroup {
+    fn eq(&self, other: &PermutationGroup) -> bool {
+        self.is_subgroup_of(other) && other.is_subgroup_of(self)
+    }
+}
+
+impl fmt::Display for PermutationGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gen_str: Vec<String> = self
+            .generators
+            .iter()
+            .map(|gen| format!("{}", gen))
+            .collect();
+        write!(f, "<{}>", gen_str.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod permutation_group_tests {
+    use super::PermutationGroup;
+    use crate::finite_group::Permutation;
+
+    #[test]
+    fn symmetric_and_alternating_orders() {
+        for (n, factorial) in [(1, 1), (2, 2), (3, 6), (5, 120), (7, 5040)] {
+            assert_eq!(PermutationGroup::symmetric(n).order(), factorial);
+            assert_eq!(
+                PermutationGroup::alternating(n).order(),
+                (factorial / 2).max(1)
+            );
+        }
+        assert_eq!(PermutationGroup::symmetric(12).order(), 479_001_600)
+    }
+
+    #[test]
+    fn membership() {
+        let a5 = PermutationGroup::alternating(5);
+        assert!(a5.contains(&"(0 1)(2 3)".parse().unwrap()));
+        assert!(!a5.contains(&"(0 1)".parse().unwrap()));
+        assert!(!a5.contains(&"(0 5)".parse().unwrap()));
+        assert!(a5.is_subgroup_of(&PermutationGroup::symmetric(5)))
+    }
+
+    #[test]
+    fn small_groups() {
+        // the symmetries of a square, generated by a rotation and a reflection
+        let dihedral = PermutationGroup::generated_by(
+            4,
+            vec!["(0 1 2 3)".parse().unwrap(), "(1 3)".parse().unwrap()],
+        );
+        assert_eq!(dihedral.order(), 8);
+        assert_eq!(dihedral.to_subgroup().order(), 8);
+        assert!(!dihedral.contains(&Permutation::transposition(0, 1)));
+        assert_eq!(PermutationGroup::cyclic(6).order(), 6);
+        assert_eq!(dihedral.orbit(2).len(), 4)
+    }
+
+    #[test]
+    fn products() {
+        let product = PermutationGroup::symmetric(3).direct_product(&PermutationGroup::cyclic(4));
+        assert_eq!(product.order(), 24);
+        assert!(product.contains(&"(0 1)(3 5)(4 6)".parse().unwrap()));
+        assert!(!product.contains(&"(2 3)".parse().unwrap()))
+    }
+
+    #[test]
+    fn normal_subgroup_of_s4() {
+        // the Klein four group is normal in S_4 with quotient S_3
+        let s4 = PermutationGroup::symmetric(4).to_subgroup();
+        let klein = PermutationGroup::generated_by(
+            4,
+            vec!["(0 1)(2 3)".parse().unwrap(), "(0 2)(1 3)".parse().unwrap()],
+        )
+        .to_subgroup();
+        assert!(klein.is_normal_in(&s4));
+        assert_eq!(klein.quotient(&s4).unwrap().order(), 6);
+        let transposition = PermutationGroup::generated_by(4, vec!["(0 1)".parse().unwrap()]);
+        assert!(!transposition.to_subgroup().is_normal_in(&s4))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::group::{FiniteGroup, Group};
+use std::{fmt, ops::Mul};
+
+/// The direct product G x H with componentwise multiplication
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectProduct<G: Group, H: Group>(pub G, pub H);
+
+impl<G: Group, H: Group> DirectProduct<G, H> {
+    /// the inclusion of G as G x 1
+    pub fn left(g: G) -> DirectProduct<G, H> {
+        DirectProduct(g, H::one())
+    }
+
+    /// the inclusion of H as 1 x H
+    pub fn right(h: H) -> DirectProduct<G, H> {
+        DirectProduct(G::one(), h)
+    }
+}
+
+impl<G, H> fmt::Display for DirectProduct<G, H>
+where
+    G: Group + fmt::Display,
+    H: Group + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl<G: Group, H: Group> Mul for DirectProduct<G, H> {
+    type Output = DirectProduct<G, H>;
+    fn mul(self, other: DirectProduct<G, H>) -> DirectProduct<G, H> {
+        DirectProduct(self.0 * other.0, self.1 * other.1)
+    }
+}
+
+impl<G: Group, H: Group> Group for DirectProduct<G, H> {
+    fn one() -> DirectProduct<G, H> {
+        DirectProduct(G::one(), H::one())
+    }
+
+    fn inverse(self) -> DirectProduct<G, H> {
+        DirectProduct(self.0.inverse(), self.1.inverse())
+    }
+}
+
+impl<G: FiniteGroup, H: FiniteGroup> FiniteGroup for DirectProduct<G, H> {}
+
+#[cfg(test)]
+mod product_tests {
+    use super::DirectProduct;
+    use crate::{
+        finite_group::{Cyclic, Permutation},
+        group::SubGroup,
+    };
+
+    #[test]
+    fn chinese_remainder() {
+        // Z/2 x Z/3 is cyclic of order 6
+        let generator = DirectProduct(Cyclic::<2>::generator(), Cyclic::<3>::generator());
+        assert_eq!(SubGroup::generated_by(vec![generator]).order(), 6);
+        // Z/2 x Z/2 is not
+        let diagonal = DirectProduct(Cyclic::<2>::generator(), Cyclic::<2>::generator());
+        assert_eq!(SubGroup::generated_by(vec![diagonal]).order(), 2)
+    }
+
+    #[test]
+    fn factors_commute() {
+        let left = DirectProduct::<Permutation, Cyclic<4>>::left(Permutation::transposition(0, 1));
+        let right = DirectProduct::right(Cyclic::<4>::generator());
+        assert_eq!(left.clone() * right.clone(), right * left);
+        let group = SubGroup::generated_by(vec![
+            DirectProduct::left("(0 1 2)".parse().unwrap()),
+            DirectProduct::left(Permutation::transposition(0, 1)),
+            DirectProduct::right(Cyclic::<4>::generator()),
+        ]);
+        assert_eq!(group.order(), 24)
+    }
+}
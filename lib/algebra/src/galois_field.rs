@@ -0,0 +1,460 @@
+use super::{
+    errors::Error,
+    field::{Field, FiniteField},
+    group::AbelianGroup,
+    prime_field::PrimeField,
+    ring::Ring,
+};
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// The finite field GF(p^n) = F_p[a]/(f)
+/// f is the lexicographically smallest monic irreducible polynomial of degree n over F_p
+/// elements are stored as coefficients c_0 + c_1 a + ... + c_(n-1) a^(n-1)
+/// P has to be a prime and 1 <= N <= MAX_DEGREE (this is not checked)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GaloisField<const P: u64, const N: usize> {
+    coefficients: [u64; N],
+}
+
+/// largest supported degree n, the order p^n has to fit into a u64 anyway
+pub const MAX_DEGREE: usize = 63;
+
+/// polynomials of degree at most MAX_DEGREE in the const searches, lowest coefficient first
+type Coefficients = [u64; MAX_DEGREE + 1];
+
+impl<const P: u64, const N: usize> GaloisField<P, N> {
+    /// coefficients of f, computed once per field at compile time
+    const MODULUS: &'static Coefficients = &smallest_irreducible(P, N);
+
+    pub fn new(coefficients: [u64; N]) -> GaloisField<P, N> {
+        GaloisField {
+            coefficients: coefficients.map(|c| c % P),
+        }
+    }
+
+    pub fn coefficients(&self) -> [u64; N] {
+        self.coefficients
+    }
+
+    /// the class a of X, it generates GF(p^n) over F_p
+    /// but in general not the multiplicative group, see primitive_element
+    /// for n = 1 the modulus is X, so a = 0
+    pub fn generator() -> GaloisField<P, N> {
+        let mut coefficients = [0; N];
+        if N > 1 {
+            coefficients[1] = 1;
+        } else {
+            coefficients[0] = (P - Self::MODULUS[0]) % P;
+        }
+        GaloisField { coefficients }
+    }
+
+    /// the first element in the order of elements() that generates
+    /// the cyclic multiplicative group, i.e. has order p^n - 1
+    pub fn primitive_element() -> GaloisField<P, N> {
+        let order = Self::order() - 1;
+        let mut primes = vec![];
+        let mut rest = order;
+        let mut r = 2;
+        while r * r <= rest {
+            if rest % r == 0 {
+                primes.push(r);
+                while rest % r == 0 {
+                    rest /= r;
+                }
+            }
+            r += 1;
+        }
+        if rest > 1 {
+            primes.push(rest);
+        }
+        Self::elements()
+            .into_iter()
+            .skip(1)
+            .find(|x| {
+                primes
+                    .iter()
+                    .all(|r| x.pow_mod(order / r) != <GaloisField<P, N> as Ring>::one())
+            })
+            .unwrap()
+    }
+
+    /// x^n by repeated squaring
+    pub fn pow_mod(self, mut n: u64) -> GaloisField<P, N> {
+        let mut base = self;
+        let mut res = <GaloisField<P, N> as Ring>::one();
+        while n > 0 {
+            if n % 2 == 1 {
+                res = res * base;
+            }
+            base = base * base;
+            n /= 2;
+        }
+        res
+    }
+
+    /// the defining polynomial f, lowest coefficient first
+    pub fn modulus() -> &'static [u64] {
+        &Self::MODULUS[..=N]
+    }
+}
+
+const fn mul_mod_p(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 * b as u128) % p as u128) as u64
+}
+
+/// a^(p-2) = a^-1 in F_p
+const fn inverse_mod_p(a: u64, p: u64) -> u64 {
+    let mut res = 1;
+    let mut base = a;
+    let mut e = p - 2;
+    while e > 0 {
+        if e % 2 == 1 {
+            res = mul_mod_p(res, base, p);
+        }
+        base = mul_mod_p(base, base, p);
+        e /= 2;
+    }
+    res
+}
+
+/// g h modulo the monic f of degree n, for g and h of degree less than n
+/// Horner's scheme in the coefficients of g, so no product of degree 2n - 2 is needed
+const fn mul_mod(
+    g: &Coefficients,
+    h: &Coefficients,
+    f: &Coefficients,
+    n: usize,
+    p: u64,
+) -> Coefficients {
+    let mut res = [0; MAX_DEGREE + 1];
+    let mut i = n;
+    while i > 0 {
+        i -= 1;
+        // res = X res mod f
+        let top = res[n - 1];
+        let mut j = n - 1;
+        while j > 0 {
+            res[j] = (res[j - 1] + p - mul_mod_p(top, f[j], p)) % p;
+            j -= 1;
+        }
+        res[0] = (p - mul_mod_p(top, f[0], p)) % p;
+        // res = res + g_i h
+        let mut j = 0;
+        while j < n {
+            res[j] = (res[j] + mul_mod_p(g[i], h[j], p)) % p;
+            j += 1;
+        }
+    }
+    res
+}
+
+/// g^p modulo f by repeated squaring
+const fn frobenius(g: &Coefficients, f: &Coefficients, n: usize, p: u64) -> Coefficients {
+    let mut res = [0; MAX_DEGREE + 1];
+    res[0] = 1;
+    let mut base = *g;
+    let mut e = p;
+    while e > 0 {
+        if e % 2 == 1 {
+            res = mul_mod(&res, &base, f, n, p);
+        }
+        base = mul_mod(&base, &base, f, n, p);
+        e /= 2;
+    }
+    res
+}
+
+/// degree of g, None for g = 0
+const fn degree(g: &Coefficients) -> Option<usize> {
+    let mut i = MAX_DEGREE + 1;
+    while i > 0 {
+        i -= 1;
+        if g[i] != 0 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// checks if gcd(g, h) is constant by the euclidean algorithm
+const fn coprime(g: &Coefficients, h: &Coefficients, p: u64) -> bool {
+    let mut a = *g;
+    let mut b = *h;
+    loop {
+        let Some(deg_b) = degree(&b) else {
+            return matches!(degree(&a), Some(0));
+        };
+        // a = a mod b
+        let lead_inverse = inverse_mod_p(b[deg_b], p);
+        while let Some(deg_a) = degree(&a) {
+            if deg_a < deg_b {
+                break;
+            }
+            let factor = mul_mod_p(a[deg_a], lead_inverse, p);
+            let shift = deg_a - deg_b;
+            let mut j = 0;
+            while j <= deg_b {
+                a[shift + j] = (a[shift + j] + p - mul_mod_p(factor, b[j], p)) % p;
+                j += 1;
+            }
+        }
+        let rest = a;
+        a = b;
+        b = rest;
+    }
+}
+
+/// the monic polynomials of degree n over F_p are searched in lexicographic order
+/// f is irreducible iff it has no factor of degree d <= n/2,
+/// i.e. iff gcd(X^(p^d) - X, f) = 1 for these d
+const fn smallest_irreducible(p: u64, n: usize) -> Coefficients {
+    assert!(n >= 1 && n <= MAX_DEGREE, "unsupported extension degree");
+    let mut f = [0; MAX_DEGREE + 1];
+    f[n] = 1;
+    loop {
+        // X mod f
+        let mut power = [0; MAX_DEGREE + 1];
+        power[1] = 1;
+        let mut irreducible = true;
+        let mut d = 1;
+        while d <= n / 2 && irreducible {
+            power = frobenius(&power, &f, n, p);
+            let mut difference = power;
+            difference[1] = (difference[1] + p - 1) % p;
+            irreducible = coprime(&f, &difference, p);
+            d += 1;
+        }
+        if irreducible {
+            return f;
+        }
+        // next polynomial, the lowest coefficient runs fastest
+        let mut i = 0;
+        while f[i] == p - 1 {
+            f[i] = 0;
+            i += 1;
+        }
+        f[i] += 1;
+    }
+}
+
+impl<const P: u64, const N: usize> From<PrimeField<P>> for GaloisField<P, N> {
+    fn from(x: PrimeField<P>) -> GaloisField<P, N> {
+        let mut coefficients = [0; N];
+        coefficients[0] = x.value();
+        GaloisField { coefficients }
+    }
+}
+
+impl<const P: u64, const N: usize> From<i64> for GaloisField<P, N> {
+    fn from(n: i64) -> GaloisField<P, N> {
+        PrimeField::<P>::from(n).into()
+    }
+}
+
+impl<const P: u64, const N: usize> fmt::Display for GaloisField<P, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| **c != 0)
+            .map(|(i, c)| match i {
+                0 => format!("{}", c),
+                1 => format!("{}a", c),
+                _ => format!("{}a^{}", c, i),
+            })
+            .collect();
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join("+"))
+        }
+    }
+}
+
+impl<const P: u64, const N: usize> Add for GaloisField<P, N> {
+    type Output = GaloisField<P, N>;
+    fn add(self, other: GaloisField<P, N>) -> GaloisField<P, N> {
+        let mut coefficients = self.coefficients;
+        for (c, d) in coefficients.iter_mut().zip(other.coefficients.iter()) {
+            *c = (*c + d) % P;
+        }
+        GaloisField { coefficients }
+    }
+}
+
+impl<const P: u64, const N: usize> Sub for GaloisField<P, N> {
+    type Output = GaloisField<P, N>;
+    fn sub(self, other: GaloisField<P, N>) -> GaloisField<P, N> {
+        self + (-other)
+    }
+}
+
+impl<const P: u64, const N: usize> Neg for GaloisField<P, N> {
+    type Output = GaloisField<P, N>;
+    fn neg(self) -> GaloisField<P, N> {
+        GaloisField {
+            coefficients: self.coefficients.map(|c| (P - c) % P),
+        }
+    }
+}
+
+impl<const P: u64, const N: usize> Mul for GaloisField<P, N> {
+    type Output = GaloisField<P, N>;
+    fn mul(self, other: GaloisField<P, N>) -> GaloisField<P, N> {
+        // Horner's scheme in the coefficients of self, reducing X res by the modulus
+        let modulus = Self::MODULUS;
+        let mut coefficients = [0; N];
+        for c in self.coefficients.iter().rev() {
+            let top = coefficients[N - 1];
+            for j in (1..N).rev() {
+                coefficients[j] = (coefficients[j - 1] + P - mul_mod_p(top, modulus[j], P)) % P;
+            }
+            coefficients[0] = (P - mul_mod_p(top, modulus[0], P)) % P;
+            for (res, d) in coefficients.iter_mut().zip(other.coefficients.iter()) {
+                *res = (*res + mul_mod_p(*c, *d, P)) % P;
+            }
+        }
+        GaloisField { coefficients }
+    }
+}
+
+/// panics when dividing by zero
+impl<const P: u64, const N: usize> Div for GaloisField<P, N> {
+    type Output = GaloisField<P, N>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: GaloisField<P, N>) -> GaloisField<P, N> {
+        self * other.inverse()
+    }
+}
+
+impl<const P: u64, const N: usize> AbelianGroup for GaloisField<P, N> {
+    fn zero() -> GaloisField<P, N> {
+        GaloisField {
+            coefficients: [0; N],
+        }
+    }
+}
+
+impl<const P: u64, const N: usize> Ring for GaloisField<P, N> {
+    fn one() -> GaloisField<P, N> {
+        let mut coefficients = [0; N];
+        coefficients[0] = 1;
+        GaloisField { coefficients }
+    }
+}
+
+impl<const P: u64, const N: usize> Field for GaloisField<P, N> {
+    fn one() -> GaloisField<P, N> {
+        <GaloisField<P, N> as Ring>::one()
+    }
+
+    /// x^(q-2) = x^-1 in a field with q elements
+    fn inverse(self) -> GaloisField<P, N> {
+        if self == GaloisField::zero() {
+            panic!("{}", Error::DivisionByZero)
+        }
+        self.pow_mod(Self::order() - 2)
+    }
+}
+
+impl<const P: u64, const N: usize> FiniteField for GaloisField<P, N> {
+    fn characteristic() -> u64 {
+        P
+    }
+
+    fn order() -> u64 {
+        P.pow(N as u32)
+    }
+
+    fn elements() -> Vec<GaloisField<P, N>> {
+        (0..Self::order())
+            .map(|mut index| {
+                let mut coefficients = [0; N];
+                for coeff in coefficients.iter_mut() {
+                    *coeff = index % P;
+                    index /= P;
+                }
+                GaloisField { coefficients }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod galois_field_tests {
+    use super::GaloisField;
+    use crate::{
+        field::{Field, FiniteField},
+        group::AbelianGroup,
+    };
+
+    type F4 = GaloisField<2, 2>;
+    type F27 = GaloisField<3, 3>;
+
+    #[test]
+    fn modulus_f4() {
+        // x^2 + x + 1 is the only irreducible quadric over F_2
+        assert_eq!(F4::modulus(), [1, 1, 1])
+    }
+
+    #[test]
+    fn moduli() {
+        // x^2 + 1 is irreducible over F_3 and x^3 + 2x + 1 is the smallest cubic
+        assert_eq!(GaloisField::<3, 2>::modulus(), [1, 0, 1]);
+        assert_eq!(F27::modulus(), [1, 2, 0, 1]);
+        assert_eq!(GaloisField::<2, 6>::modulus(), [1, 1, 0, 0, 0, 0, 1]);
+        assert_eq!(GaloisField::<5, 1>::modulus(), [0, 1])
+    }
+
+    #[test]
+    fn primitive_elements() {
+        // a is a root of x^2 + 1 and has order 4 in F_9^*
+        type F9 = GaloisField<3, 2>;
+        assert_eq!(F9::generator().pow_mod(4), <F9 as Field>::one());
+        let g = F9::primitive_element();
+        let powers: Vec<F9> = (0..8).map(|k| g.pow_mod(k)).collect();
+        assert!(F9::elements().iter().skip(1).all(|x| powers.contains(x)));
+        // 2 is the smallest primitive root modulo 5
+        assert_eq!(
+            GaloisField::<5, 1>::primitive_element(),
+            GaloisField::new([2])
+        )
+    }
+
+    #[test]
+    fn generator_order() {
+        // the multiplicative group of F_4 is cyclic of order 3
+        let a = F4::generator();
+        assert_ne!(a.pow_mod(1), <F4 as Field>::one());
+        assert_eq!(a.pow_mod(3), <F4 as Field>::one())
+    }
+
+    #[test]
+    fn inverses() {
+        for x in F27::elements().into_iter().skip(1) {
+            assert_eq!(x * x.inverse(), <F27 as Field>::one())
+        }
+    }
+
+    #[test]
+    fn frobenius_fixes_prime_field() {
+        // x^p = x exactly for x in F_p
+        let fixed = F27::elements()
+            .into_iter()
+            .filter(|x| x.pow_mod(3) == *x)
+            .count();
+        assert_eq!(fixed, 3)
+    }
+
+    #[test]
+    fn characteristic() {
+        let one = <F27 as Field>::one();
+        assert_eq!(one + one + one, F27::zero())
+    }
+}
@@ -0,0 +1,190 @@
+use super::{
+    domain::{EuclideanDomain, IntegralDomain, UniqueFactorizationDomain},
+    errors::Error,
+    group::AbelianGroup,
+    ring::{CommutativeRing, Ring},
+};
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Gaussian integers re + i im in Z[i]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GaussianInteger {
+    pub re: i64,
+    pub im: i64,
+}
+
+impl GaussianInteger {
+    pub fn new(re: i64, im: i64) -> GaussianInteger {
+        GaussianInteger { re, im }
+    }
+
+    /// the imaginary unit
+    pub fn i() -> GaussianInteger {
+        GaussianInteger { re: 0, im: 1 }
+    }
+
+    pub fn conj(&self) -> GaussianInteger {
+        GaussianInteger {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// N(a + bi) = a^2 + b^2, the Euclidean function
+    pub fn norm(&self) -> i64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// 1, i, -1 and -i
+    pub fn is_unit(&self) -> bool {
+        self.norm() == 1
+    }
+}
+
+/// closest integer to num / den for den > 0, rounding halves up
+fn round_div(num: i64, den: i64) -> i64 {
+    (2 * num + den).div_euclid(2 * den)
+}
+
+impl From<i64> for GaussianInteger {
+    fn from(re: i64) -> GaussianInteger {
+        GaussianInteger { re, im: 0 }
+    }
+}
+
+impl fmt::Display for GaussianInteger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.im == 0 {
+            self.re.fmt(f)
+        } else if self.im < 0 {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl Add for GaussianInteger {
+    type Output = GaussianInteger;
+    fn add(self, other: GaussianInteger) -> GaussianInteger {
+        GaussianInteger {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl Sub for GaussianInteger {
+    type Output = GaussianInteger;
+    fn sub(self, other: GaussianInteger) -> GaussianInteger {
+        GaussianInteger {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
+impl Mul for GaussianInteger {
+    type Output = GaussianInteger;
+    fn mul(self, other: GaussianInteger) -> GaussianInteger {
+        GaussianInteger {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+impl Neg for GaussianInteger {
+    type Output = GaussianInteger;
+    fn neg(self) -> GaussianInteger {
+        GaussianInteger {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl AbelianGroup for GaussianInteger {
+    fn zero() -> GaussianInteger {
+        GaussianInteger { re: 0, im: 0 }
+    }
+}
+
+impl Ring for GaussianInteger {
+    fn one() -> GaussianInteger {
+        GaussianInteger { re: 1, im: 0 }
+    }
+}
+
+impl CommutativeRing for GaussianInteger {}
+
+impl IntegralDomain for GaussianInteger {}
+
+impl UniqueFactorizationDomain for GaussianInteger {}
+
+/// the quotient is self / divisor rounded to the closest Gaussian integer
+/// so N(r) <= N(divisor) / 2
+/// normal forms lie in the quadrant re > 0, im >= 0
+impl EuclideanDomain for GaussianInteger {
+    fn div_rem(
+        &self,
+        divisor: &GaussianInteger,
+    ) -> Result<(GaussianInteger, GaussianInteger), Error> {
+        let norm = divisor.norm();
+        if norm == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        let numerator = *self * divisor.conj();
+        let quotient = GaussianInteger {
+            re: round_div(numerator.re, norm),
+            im: round_div(numerator.im, norm),
+        };
+        Ok((quotient, *self - quotient * *divisor))
+    }
+
+    fn normal_unit(&self) -> GaussianInteger {
+        let (re, im) = (self.re, self.im);
+        if re > 0 && im >= 0 || re == 0 && im == 0 {
+            GaussianInteger::one()
+        } else if re <= 0 && im > 0 {
+            -GaussianInteger::i()
+        } else if re < 0 && im <= 0 {
+            -GaussianInteger::one()
+        } else {
+            GaussianInteger::i()
+        }
+    }
+}
+
+#[cfg(test)]
+mod gaussian_integer_tests {
+    use super::GaussianInteger;
+    use crate::domain::EuclideanDomain;
+
+    #[test]
+    fn small_remainder() {
+        let a = GaussianInteger::new(27, -23);
+        let b = GaussianInteger::new(8, 1);
+        let (q, r) = a.div_rem(&b).unwrap();
+        assert_eq!(q * b + r, a);
+        assert!(2 * r.norm() <= b.norm())
+    }
+
+    #[test]
+    fn normal_forms_in_first_quadrant() {
+        for z in [
+            GaussianInteger::new(2, 3),
+            GaussianInteger::new(-3, 2),
+            GaussianInteger::new(-2, -3),
+            GaussianInteger::new(3, -2),
+            GaussianInteger::new(0, -5),
+        ] {
+            let normal = z.normalize();
+            assert!(normal.re > 0 && normal.im >= 0);
+            assert_eq!(normal.norm(), z.norm())
+        }
+    }
+}
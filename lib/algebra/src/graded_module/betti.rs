@@ -0,0 +1,127 @@
+use super::FreeModule;
+use std::{collections::BTreeMap, fmt};
+
+/// Graded Betti numbers beta_(i,j) of a minimal free resolution
+/// beta_(i,j) is the number of basis elements of degree j in F_i
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BettiTable {
+    betti: BTreeMap<(usize, i64), usize>,
+}
+
+impl BettiTable {
+    /// table of the free modules F_0, F_1, ... of a resolution
+    pub fn new(modules: &[FreeModule]) -> BettiTable {
+        let mut betti = BTreeMap::new();
+        for (i, module) in modules.iter().enumerate() {
+            for deg in module.degrees().iter() {
+                *betti.entry((i, *deg)).or_insert(0) += 1;
+            }
+        }
+        BettiTable { betti }
+    }
+
+    /// beta_(i,j)
+    pub fn get(&self, i: usize, j: i64) -> usize {
+        self.betti.get(&(i, j)).copied().unwrap_or(0)
+    }
+
+    /// rank of F_i
+    pub fn total(&self, i: usize) -> usize {
+        self.betti
+            .iter()
+            .filter(|((k, _), _)| *k == i)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// the largest i with F_i != 0, the projective dimension of the module
+    /// None for the zero module
+    pub fn projective_dimension(&self) -> Option<usize> {
+        self.betti.keys().map(|(i, _)| *i).max()
+    }
+
+    /// Castelnuovo-Mumford regularity, the largest j - i with beta_(i,j) != 0
+    /// None for the zero module
+    pub fn regularity(&self) -> Option<i64> {
+        self.betti.keys().map(|(i, j)| j - *i as i64).max()
+    }
+}
+
+/// Macaulay2 style table, column i and row d hold beta_(i,i+d)
+/// zeros are shown as dots
+///        0 1 2
+/// total: 1 3 2
+///     0: 1 . .
+///     1: . 3 2
+impl fmt::Display for BettiTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let length = self.projective_dimension().map(|i| i + 1).unwrap_or(0);
+        let rows: Vec<i64> = match (
+            self.betti.keys().map(|(i, j)| j - *i as i64).min(),
+            self.regularity(),
+        ) {
+            (Some(low), Some(high)) => (low..=high).collect(),
+            _ => vec![],
+        };
+        let entry = |count: usize| match count {
+            0 => ".".to_string(),
+            _ => count.to_string(),
+        };
+
+        let mut lines: Vec<(String, Vec<String>)> = vec![
+            (String::new(), (0..length).map(|i| i.to_string()).collect()),
+            (
+                "total:".to_string(),
+                (0..length).map(|i| self.total(i).to_string()).collect(),
+            ),
+        ];
+        for d in rows.iter() {
+            lines.push((
+                format!("{d}:"),
+                (0..length)
+                    .map(|i| entry(self.get(i, i as i64 + d)))
+                    .collect(),
+            ));
+        }
+
+        let label_width = lines
+            .iter()
+            .map(|(label, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        let widths: Vec<usize> = (0..length)
+            .map(|i| lines.iter().map(|(_, row)| row[i].len()).max().unwrap_or(0))
+            .collect();
+        for (k, (label, row)) in lines.iter().enumerate() {
+            if k > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{label:>label_width$}")?;
+            for (value, width) in row.iter().zip(widths.iter()) {
+                write!(f, " {value:>width$}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod betti_tests {
+    use super::BettiTable;
+    use crate::graded_module::FreeModule;
+
+    #[test]
+    fn display() {
+        let table = BettiTable::new(&[
+            FreeModule::new(4, vec![0]),
+            FreeModule::new(4, vec![2, 2, 2]),
+            FreeModule::new(4, vec![3, 3]),
+        ]);
+        assert_eq!(
+            table.to_string(),
+            "       0 1 2\ntotal: 1 3 2\n    0: 1 . .\n    1: . 3 2"
+        );
+        assert_eq!(table.regularity(), Some(1));
+        assert_eq!(table.projective_dimension(), Some(2))
+    }
+}
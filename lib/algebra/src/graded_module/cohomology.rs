@@ -0,0 +1,238 @@
+use super::{FreeModule, FreeResolution, GradedMap, GradedModule};
+use crate::{field::Field, matrix::Matrix, polynomials::Monomial, projective::ProjectiveScheme};
+use std::collections::BTreeMap;
+
+impl FreeModule {
+    /// K-basis of the degree d part, pairs (i, m) standing for m e_i
+    fn monomial_basis(&self, d: i64) -> Vec<(usize, Vec<usize>)> {
+        self.degrees()
+            .iter()
+            .enumerate()
+            .filter(|(_, deg)| **deg <= d)
+            .flat_map(|(i, deg)| {
+                Monomial::<i64>::all_of_degree(self.num_variables(), (d - deg) as usize)
+                    .into_iter()
+                    .map(move |mono| (i, mono.powers()))
+            })
+            .collect()
+    }
+}
+
+impl<K: Field + Clone> GradedMap<K> {
+    /// the K-linear map in degree d with respect to the monomial bases
+    pub fn degree_part(&self, d: i64) -> Matrix<K> {
+        let source_basis = self.source().monomial_basis(d);
+        let target_basis: BTreeMap<(usize, Vec<usize>), usize> = self
+            .target()
+            .monomial_basis(d)
+            .into_iter()
+            .enumerate()
+            .map(|(row, elem)| (elem, row))
+            .collect();
+        let mut matrix = Matrix::zero(target_basis.len(), source_basis.len());
+        for (col, (j, powers)) in source_basis.into_iter().enumerate() {
+            let factor = Monomial::new(<K as Field>::one(), powers);
+            for (i, entry) in self.columns()[j].iter().enumerate() {
+                for term in entry.mul_monomial(&factor).into_terms() {
+                    let row = target_basis[&(i, term.powers())];
+                    matrix.set_entry(row, col, term.coefficient);
+                }
+            }
+        }
+        matrix
+    }
+}
+
+/// Cohomology of the coherent sheaf ~M on P^(n-1) attached to a graded module M
+/// over S = K[X_0,...,X_(n-1)], computed by local duality
+/// dim H^i_m(M)_d = dim Ext^(n-i)(M, S)_(-d-n)
+/// and the Ext modules are the cohomology of the dual of the minimal free resolution
+/// H^i(~M(d)) = H^(i+1)_m(M)_d for i > 0 and
+/// 0 -> H^0_m(M)_d -> M_d -> H^0(~M(d)) -> H^1_m(M)_d -> 0
+pub struct SheafCohomology<K: Field> {
+    module: GradedModule<K>,
+    resolution: FreeResolution<K>,
+    regularity: Option<i64>,
+}
+
+impl<K: Field + Clone> SheafCohomology<K> {
+    pub fn new(module: &GradedModule<K>) -> SheafCohomology<K> {
+        let resolution = module.minimal_free_resolution();
+        let regularity = resolution.betti_table().regularity();
+        SheafCohomology {
+            module: module.clone(),
+            resolution,
+            regularity,
+        }
+    }
+
+    /// dimension of Ext^j(M, S) in degree d
+    /// from the complex F_(j-1)* -> F_j* -> F_(j+1)*
+    pub fn ext_dimension(&self, j: usize, d: i64) -> usize {
+        let module = self.resolution.module(j).dual();
+        let dimension = module.monomial_basis(d).len();
+        let incoming = match self.resolution.differential(j) {
+            Some(map) => map.dual().degree_part(d).rank(),
+            None => 0,
+        };
+        let outgoing = match self.resolution.differential(j + 1) {
+            Some(map) => map.dual().degree_part(d).rank(),
+            None => 0,
+        };
+        dimension - incoming - outgoing
+    }
+
+    /// dimension of the local cohomology H^i_m(M) in degree d
+    pub fn local_cohomology_dimension(&self, i: usize, d: i64) -> usize {
+        let n = self.module.num_variables();
+        match n.checked_sub(i) {
+            Some(j) => self.ext_dimension(j, -d - n as i64),
+            None => 0,
+        }
+    }
+
+    /// dim H^i(~M(d))
+    /// H^i_m(M)_d vanishes for d + i > reg(M), so above the regularity
+    /// only H^0 is nonzero and agrees with the Hilbert function
+    pub fn dimension(&self, i: usize, d: i64) -> usize {
+        let Some(regularity) = self.regularity else {
+            return 0;
+        };
+        if d > regularity {
+            return match i {
+                0 => self.module.hilbert_function(d) as usize,
+                _ => 0,
+            };
+        }
+        match i {
+            0 => {
+                self.module.hilbert_function(d) as usize - self.local_cohomology_dimension(0, d)
+                    + self.local_cohomology_dimension(1, d)
+            }
+            _ => self.local_cohomology_dimension(i + 1, d),
+        }
+    }
+
+    /// sum_i (-1)^i dim H^i(~M(d)), this is the Hilbert polynomial at d
+    pub fn euler_characteristic(&self, d: i64) -> i64 {
+        (0..self.module.num_variables())
+            .map(|i| {
+                let dimension = self.dimension(i, d) as i64;
+                if i % 2 == 0 {
+                    dimension
+                } else {
+                    -dimension
+                }
+            })
+            .sum()
+    }
+
+    /// rows H^i for i = 0, ..., n-1 and columns d in degrees
+    pub fn table(&self, degrees: std::ops::RangeInclusive<i64>) -> Vec<Vec<usize>> {
+        (0..self.module.num_variables())
+            .map(|i| degrees.clone().map(|d| self.dimension(i, d)).collect())
+            .collect()
+    }
+}
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// cohomology of the twisted structure sheaves O_X(d)
+    pub fn sheaf_cohomology(&self) -> SheafCohomology<K> {
+        SheafCohomology::new(&self.coordinate_module())
+    }
+
+    /// dim H^i(X, O_X(d))
+    pub fn cohomology_dimension(&self, i: usize, d: i64) -> usize {
+        self.sheaf_cohomology().dimension(i, d)
+    }
+}
+
+#[cfg(test)]
+mod cohomology_tests {
+    use crate::{
+        prime_field::PrimeField,
+        projective::{ProjectiveMorphism, ProjectiveScheme},
+        rational::Rational,
+        test_helpers::{homogeneous, homogeneous_over},
+    };
+
+    #[test]
+    fn projective_plane() {
+        let cohomology = ProjectiveScheme::<Rational>::projective_space(3).sheaf_cohomology();
+        // h^0(O(d)) = (d+2 choose 2) and h^2(O(d)) = (-d-1 choose 2)
+        assert_eq!(
+            cohomology.table(-5..=2),
+            vec![
+                vec![0, 0, 0, 0, 0, 1, 3, 6],
+                vec![0, 0, 0, 0, 0, 0, 0, 0],
+                vec![6, 3, 1, 0, 0, 0, 0, 0],
+            ]
+        )
+    }
+
+    #[test]
+    fn plane_cubic() {
+        // y^2 z - x^3 - x z^2 is an elliptic curve over F_7
+        let cubic = homogeneous_over::<PrimeField<7>>(vec![
+            (1, vec![0, 2, 1]),
+            (-1, vec![3, 0, 0]),
+            (-1, vec![1, 0, 2]),
+        ]);
+        let curve = ProjectiveScheme::new(vec![cubic]).unwrap();
+        let cohomology = curve.sheaf_cohomology();
+        assert_eq!(
+            cohomology.table(-2..=2),
+            vec![vec![0, 0, 1, 3, 6], vec![6, 3, 1, 0, 0], vec![0; 5]]
+        );
+        for d in -2..=2 {
+            assert_eq!(cohomology.euler_characteristic(d), 3 * d);
+        }
+    }
+
+    #[test]
+    fn twisted_cubic() {
+        // O_X(d) = O_P1(3d), so h^1(O_X(-1)) = 2
+        let twisted = ProjectiveMorphism::<Rational>::veronese_embedding(1, 3).image();
+        assert_eq!(twisted.cohomology_dimension(1, -1), 2);
+        assert_eq!(twisted.cohomology_dimension(0, 2), 7);
+        assert_eq!(twisted.cohomology_dimension(1, 0), 0)
+    }
+
+    #[test]
+    fn embedded_point_does_not_change_cohomology() {
+        // (x^2, x y, x z) defines the line x = 0 with an embedded point
+        let x = |powers: Vec<usize>| homogeneous(vec![(1, powers)]);
+        let scheme =
+            ProjectiveScheme::new(vec![x(vec![2, 0, 0]), x(vec![1, 1, 0]), x(vec![1, 0, 1])])
+                .unwrap();
+        // the linear form x vanishes on the line but is nonzero in S/I
+        assert_eq!(scheme.coordinate_module().hilbert_function(1), 3);
+        assert_eq!(scheme.cohomology_dimension(0, 0), 1);
+        assert_eq!(scheme.cohomology_dimension(0, 1), 2);
+        assert_eq!(scheme.cohomology_dimension(1, -2), 1)
+    }
+
+    #[test]
+    fn surfaces() {
+        // the quadric P^1 x P^1 has canonical sheaf O(-2)
+        let quadric = ProjectiveScheme::<Rational>::projective_space(2)
+            .product(&ProjectiveScheme::projective_space(2))
+            .unwrap();
+        let cohomology = quadric.sheaf_cohomology();
+        assert_eq!(cohomology.dimension(0, 1), 4);
+        assert_eq!(cohomology.dimension(2, -2), 1);
+        assert_eq!(cohomology.dimension(1, -1), 0);
+        // a quartic surface over F_5 is a K3 surface with h^0 = h^2 = 1, h^1 = 0
+        let quartic = homogeneous_over::<PrimeField<5>>(vec![
+            (1, vec![4, 0, 0, 0]),
+            (1, vec![0, 4, 0, 0]),
+            (1, vec![0, 0, 4, 0]),
+            (1, vec![0, 0, 0, 4]),
+        ]);
+        let k3 = ProjectiveScheme::new(vec![quartic])
+            .unwrap()
+            .sheaf_cohomology();
+        assert_eq!(k3.table(0..=0), vec![vec![1], vec![0], vec![1], vec![0]]);
+        assert_eq!(k3.euler_characteristic(0), 2)
+    }
+}
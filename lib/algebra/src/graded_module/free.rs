@@ -0,0 +1,522 @@
+use super::groebner::{
+    add_vectors, is_zero_vector, minimal_generators, mul_vector, unit_vector, vector_degree,
+    zero_vector, ModuleBasis,
+};
+use crate::{
+    errors::Error,
+    field::Field,
+    hilbert::HilbertSeries,
+    polynomials::{HomogeneousPolynomial, Polynomial},
+};
+use std::fmt;
+
+/// Graded free module S(-d_1) + ... + S(-d_r) over S = K[X_0,...,X_(n-1)]
+/// the i-th basis element e_i has degree d_i
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FreeModule {
+    num_variables: usize,
+    degrees: Vec<i64>,
+}
+
+impl FreeModule {
+    pub fn new(num_variables: usize, degrees: Vec<i64>) -> FreeModule {
+        FreeModule {
+            num_variables,
+            degrees,
+        }
+    }
+
+    /// the ring S itself, generated in degree 0
+    pub fn ring(num_variables: usize) -> FreeModule {
+        FreeModule::new(num_variables, vec![0])
+    }
+
+    /// the zero module
+    pub fn zero(num_variables: usize) -> FreeModule {
+        FreeModule::new(num_variables, vec![])
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    pub fn rank(&self) -> usize {
+        self.degrees.len()
+    }
+
+    /// degrees of the basis elements
+    pub fn degrees(&self) -> &[i64] {
+        &self.degrees
+    }
+
+    /// the twist F(d), all basis elements have their degree lowered by d
+    pub fn shift(&self, d: i64) -> FreeModule {
+        FreeModule::new(
+            self.num_variables,
+            self.degrees.iter().map(|deg| deg - d).collect(),
+        )
+    }
+
+    /// F + G, the basis of F comes first
+    pub fn direct_sum(&self, other: &FreeModule) -> FreeModule {
+        let mut degrees = self.degrees.clone();
+        degrees.extend(other.degrees.iter());
+        FreeModule::new(self.num_variables.max(other.num_variables), degrees)
+    }
+
+    /// F (x) G, the basis element e_i (x) f_j sits at position i rank(G) + j
+    /// and has degree d_i + deg(f_j)
+    pub fn tensor(&self, other: &FreeModule) -> FreeModule {
+        let degrees = self
+            .degrees
+            .iter()
+            .flat_map(|deg| other.degrees.iter().map(move |other_deg| deg + other_deg))
+            .collect();
+        FreeModule::new(self.num_variables.max(other.num_variables), degrees)
+    }
+
+    /// F* = Hom(F, S) = S(d_1) + ... + S(d_r), the dual basis element e_i* has degree -d_i
+    pub fn dual(&self) -> FreeModule {
+        FreeModule::new(
+            self.num_variables,
+            self.degrees.iter().map(|deg| -deg).collect(),
+        )
+    }
+
+    /// Hom(F, G) = F* (x) G, where e_i* (x) f_j is the map sending e_i to f_j
+    pub fn hom(&self, other: &FreeModule) -> FreeModule {
+        self.dual().tensor(other)
+    }
+
+    /// dimension of the degree d part
+    pub fn hilbert_function(&self, d: i64) -> i64 {
+        let ring = HilbertSeries::from_monomial_ideal(&[], self.num_variables);
+        self.degrees
+            .iter()
+            .filter(|deg| **deg <= d)
+            .map(|deg| ring.hilbert_function((d - deg) as usize))
+            .sum()
+    }
+}
+
+/// sums of twists S(-d)^k with increasing d, 0 for the zero module
+impl fmt::Display for FreeModule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut degrees = self.degrees.clone();
+        degrees.sort();
+        degrees.dedup();
+        if degrees.is_empty() {
+            return f.write_str("0");
+        }
+        let summands: Vec<String> = degrees
+            .iter()
+            .map(|deg| {
+                let count = self.degrees.iter().filter(|other| *other == deg).count();
+                let twist = match deg {
+                    0 => "S".to_string(),
+                    _ => format!("S({})", -deg),
+                };
+                if count == 1 {
+                    twist
+                } else {
+                    format!("{twist}^{count}")
+                }
+            })
+            .collect();
+        f.write_str(&summands.join(" + "))
+    }
+}
+
+/// Homogeneous map of degree 0 between graded free modules
+/// given by a matrix of homogeneous polynomials,
+/// column j is the image of the j-th basis element of the source
+/// so a nonzero entry (i, j) has degree deg(source_j) - deg(target_i)
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradedMap<K: Field> {
+    source: FreeModule,
+    target: FreeModule,
+    columns: Vec<Vec<Polynomial<K>>>,
+}
+
+impl<K: Field + Clone> GradedMap<K> {
+    /// map into target given by the rows of its matrix
+    /// the degrees of the source are read off from the entries,
+    /// zero columns get degree 0
+    pub fn new(
+        target: FreeModule,
+        rows: Vec<Vec<HomogeneousPolynomial<K>>>,
+    ) -> Result<GradedMap<K>, Error> {
+        let num_columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        let degrees = (0..num_columns)
+            .map(|j| {
+                rows.iter()
+                    .zip(target.degrees.iter())
+                    .find(|(row, _)| row.get(j).is_some_and(|entry| !entry.is_zero()))
+                    .map(|(row, deg)| deg + row[j].deg() as i64)
+                    .unwrap_or(0)
+            })
+            .collect();
+        let source = FreeModule::new(target.num_variables, degrees);
+        GradedMap::with_source(source, target, rows)
+    }
+
+    /// map between given free modules, the entries have to fit the degrees
+    pub fn with_source(
+        source: FreeModule,
+        target: FreeModule,
+        rows: Vec<Vec<HomogeneousPolynomial<K>>>,
+    ) -> Result<GradedMap<K>, Error> {
+        let num_variables = target.num_variables;
+        if rows.len() != target.rank() {
+            return Err(Error::DimensionMismatch {
+                found: rows.len(),
+                expected: target.rank(),
+            });
+        }
+        let mut columns = vec![zero_vector(target.rank(), num_variables); source.rank()];
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != source.rank() {
+                return Err(Error::DimensionMismatch {
+                    found: row.len(),
+                    expected: source.rank(),
+                });
+            }
+            for (j, entry) in row.into_iter().enumerate() {
+                if entry.dim() > num_variables {
+                    return Err(Error::DimensionMismatch {
+                        found: entry.dim(),
+                        expected: num_variables,
+                    });
+                }
+                if entry.is_zero() {
+                    continue;
+                }
+                if entry.deg() as i64 + target.degrees[i] != source.degrees[j] {
+                    return Err(Error::IncompatibleGrading);
+                }
+                columns[j][i] = Polynomial::from(entry).with_dim(num_variables);
+            }
+        }
+        Ok(GradedMap {
+            source,
+            target,
+            columns,
+        })
+    }
+
+    /// the columns have to be homogeneous of the degrees of the source
+    pub(crate) fn from_columns(
+        source: FreeModule,
+        target: FreeModule,
+        columns: Vec<Vec<Polynomial<K>>>,
+    ) -> GradedMap<K> {
+        GradedMap {
+            source,
+            target,
+            columns,
+        }
+    }
+
+    pub fn identity(module: &FreeModule) -> GradedMap<K> {
+        let columns = (0..module.rank())
+            .map(|j| unit_vector(j, module.rank(), module.num_variables))
+            .collect();
+        GradedMap::from_columns(module.clone(), module.clone(), columns)
+    }
+
+    pub fn zero(source: &FreeModule, target: &FreeModule) -> GradedMap<K> {
+        GradedMap {
+            source: source.clone(),
+            target: target.clone(),
+            columns: vec![zero_vector(target.rank(), target.num_variables); source.rank()],
+        }
+    }
+
+    pub fn source(&self) -> &FreeModule {
+        &self.source
+    }
+
+    pub fn target(&self) -> &FreeModule {
+        &self.target
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.target.num_variables
+    }
+
+    /// images of the basis elements of the source
+    pub fn columns(&self) -> &[Vec<Polynomial<K>>] {
+        &self.columns
+    }
+
+    pub fn entry(&self, i: usize, j: usize) -> HomogeneousPolynomial<K> {
+        self.columns[j][i]
+            .clone()
+            .try_into()
+            .expect("entries are homogeneous")
+    }
+
+    pub fn rows(&self) -> Vec<Vec<HomogeneousPolynomial<K>>> {
+        (0..self.target.rank())
+            .map(|i| (0..self.source.rank()).map(|j| self.entry(i, j)).collect())
+            .collect()
+    }
+
+    /// image of a vector of the source
+    pub fn apply(&self, v: &[Polynomial<K>]) -> Result<Vec<Polynomial<K>>, Error> {
+        if v.len() != self.source.rank() {
+            return Err(Error::DimensionMismatch {
+                found: v.len(),
+                expected: self.source.rank(),
+            });
+        }
+        Ok(v.iter().zip(self.columns.iter()).fold(
+            zero_vector(self.target.rank(), self.num_variables()),
+            |acc, (a, column)| add_vectors(&acc, &mul_vector(column, a)),
+        ))
+    }
+
+    /// the composition self o other, other is applied first
+    pub fn compose(&self, other: &GradedMap<K>) -> Result<GradedMap<K>, Error> {
+        if other.target.rank() != self.source.rank() {
+            return Err(Error::DimensionMismatch {
+                found: other.target.rank(),
+                expected: self.source.rank(),
+            });
+        }
+        if other.target.degrees != self.source.degrees {
+            return Err(Error::IncompatibleGrading);
+        }
+        let columns = other
+            .columns
+            .iter()
+            .map(|column| self.apply(column))
+            .collect::<Result<Vec<Vec<Polynomial<K>>>, Error>>()?;
+        Ok(GradedMap::from_columns(
+            other.source.clone(),
+            self.target.clone(),
+            columns,
+        ))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.columns.iter().all(|column| is_zero_vector(column))
+    }
+
+    /// no entry is a nonzero constant, so the map becomes zero modulo the variables
+    pub fn is_minimal(&self) -> bool {
+        self.columns
+            .iter()
+            .flatten()
+            .all(|entry| entry.is_zero() || entry.total_degree() > 0)
+    }
+
+    /// checks if v lies in the image
+    pub fn image_contains(&self, v: &[Polynomial<K>]) -> bool {
+        ModuleBasis::new(self.columns.clone(), self.num_variables()).contains(v)
+    }
+
+    /// minimal generators of the kernel as a map onto them, computed from syzygies
+    pub fn kernel(&self) -> GradedMap<K> {
+        let syzygies = ModuleBasis::new(self.columns.clone(), self.num_variables()).syzygies();
+        GradedMap::onto_generators(self.source.clone(), syzygies)
+    }
+
+    /// the same image, generated by a minimal subset of the columns
+    pub fn minimal_generators(&self) -> GradedMap<K> {
+        GradedMap::onto_generators(self.target.clone(), self.columns.clone())
+    }
+
+    /// map from a free module onto minimal generators of the submodule generated by vectors
+    fn onto_generators(target: FreeModule, vectors: Vec<Vec<Polynomial<K>>>) -> GradedMap<K> {
+        let generators = minimal_generators(vectors, &target.degrees, target.num_variables);
+        let degrees = generators
+            .iter()
+            .map(|v| vector_degree(v, &target.degrees).unwrap())
+            .collect();
+        GradedMap::from_columns(
+            FreeModule::new(target.num_variables, degrees),
+            target,
+            generators,
+        )
+    }
+
+    /// the same matrix as a map F(d) -> G(d)
+    pub fn shift(&self, d: i64) -> GradedMap<K> {
+        GradedMap::from_columns(
+            self.source.shift(d),
+            self.target.shift(d),
+            self.columns.clone(),
+        )
+    }
+
+    /// the block diagonal map F + G -> F' + G'
+    pub fn direct_sum(&self, other: &GradedMap<K>) -> GradedMap<K> {
+        let num_variables = self.num_variables().max(other.num_variables());
+        let lower = zero_vector(other.target.rank(), num_variables);
+        let upper = zero_vector(self.target.rank(), num_variables);
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| [column.clone(), lower.clone()].concat())
+            .chain(
+                other
+                    .columns
+                    .iter()
+                    .map(|column| [upper.clone(), column.clone()].concat()),
+            )
+            .collect();
+        GradedMap::from_columns(
+            self.source.direct_sum(&other.source),
+            self.target.direct_sum(&other.target),
+            columns,
+        )
+    }
+
+    /// the map (self | other) : F + G -> T for two maps into the same module T
+    pub fn concatenate(&self, other: &GradedMap<K>) -> Result<GradedMap<K>, Error> {
+        if self.target.rank() != other.target.rank() {
+            return Err(Error::DimensionMismatch {
+                found: other.target.rank(),
+                expected: self.target.rank(),
+            });
+        }
+        if self.target.degrees != other.target.degrees {
+            return Err(Error::IncompatibleGrading);
+        }
+        let mut columns = self.columns.clone();
+        columns.extend(other.columns.iter().cloned());
+        Ok(GradedMap::from_columns(
+            self.source.direct_sum(&other.source),
+            self.target.clone(),
+            columns,
+        ))
+    }
+
+    /// Kronecker product f (x) g : F (x) G -> F' (x) G'
+    /// with the bases ordered as in FreeModule::tensor
+    pub fn tensor(&self, other: &GradedMap<K>) -> GradedMap<K> {
+        let mut columns = vec![];
+        for column in self.columns.iter() {
+            for other_column in other.columns.iter() {
+                columns.push(
+                    column
+                        .iter()
+                        .flat_map(|f| other_column.iter().map(move |g| f.clone() * g.clone()))
+                        .collect(),
+                );
+            }
+        }
+        GradedMap::from_columns(
+            self.source.tensor(&other.source),
+            self.target.tensor(&other.target),
+            columns,
+        )
+    }
+
+    /// the transpose G* -> F* of f : F -> G
+    pub fn dual(&self) -> GradedMap<K> {
+        let columns = (0..self.target.rank())
+            .map(|i| {
+                self.columns
+                    .iter()
+                    .map(|column| column[i].clone())
+                    .collect()
+            })
+            .collect();
+        GradedMap::from_columns(self.target.dual(), self.source.dual(), columns)
+    }
+
+    /// the first rank components of every column, a map into the first rank summands
+    pub(crate) fn truncate_target(&self, rank: usize) -> GradedMap<K> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column[..rank].to_vec())
+            .collect();
+        let target = FreeModule::new(self.num_variables(), self.target.degrees[..rank].to_vec());
+        GradedMap::from_columns(self.source.clone(), target, columns)
+    }
+}
+
+/// the matrix row by row, entries are aligned in columns
+impl<K> fmt::Display for GradedMap<K>
+where
+    K: Field + Clone + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries: Vec<Vec<String>> = (0..self.target.rank())
+            .map(|i| {
+                self.columns
+                    .iter()
+                    .map(|column| format!("{}", column[i]))
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..self.source.rank())
+            .map(|j| entries.iter().map(|row| row[j].len()).max().unwrap_or(0))
+            .collect();
+        for (i, row) in entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let padded: Vec<String> = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(entry, width)| format!("{entry:>width$}"))
+                .collect();
+            write!(f, "| {} |", padded.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod free_module_tests {
+    use super::{FreeModule, GradedMap};
+    use crate::{
+        polynomials::{HomogeneousPolynomial, Monomial},
+        rational::Rational,
+    };
+
+    fn var(i: usize) -> HomogeneousPolynomial<Rational> {
+        let mut powers = vec![0; 3];
+        powers[i] = 1;
+        HomogeneousPolynomial::from(Monomial::new(1.into(), powers))
+    }
+
+    #[test]
+    fn twists() {
+        let module = FreeModule::new(3, vec![0, 2, 2]);
+        assert_eq!(module.to_string(), "S + S(-2)^2");
+        assert_eq!(module.shift(1).to_string(), "S(1) + S(-1)^2");
+        // S_1 has dimension 3 and S(-2)_1 = 0
+        assert_eq!(module.hilbert_function(1), 3);
+        assert_eq!(module.hilbert_function(2), 6 + 2);
+        assert_eq!(FreeModule::zero(3).to_string(), "0")
+    }
+
+    #[test]
+    fn source_degrees_from_entries() {
+        let map = GradedMap::new(FreeModule::ring(3), vec![vec![var(0), var(1) * var(2)]]).unwrap();
+        assert_eq!(map.source().degrees(), &[1, 2]);
+        assert!(map.is_minimal());
+        let wrong = GradedMap::with_source(
+            FreeModule::new(3, vec![1, 1]),
+            FreeModule::ring(3),
+            vec![vec![var(0), var(1) * var(2)]],
+        );
+        assert!(wrong.is_err())
+    }
+
+    #[test]
+    fn composition_and_kernel() {
+        // (x y) has kernel generated by (y, -x)
+        let map = GradedMap::new(FreeModule::ring(3), vec![vec![var(0), var(1)]]).unwrap();
+        let kernel = map.kernel();
+        assert_eq!(kernel.source().degrees(), &[2]);
+        assert!(map.compose(&kernel).unwrap().is_zero());
+        let identity = GradedMap::identity(map.source());
+        assert_eq!(map.compose(&identity).unwrap(), map)
+    }
+}
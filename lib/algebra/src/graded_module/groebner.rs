@@ -0,0 +1,355 @@
+use crate::{
+    field::Field,
+    polynomials::{Monomial, MonomialOrder, Polynomial},
+};
+use std::cmp::Ordering;
+
+/// Elements of the free module S^r are vectors of r polynomials
+/// module monomials m e_i are compared by m in GRevLex first,
+/// ties are broken by the position with e_0 > e_1 > ...
+const ORDER: MonomialOrder = MonomialOrder::GRevLex;
+
+pub(crate) fn zero_vector<K: Field>(rank: usize, num_variables: usize) -> Vec<Polynomial<K>> {
+    (0..rank)
+        .map(|_| Polynomial::new(vec![]).with_dim(num_variables))
+        .collect()
+}
+
+/// the basis vector e_i of S^rank
+pub(crate) fn unit_vector<K: Field>(
+    i: usize,
+    rank: usize,
+    num_variables: usize,
+) -> Vec<Polynomial<K>> {
+    let mut unit = zero_vector(rank, num_variables);
+    unit[i] = Polynomial::new(vec![Monomial::new(
+        <K as Field>::one(),
+        vec![0; num_variables],
+    )]);
+    unit
+}
+
+pub(crate) fn is_zero_vector<K: Field>(v: &[Polynomial<K>]) -> bool {
+    v.iter().all(|component| component.is_zero())
+}
+
+pub(crate) fn add_vectors<K: Field + Clone>(
+    v: &[Polynomial<K>],
+    w: &[Polynomial<K>],
+) -> Vec<Polynomial<K>> {
+    v.iter()
+        .zip(w.iter())
+        .map(|(a, b)| a.clone() + b.clone())
+        .collect()
+}
+
+pub(crate) fn sub_vectors<K: Field + Clone>(
+    v: &[Polynomial<K>],
+    w: &[Polynomial<K>],
+) -> Vec<Polynomial<K>> {
+    v.iter()
+        .zip(w.iter())
+        .map(|(a, b)| a.clone() - b.clone())
+        .collect()
+}
+
+pub(crate) fn mul_vector<K: Field + Clone>(
+    v: &[Polynomial<K>],
+    factor: &Polynomial<K>,
+) -> Vec<Polynomial<K>> {
+    v.iter()
+        .map(|component| {
+            let dim = component.dim();
+            (factor.clone() * component.clone()).with_dim(dim)
+        })
+        .collect()
+}
+
+fn mul_vector_monomial<K: Field + Clone>(
+    v: &[Polynomial<K>],
+    mono: &Monomial<K>,
+) -> Vec<Polynomial<K>> {
+    v.iter()
+        .map(|component| component.mul_monomial(mono))
+        .collect()
+}
+
+/// position and leading term of a vector, None for the zero vector
+pub(crate) fn leading_term<K: Field>(v: &[Polynomial<K>]) -> Option<(usize, &Monomial<K>)> {
+    let mut lead: Option<(usize, &Monomial<K>)> = None;
+    for (i, component) in v.iter().enumerate() {
+        if let Some(term) = component.leading_term(ORDER) {
+            if lead.is_none_or(|(_, current)| ORDER.cmp(term, current) == Ordering::Greater) {
+                lead = Some((i, term));
+            }
+        }
+    }
+    lead
+}
+
+/// degree of a homogeneous vector when e_i has degree degrees[i]
+/// None for the zero vector
+pub(crate) fn vector_degree<K: Field>(v: &[Polynomial<K>], degrees: &[i64]) -> Option<i64> {
+    v.iter()
+        .zip(degrees.iter())
+        .find(|(component, _)| !component.is_zero())
+        .map(|(component, deg)| deg + component.total_degree() as i64)
+}
+
+/// Groebner basis of the submodule of S^rank generated by vectors f_1,...,f_s
+/// every basis element remembers its expression in the generators,
+/// basis[k] = sum_j lifts[k][j] f_j
+pub(crate) struct ModuleBasis<K: Field> {
+    num_variables: usize,
+    generators: Vec<Vec<Polynomial<K>>>,
+    basis: Vec<Vec<Polynomial<K>>>,
+    lifts: Vec<Vec<Polynomial<K>>>,
+    pairs: Vec<(usize, usize)>,
+}
+
+impl<K: Field + Clone> ModuleBasis<K> {
+    pub(crate) fn new(generators: Vec<Vec<Polynomial<K>>>, num_variables: usize) -> ModuleBasis<K> {
+        let mut module_basis = ModuleBasis {
+            num_variables,
+            generators: vec![],
+            basis: vec![],
+            lifts: vec![],
+            pairs: vec![],
+        };
+        for gen in generators.into_iter() {
+            module_basis.add_generator(gen);
+        }
+        module_basis.complete();
+        module_basis
+    }
+
+    /// adds a generator and completes the basis
+    pub(crate) fn extend(&mut self, generator: Vec<Polynomial<K>>) {
+        self.add_generator(generator);
+        self.complete();
+    }
+
+    fn add_generator(&mut self, generator: Vec<Polynomial<K>>) {
+        let j = self.generators.len();
+        for lift in self.lifts.iter_mut() {
+            lift.push(Polynomial::new(vec![]).with_dim(self.num_variables));
+        }
+        if !is_zero_vector(&generator) {
+            self.insert(generator.clone(), unit_vector(j, j + 1, self.num_variables));
+        }
+        self.generators.push(generator);
+    }
+
+    /// Buchberger's algorithm for modules
+    /// S-vectors are only formed for leading terms in the same position
+    /// and pairs are selected by the degree of the lcm
+    fn complete(&mut self) {
+        while let Some(next) =
+            (0..self.pairs.len()).min_by_key(|ind| self.pair_degree(self.pairs[*ind]))
+        {
+            let (k, l) = self.pairs.swap_remove(next);
+            let (s_vector, s_lift) = self.s_vector(k, l);
+            let (quotients, remainder) = self.divide(&s_vector);
+            if !is_zero_vector(&remainder) {
+                let lift = self.combine_lifts(s_lift, &quotients);
+                self.insert(remainder, lift);
+            }
+        }
+    }
+
+    fn insert(&mut self, vector: Vec<Polynomial<K>>, lift: Vec<Polynomial<K>>) {
+        let position = leading_term(&vector).unwrap().0;
+        let new = self.basis.len();
+        for k in 0..new {
+            if leading_term(&self.basis[k]).unwrap().0 == position {
+                self.pairs.push((k, new));
+            }
+        }
+        self.basis.push(vector);
+        self.lifts.push(lift);
+    }
+
+    fn pair_degree(&self, (k, l): (usize, usize)) -> usize {
+        let (_, lead_k) = leading_term(&self.basis[k]).unwrap();
+        let (_, lead_l) = leading_term(&self.basis[l]).unwrap();
+        lead_k.lcm(lead_l).deg()
+    }
+
+    /// lcm/lt(g_k) g_k - lcm/lt(g_l) g_l together with its expression in the generators
+    fn s_vector(&self, k: usize, l: usize) -> (Vec<Polynomial<K>>, Vec<Polynomial<K>>) {
+        let (_, lead_k) = leading_term(&self.basis[k]).unwrap();
+        let (_, lead_l) = leading_term(&self.basis[l]).unwrap();
+        let lcm = lead_k.lcm(lead_l);
+        let factor_k = lcm.quotient(lead_k).unwrap();
+        let factor_l = lcm.quotient(lead_l).unwrap();
+        let s_vector = sub_vectors(
+            &mul_vector_monomial(&self.basis[k], &factor_k),
+            &mul_vector_monomial(&self.basis[l], &factor_l),
+        );
+        let s_lift = sub_vectors(
+            &mul_vector_monomial(&self.lifts[k], &factor_k),
+            &mul_vector_monomial(&self.lifts[l], &factor_l),
+        );
+        (s_vector, s_lift)
+    }
+
+    /// lift - sum_u q_u lifts[u]
+    fn combine_lifts(
+        &self,
+        lift: Vec<Polynomial<K>>,
+        quotients: &[Polynomial<K>],
+    ) -> Vec<Polynomial<K>> {
+        quotients
+            .iter()
+            .zip(self.lifts.iter())
+            .filter(|(q, _)| !q.is_zero())
+            .fold(lift, |acc, (q, a)| sub_vectors(&acc, &mul_vector(a, q)))
+    }
+
+    /// division with remainder, v = sum_u q_u basis[u] + r
+    /// no term of r is divisible by a leading term in the same position
+    pub(crate) fn divide(&self, v: &[Polynomial<K>]) -> (Vec<Polynomial<K>>, Vec<Polynomial<K>>) {
+        let leads: Vec<(usize, &Monomial<K>)> = self
+            .basis
+            .iter()
+            .map(|g| leading_term(g).unwrap())
+            .collect();
+        let mut quotients: Vec<Polynomial<K>> = zero_vector(self.basis.len(), self.num_variables);
+        let mut remainder = zero_vector(v.len(), self.num_variables);
+        let mut current = v.to_vec();
+
+        while let Some((position, lead)) = leading_term(&current).map(|(i, m)| (i, m.clone())) {
+            let division = leads
+                .iter()
+                .enumerate()
+                .find_map(|(u, (g_position, g_lead))| {
+                    if *g_position == position {
+                        lead.quotient(g_lead).map(|quot| (u, quot))
+                    } else {
+                        None
+                    }
+                });
+            match division {
+                Some((u, quot)) => {
+                    current = sub_vectors(&current, &mul_vector_monomial(&self.basis[u], &quot));
+                    quotients[u] = quotients[u].clone() + quot.into();
+                }
+                None => {
+                    let term: Polynomial<K> = lead.into();
+                    current[position] = current[position].clone() - term.clone();
+                    remainder[position] = remainder[position].clone() + term;
+                }
+            }
+        }
+        (quotients, remainder)
+    }
+
+    pub(crate) fn contains(&self, v: &[Polynomial<K>]) -> bool {
+        is_zero_vector(&self.divide(v).1)
+    }
+
+    /// exponents of the leading terms in the given position
+    pub(crate) fn leading_exponents(&self, position: usize) -> Vec<Vec<usize>> {
+        self.basis
+            .iter()
+            .map(|g| leading_term(g).unwrap())
+            .filter(|(i, _)| *i == position)
+            .map(|(_, lead)| lead.powers())
+            .collect()
+    }
+
+    /// generators of the syzygies {a in S^s : a_1 f_1 + ... + a_s f_s = 0}
+    /// by Schreyer's theorem the reductions of the S-vectors to zero
+    /// give the syzygies of the Groebner basis, they are transferred to the generators
+    /// with the lifts and completed by e_j - (expression of f_j through the lifts)
+    pub(crate) fn syzygies(&self) -> Vec<Vec<Polynomial<K>>> {
+        let num_generators = self.generators.len();
+        let mut syzygies = vec![];
+        for l in 0..self.basis.len() {
+            for k in 0..l {
+                if leading_term(&self.basis[k]).unwrap().0
+                    != leading_term(&self.basis[l]).unwrap().0
+                {
+                    continue;
+                }
+                let (s_vector, s_lift) = self.s_vector(k, l);
+                let (quotients, _) = self.divide(&s_vector);
+                syzygies.push(self.combine_lifts(s_lift, &quotients));
+            }
+        }
+        for j in 0..num_generators {
+            let (quotients, _) = self.divide(&self.generators[j]);
+            let unit = unit_vector(j, num_generators, self.num_variables);
+            syzygies.push(self.combine_lifts(unit, &quotients));
+        }
+        syzygies.retain(|syz| !is_zero_vector(syz));
+        syzygies
+    }
+}
+
+/// minimal homogeneous generators of the submodule generated by vectors
+/// the vectors are sorted by degree and kept unless the previous ones generate them,
+/// by the graded Nakayama lemma this is a minimal generating set
+pub(crate) fn minimal_generators<K: Field + Clone>(
+    vectors: Vec<Vec<Polynomial<K>>>,
+    degrees: &[i64],
+    num_variables: usize,
+) -> Vec<Vec<Polynomial<K>>> {
+    let mut vectors: Vec<(i64, Vec<Polynomial<K>>)> = vectors
+        .into_iter()
+        .filter_map(|v| vector_degree(&v, degrees).map(|deg| (deg, v)))
+        .collect();
+    vectors.sort_by_key(|(deg, _)| *deg);
+
+    let mut minimal: Vec<Vec<Polynomial<K>>> = vec![];
+    let mut basis = ModuleBasis::new(vec![], num_variables);
+    for (_, v) in vectors.into_iter() {
+        if !basis.contains(&v) {
+            basis.extend(v.clone());
+            minimal.push(v);
+        }
+    }
+    minimal
+}
+
+#[cfg(test)]
+mod module_groebner_tests {
+    use super::{add_vectors, is_zero_vector, mul_vector, ModuleBasis};
+    use crate::{
+        polynomials::{Monomial, Polynomial},
+        rational::Rational,
+    };
+
+    fn var(i: usize) -> Polynomial<Rational> {
+        Polynomial::variable(i, 3)
+    }
+
+    #[test]
+    fn syzygies_of_variables() {
+        // the syzygies of x, y, z are the Koszul relations
+        let generators = vec![vec![var(0)], vec![var(1)], vec![var(2)]];
+        let basis = ModuleBasis::new(generators.clone(), 3);
+        let syzygies = basis.syzygies();
+        assert_eq!(syzygies.len(), 3);
+        for syz in syzygies.iter() {
+            let combination = syz
+                .iter()
+                .zip(generators.iter())
+                .fold(vec![Polynomial::new(vec![])], |acc, (a, f)| {
+                    add_vectors(&acc, &mul_vector(f, a))
+                });
+            assert!(is_zero_vector(&combination));
+        }
+    }
+
+    #[test]
+    fn membership() {
+        // (x, y) and (y, 0) generate (0, y^2) and (x y, 0) but not (1, 0)
+        let zero = Polynomial::new(vec![]).with_dim(3);
+        let basis = ModuleBasis::new(vec![vec![var(0), var(1)], vec![var(1), zero.clone()]], 3);
+        assert!(basis.contains(&[zero.clone(), var(1) * var(1)]));
+        assert!(basis.contains(&[var(0) * var(1), zero.clone()]));
+        let one = Polynomial::new(vec![Monomial::new(Rational::from(1), vec![0, 0, 0])]);
+        assert!(!basis.contains(&[one, zero]))
+    }
+}
@@ -0,0 +1,13 @@
+pub mod betti;
+pub mod cohomology;
+pub mod free;
+mod groebner;
+pub mod module;
+pub mod natural;
+pub mod resolution;
+
+pub use betti::BettiTable;
+pub use cohomology::SheafCohomology;
+pub use free::{FreeModule, GradedMap};
+pub use module::GradedModule;
+pub use resolution::FreeResolution;
@@ -0,0 +1,292 @@
+use super::{
+    groebner::{minimal_generators, mul_vector, sub_vectors, vector_degree, ModuleBasis},
+    BettiTable, FreeModule, FreeResolution, GradedMap,
+};
+use crate::{
+    errors::Error,
+    field::Field,
+    hilbert::HilbertSeries,
+    polynomials::{HomogeneousPolynomial, Polynomial},
+    projective::ProjectiveScheme,
+};
+
+/// Finitely generated graded module M = coker(F_1 -> F_0) over S = K[X_0,...,X_(n-1)]
+/// the basis of F_0 maps to generators of M
+/// and the columns of the presentation matrix are the relations between them
+#[derive(Clone, Debug)]
+pub struct GradedModule<K: Field> {
+    presentation: GradedMap<K>,
+}
+
+impl<K: Field + Clone> GradedModule<K> {
+    /// the cokernel of the presentation
+    pub fn new(presentation: GradedMap<K>) -> GradedModule<K> {
+        GradedModule { presentation }
+    }
+
+    /// free module without relations
+    pub fn free(module: &FreeModule) -> GradedModule<K> {
+        GradedModule::new(GradedMap::zero(
+            &FreeModule::zero(module.num_variables()),
+            module,
+        ))
+    }
+
+    /// S/I for the ideal I generated by generators
+    pub fn quotient_ring(
+        num_variables: usize,
+        generators: Vec<HomogeneousPolynomial<K>>,
+    ) -> Result<GradedModule<K>, Error> {
+        Ok(GradedModule::new(GradedMap::new(
+            FreeModule::ring(num_variables),
+            vec![generators],
+        )?))
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.presentation.num_variables()
+    }
+
+    /// the free module F_0 mapping onto M
+    pub fn generators(&self) -> &FreeModule {
+        self.presentation.target()
+    }
+
+    /// the map F_1 -> F_0 whose image are the relations
+    pub fn presentation(&self) -> &GradedMap<K> {
+        &self.presentation
+    }
+
+    /// the twist M(d) with M(d)_e = M_(d+e)
+    pub fn shift(&self, d: i64) -> GradedModule<K> {
+        GradedModule::new(self.presentation.shift(d))
+    }
+
+    /// M + N, presented by the block diagonal matrix
+    pub fn direct_sum(&self, other: &GradedModule<K>) -> GradedModule<K> {
+        GradedModule::new(self.presentation.direct_sum(&other.presentation))
+    }
+
+    /// M (x) N = coker(phi (x) 1 | 1 (x) psi : F_1 (x) G_0 + F_0 (x) G_1 -> F_0 (x) G_0)
+    /// for M = coker(phi : F_1 -> F_0) and N = coker(psi : G_1 -> G_0)
+    pub fn tensor(&self, other: &GradedModule<K>) -> GradedModule<K> {
+        let left = self
+            .presentation
+            .tensor(&GradedMap::identity(other.generators()));
+        let right = GradedMap::identity(self.generators()).tensor(&other.presentation);
+        GradedModule::new(
+            left.concatenate(&right)
+                .expect("both maps end in F_0 (x) G_0"),
+        )
+    }
+
+    /// Hom(M, N), the kernel of Hom(F_0, N) -> Hom(F_1, N) induced by phi
+    /// where Hom(F_i, N) = coker(1 (x) psi : F_i* (x) G_1 -> F_i* (x) G_0)
+    /// the generators are the elements of F_0* (x) G_0 that phi maps into the relations,
+    /// the relations are the combinations of them that lie in the image of 1 (x) psi
+    pub fn hom(&self, other: &GradedModule<K>) -> GradedModule<K> {
+        let psi = &other.presentation;
+        let induced = self
+            .presentation
+            .dual()
+            .tensor(&GradedMap::identity(other.generators()));
+        let target_relations = GradedMap::identity(&self.presentation.source().dual()).tensor(psi);
+        let generators = induced
+            .concatenate(&target_relations)
+            .expect("both maps end in F_1* (x) G_0")
+            .kernel()
+            .truncate_target(induced.source().rank())
+            .minimal_generators();
+
+        let source_relations = GradedMap::identity(&self.generators().dual()).tensor(psi);
+        let relations = generators
+            .concatenate(&source_relations)
+            .expect("both maps end in F_0* (x) G_0")
+            .kernel()
+            .truncate_target(generators.source().rank())
+            .minimal_generators();
+        GradedModule::new(relations)
+    }
+
+    /// M* = Hom(M, S)
+    pub fn dual(&self) -> GradedModule<K> {
+        self.hom(&GradedModule::free(&FreeModule::ring(self.num_variables())))
+    }
+
+    /// the multiplicative disjunction of linear logic, (M* (x) N*)*
+    /// for free modules this agrees with the tensor product
+    pub fn par(&self, other: &GradedModule<K>) -> GradedModule<K> {
+        self.dual().tensor(&other.dual()).dual()
+    }
+
+    /// dimension of the degree d part
+    /// counts the module monomials that are not leading terms of relations
+    pub fn hilbert_function(&self, d: i64) -> i64 {
+        let relations =
+            ModuleBasis::new(self.presentation.columns().to_vec(), self.num_variables());
+        self.generators()
+            .degrees()
+            .iter()
+            .enumerate()
+            .filter(|(_, deg)| **deg <= d)
+            .map(|(i, deg)| {
+                HilbertSeries::from_monomial_ideal(
+                    &relations.leading_exponents(i),
+                    self.num_variables(),
+                )
+                .hilbert_function((d - deg) as usize)
+            })
+            .sum()
+    }
+
+    /// presentation of the same module by minimal generators and minimal relations
+    /// relations with a constant entry eliminate the corresponding generator
+    pub fn minimal_presentation(&self) -> GradedModule<K> {
+        let num_variables = self.num_variables();
+        let mut degrees = self.generators().degrees().to_vec();
+        let mut relations = self.presentation.columns().to_vec();
+        loop {
+            relations = minimal_generators(relations, &degrees, num_variables);
+            let unit = relations.iter().enumerate().find_map(|(j, relation)| {
+                relation
+                    .iter()
+                    .position(|entry| !entry.is_zero() && entry.total_degree() == 0)
+                    .map(|i| (j, i))
+            });
+            let Some((j, i)) = unit else {
+                break;
+            };
+            // e_i = -1/c (relation - c e_i) in M, so the generator e_i can be removed
+            let pivot = relations.remove(j);
+            let inverse = <K as Field>::one() / pivot[i].terms()[0].coefficient.clone();
+            relations = relations
+                .into_iter()
+                .map(|relation| {
+                    let factor = relation[i].clone().scale(inverse.clone());
+                    let mut reduced = sub_vectors(&relation, &mul_vector(&pivot, &factor));
+                    reduced.remove(i);
+                    reduced
+                })
+                .collect();
+            degrees.remove(i);
+        }
+        let relation_degrees = relations
+            .iter()
+            .map(|relation| vector_degree(relation, &degrees).unwrap())
+            .collect();
+        GradedModule::new(GradedMap::from_columns(
+            FreeModule::new(num_variables, relation_degrees),
+            FreeModule::new(num_variables, degrees),
+            relations,
+        ))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.minimal_presentation().generators().rank() == 0
+    }
+
+    /// checks if an element of F_0 is zero in M
+    pub fn is_zero_element(&self, v: &[Polynomial<K>]) -> bool {
+        self.presentation.image_contains(v)
+    }
+
+    pub fn minimal_free_resolution(&self) -> FreeResolution<K> {
+        FreeResolution::minimal(self)
+    }
+
+    /// graded Betti numbers of the minimal free resolution
+    pub fn betti_table(&self) -> BettiTable {
+        self.minimal_free_resolution().betti_table()
+    }
+}
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// the homogeneous coordinate ring S/I as a module over S
+    pub fn coordinate_module(&self) -> GradedModule<K> {
+        GradedModule::quotient_ring(self.num_variables(), self.ideal_generators())
+            .expect("ideal generators are homogeneous in num_variables variables")
+    }
+
+    /// graded Betti numbers of the homogeneous coordinate ring
+    pub fn betti_table(&self) -> BettiTable {
+        self.coordinate_module().betti_table()
+    }
+}
+
+#[cfg(test)]
+mod graded_module_tests {
+    use super::GradedModule;
+    use crate::{
+        graded_module::{FreeModule, GradedMap},
+        polynomials::{HomogeneousPolynomial, Monomial},
+        projective::ProjectiveMorphism,
+        rational::Rational,
+    };
+
+    #[test]
+    fn hilbert_function_of_twisted_cubic() {
+        let twisted = ProjectiveMorphism::<Rational>::veronese_embedding(1, 3).image();
+        let module = twisted.coordinate_module();
+        for d in 0..5 {
+            assert_eq!(module.hilbert_function(d), 3 * d + 1);
+        }
+        assert_eq!(module.hilbert_function(-1), 0)
+    }
+
+    #[test]
+    fn pruning_units() {
+        // coker of (1, x)^T : S -> S + S(1) is free on the second generator
+        let one = HomogeneousPolynomial::from(Monomial::new(Rational::from(1), vec![0, 0]));
+        let x = HomogeneousPolynomial::from(Monomial::new(Rational::from(1), vec![1, 0]));
+        let presentation =
+            GradedMap::new(FreeModule::new(2, vec![0, -1]), vec![vec![one], vec![x]]).unwrap();
+        let module = GradedModule::new(presentation);
+        let minimal = module.minimal_presentation();
+        assert_eq!(minimal.generators(), &FreeModule::new(2, vec![-1]));
+        assert_eq!(minimal.presentation().source().rank(), 0);
+        assert_eq!(
+            module.betti_table().to_string(),
+            "       0\ntotal: 1\n   -1: 1"
+        );
+        assert_eq!(module.hilbert_function(0), 2);
+        assert!(!module.is_zero())
+    }
+
+    fn cyclic(powers: Vec<usize>) -> GradedModule<Rational> {
+        let generator = HomogeneousPolynomial::from(Monomial::new(Rational::from(1), powers));
+        GradedModule::quotient_ring(2, vec![generator]).unwrap()
+    }
+
+    #[test]
+    fn tensor_and_sum() {
+        // S/(x) (x) S/(y) = S/(x, y) = K
+        let product = cyclic(vec![1, 0]).tensor(&cyclic(vec![0, 1]));
+        assert_eq!(product.hilbert_function(0), 1);
+        assert_eq!(product.hilbert_function(1), 0);
+        let sum = cyclic(vec![1, 0]).direct_sum(&cyclic(vec![0, 1]).shift(-1));
+        assert_eq!(sum.hilbert_function(0), 1);
+        assert_eq!(sum.hilbert_function(1), 2)
+    }
+
+    #[test]
+    fn hom_and_duals() {
+        // Hom(S/(x), S/(x^2)) = (x)/(x^2), generated by x in degree 1
+        let hom = cyclic(vec![1, 0]).hom(&cyclic(vec![2, 0]));
+        let dims: Vec<i64> = (0..3).map(|d| hom.hilbert_function(d)).collect();
+        assert_eq!(dims, vec![0, 1, 1]);
+        // torsion modules have no dual
+        assert!(cyclic(vec![1, 0]).dual().is_zero());
+        // Hom(S(-1) + S, S(-3)) = S(-2) + S(-3)
+        let free = |degrees: Vec<i64>| GradedModule::<Rational>::free(&FreeModule::new(2, degrees));
+        let hom = free(vec![1, 0]).hom(&free(vec![3]));
+        assert_eq!(hom.minimal_presentation().generators().degrees(), &[2, 3]);
+        // par and tensor agree on free modules
+        let (left, right) = (free(vec![1, 0]), free(vec![2]));
+        for d in 0..4 {
+            assert_eq!(
+                left.par(&right).hilbert_function(d),
+                left.tensor(&right).hilbert_function(d)
+            );
+        }
+    }
+}
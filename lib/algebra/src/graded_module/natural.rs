@@ -0,0 +1,137 @@
+use super::{groebner::zero_vector, FreeModule, GradedMap};
+use crate::{errors::Error, field::Field, polynomials::Polynomial};
+
+/// Natural maps between free modules
+/// Hom(F, G) is identified with F* (x) G as in FreeModule::hom
+/// and tensor products use the basis order of FreeModule::tensor
+impl<K: Field + Clone> GradedMap<K> {
+    /// map whose column j has a one in row position(j), if any
+    fn with_ones(
+        source: FreeModule,
+        target: FreeModule,
+        position: impl Fn(usize) -> Option<usize>,
+    ) -> GradedMap<K> {
+        let num_variables = target.num_variables();
+        let columns = (0..source.rank())
+            .map(|j| {
+                let mut column = zero_vector(target.rank(), num_variables);
+                if let Some(i) = position(j) {
+                    column[i] = Polynomial::constant(<K as Field>::one()).with_dim(num_variables);
+                }
+                column
+            })
+            .collect();
+        GradedMap::from_columns(source, target, columns)
+    }
+
+    /// evaluation Hom(F, G) (x) F -> G, (e_i* (x) g_j) (x) e_k -> delta_ik g_j
+    pub fn evaluation(module: &FreeModule, other: &FreeModule) -> GradedMap<K> {
+        let (rank, other_rank) = (module.rank(), other.rank());
+        GradedMap::with_ones(module.hom(other).tensor(module), other.clone(), |column| {
+            let (i, k) = (column / rank / other_rank, column % rank);
+            let j = column / rank % other_rank;
+            (i == k).then_some(j)
+        })
+    }
+
+    /// pairing F* (x) F -> S, the evaluation into S
+    pub fn pairing(module: &FreeModule) -> GradedMap<K> {
+        GradedMap::evaluation(module, &FreeModule::ring(module.num_variables()))
+    }
+
+    /// unit A -> Hom(B, A (x) B) of the adjunction - (x) B -| Hom(B, -)
+    /// a_i -> sum_j e_j* (x) (a_i (x) e_j)
+    pub fn adjunction_unit(left: &FreeModule, right: &FreeModule) -> GradedMap<K> {
+        let target = right.hom(&left.tensor(right));
+        let columns = (0..left.rank())
+            .map(|i| {
+                let mut column = zero_vector(target.rank(), target.num_variables());
+                for j in 0..right.rank() {
+                    let position = (j * left.rank() + i) * right.rank() + j;
+                    column[position] =
+                        Polynomial::constant(<K as Field>::one()).with_dim(target.num_variables());
+                }
+                column
+            })
+            .collect();
+        GradedMap::from_columns(left.clone(), target, columns)
+    }
+
+    /// counit Hom(B, C) (x) B -> C of the adjunction, this is the evaluation
+    pub fn adjunction_counit(right: &FreeModule, target: &FreeModule) -> GradedMap<K> {
+        GradedMap::evaluation(right, target)
+    }
+
+    /// the adjoint A -> Hom(B, C) of a map A (x) B -> C
+    /// obtained as Hom(B, f) o unit
+    pub fn curry(&self, left: &FreeModule, right: &FreeModule) -> Result<GradedMap<K>, Error> {
+        GradedMap::identity(&right.dual())
+            .tensor(self)
+            .compose(&GradedMap::adjunction_unit(left, right))
+    }
+
+    /// the adjoint A (x) B -> C of a map A -> Hom(B, C)
+    /// obtained as counit o (g (x) B)
+    pub fn uncurry(&self, right: &FreeModule, target: &FreeModule) -> Result<GradedMap<K>, Error> {
+        GradedMap::adjunction_counit(right, target)
+            .compose(&self.tensor(&GradedMap::identity(right)))
+    }
+}
+
+#[cfg(test)]
+mod natural_tests {
+    use crate::{
+        graded_module::{FreeModule, GradedMap},
+        polynomials::{HomogeneousPolynomial, Monomial},
+        rational::Rational,
+    };
+
+    fn mono(powers: Vec<usize>) -> HomogeneousPolynomial<Rational> {
+        HomogeneousPolynomial::from(Monomial::new(1.into(), powers))
+    }
+
+    #[test]
+    fn evaluation_degrees() {
+        let module = FreeModule::new(2, vec![0, 1]);
+        let other = FreeModule::new(2, vec![2]);
+        let evaluation = GradedMap::<Rational>::evaluation(&module, &other);
+        assert_eq!(evaluation.source().rank(), 4);
+        assert_eq!(evaluation.source().degrees(), &[2, 3, 1, 2]);
+        assert_eq!(evaluation.target(), &other);
+        // the pairing is the identity matrix read as a row
+        let pairing = GradedMap::<Rational>::pairing(&module);
+        assert_eq!(pairing.source().degrees(), &[0, 1, -1, 0]);
+        assert_eq!(
+            pairing
+                .columns()
+                .iter()
+                .filter(|column| !column[0].is_zero())
+                .count(),
+            2
+        )
+    }
+
+    #[test]
+    fn triangle_identities() {
+        // f = (x^2, y^3) : S(-2) (x) (S + S(-1)) -> S, curried and uncurried
+        let left = FreeModule::new(2, vec![2]);
+        let right = FreeModule::new(2, vec![0, 1]);
+        let target = FreeModule::new(2, vec![0]);
+        let f = GradedMap::with_source(
+            left.tensor(&right),
+            target.clone(),
+            vec![vec![mono(vec![2, 0]), mono(vec![0, 3])]],
+        )
+        .unwrap();
+        let curried = f.curry(&left, &right).unwrap();
+        assert_eq!(curried.target(), &right.hom(&target));
+        assert_eq!(curried.uncurry(&right, &target).unwrap(), f);
+        // counit o (unit (x) B) = id
+        let unit = GradedMap::<Rational>::adjunction_unit(&left, &right);
+        let counit = GradedMap::adjunction_counit(&right, &left.tensor(&right));
+        let composite = counit
+            .compose(&unit.tensor(&GradedMap::identity(&right)))
+            .unwrap();
+        assert_eq!(composite, GradedMap::identity(&left.tensor(&right)))
+    }
+}
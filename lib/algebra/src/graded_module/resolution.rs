@@ -0,0 +1,163 @@
+use super::{BettiTable, FreeModule, GradedMap, GradedModule};
+use crate::field::Field;
+
+/// Free resolution ... -> F_2 -> F_1 -> F_0 -> M -> 0 of a graded module M
+/// differentials[i] is the map F_(i+1) -> F_i
+#[derive(Clone, Debug)]
+pub struct FreeResolution<K: Field> {
+    start: FreeModule,
+    differentials: Vec<GradedMap<K>>,
+}
+
+impl<K: Field + Clone> FreeResolution<K> {
+    /// the minimal free resolution
+    /// F_0 -> M and F_1 -> F_0 come from a minimal presentation,
+    /// every further F_(i+1) maps onto minimal generators of the kernel of F_i -> F_(i-1)
+    /// by Hilbert's syzygy theorem this stops after at most num_variables steps
+    pub fn minimal(module: &GradedModule<K>) -> FreeResolution<K> {
+        let presentation = module.minimal_presentation();
+        let start = presentation.generators().clone();
+        let mut differentials = vec![];
+        let mut current = presentation.presentation().clone();
+        while current.source().rank() > 0 {
+            let next = current.kernel();
+            differentials.push(current);
+            current = next;
+        }
+        FreeResolution {
+            start,
+            differentials,
+        }
+    }
+
+    /// index of the last nonzero free module
+    pub fn length(&self) -> usize {
+        self.differentials.len()
+    }
+
+    /// the free module F_i, zero beyond the length
+    pub fn module(&self, i: usize) -> FreeModule {
+        match i {
+            0 => self.start.clone(),
+            _ => self
+                .differentials
+                .get(i - 1)
+                .map(|map| map.source().clone())
+                .unwrap_or_else(|| FreeModule::zero(self.start.num_variables())),
+        }
+    }
+
+    /// F_0, ..., F_length
+    pub fn modules(&self) -> Vec<FreeModule> {
+        (0..=self.length()).map(|i| self.module(i)).collect()
+    }
+
+    /// the differential F_i -> F_(i-1) for 1 <= i <= length
+    pub fn differential(&self, i: usize) -> Option<&GradedMap<K>> {
+        i.checked_sub(1).and_then(|i| self.differentials.get(i))
+    }
+
+    /// consecutive differentials compose to zero
+    pub fn is_complex(&self) -> bool {
+        self.differentials
+            .windows(2)
+            .all(|maps| maps[0].compose(&maps[1]).is_ok_and(|map| map.is_zero()))
+    }
+
+    /// all differentials are minimal, so the resolution is minimal if it is exact
+    pub fn is_minimal(&self) -> bool {
+        self.differentials.iter().all(|map| map.is_minimal())
+    }
+
+    /// graded Betti numbers, the zero module has an empty table
+    pub fn betti_table(&self) -> BettiTable {
+        if self.start.rank() == 0 {
+            return BettiTable::new(&[]);
+        }
+        BettiTable::new(&self.modules())
+    }
+}
+
+#[cfg(test)]
+mod resolution_tests {
+    use crate::{
+        graded_module::GradedModule,
+        polynomials::{HomogeneousPolynomial, Monomial},
+        prime_field::PrimeField,
+        projective::{ProjectiveMorphism, ProjectiveScheme},
+        rational::Rational,
+    };
+
+    fn mono(powers: Vec<usize>) -> HomogeneousPolynomial<Rational> {
+        HomogeneousPolynomial::from(Monomial::new(1.into(), powers))
+    }
+
+    #[test]
+    fn twisted_cubic() {
+        let twisted = ProjectiveMorphism::<Rational>::veronese_embedding(1, 3).image();
+        let resolution = twisted.coordinate_module().minimal_free_resolution();
+        assert!(resolution.is_complex());
+        assert!(resolution.is_minimal());
+        assert_eq!(
+            resolution.betti_table().to_string(),
+            "       0 1 2\ntotal: 1 3 2\n    0: 1 . .\n    1: . 3 2"
+        )
+    }
+
+    #[test]
+    fn koszul_complex() {
+        // the variables of P^2 are resolved by the Koszul complex
+        let point = GradedModule::quotient_ring(
+            3,
+            vec![
+                mono(vec![1, 0, 0]),
+                mono(vec![0, 1, 0]),
+                mono(vec![0, 0, 1]),
+            ],
+        )
+        .unwrap();
+        let table = point.betti_table();
+        assert_eq!(
+            table.to_string(),
+            "       0 1 2 3\ntotal: 1 3 3 1\n    0: 1 3 3 1"
+        );
+        assert_eq!(table.regularity(), Some(0))
+    }
+
+    #[test]
+    fn complete_intersection() {
+        // two quadrics in P^3 over F_5 meet in an elliptic quartic
+        let quadric1 = HomogeneousPolynomial::new(vec![
+            Monomial::<PrimeField<5>>::new(1.into(), vec![1, 1, 0, 0]),
+            Monomial::new(1.into(), vec![0, 0, 1, 1]),
+        ])
+        .unwrap();
+        let quadric2 = HomogeneousPolynomial::new(vec![
+            Monomial::<PrimeField<5>>::new(1.into(), vec![2, 0, 0, 0]),
+            Monomial::new(1.into(), vec![0, 2, 0, 0]),
+            Monomial::new(1.into(), vec![0, 0, 2, 0]),
+        ])
+        .unwrap();
+        let curve = ProjectiveScheme::new(vec![quadric1, quadric2]).unwrap();
+        let table = curve.betti_table();
+        assert_eq!(table.get(1, 2), 2);
+        assert_eq!(table.get(2, 4), 1);
+        assert_eq!(
+            table.to_string(),
+            "       0 1 2\ntotal: 1 2 1\n    0: 1 . .\n    1: . 2 .\n    2: . . 1"
+        )
+    }
+
+    #[test]
+    fn segre_product() {
+        // P^2 x P^2 in P^8 is Gorenstein, so its Betti table is symmetric
+        let segre = ProjectiveScheme::<PrimeField<101>>::projective_space(3)
+            .product(&ProjectiveScheme::projective_space(3))
+            .unwrap();
+        let table = segre.betti_table();
+        let totals: Vec<usize> = (0..5).map(|i| table.total(i)).collect();
+        assert_eq!(totals, vec![1, 9, 16, 9, 1]);
+        assert_eq!(table.get(4, 6), 1);
+        assert_eq!(table.regularity(), Some(2))
+    }
+}
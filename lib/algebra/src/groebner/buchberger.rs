@@ -0,0 +1,48 @@
+use super::{critical_pairs::PairSet, division::normal_form};
+use crate::{
+    field::Field,
+    polynomials::{MonomialOrder, Polynomial},
+};
+
+/// Buchberger's algorithm
+/// returns a (not necessarily reduced) Groebner basis of the ideal generated by generators
+/// pairs are selected by the normal strategy (smallest lcm first)
+/// useless pairs are removed with the Gebauer-Moeller criteria
+pub fn buchberger<K>(generators: Vec<Polynomial<K>>, order: MonomialOrder) -> Vec<Polynomial<K>>
+where
+    K: Field + Clone,
+{
+    let mut state = PairSet::new(order);
+    for gen in generators.into_iter() {
+        let gen = normal_form(&gen, &state.basis(), order);
+        if !gen.is_zero() {
+            state.insert(gen);
+        }
+    }
+
+    while let Some(pair) = state.select_pair() {
+        let s_poly = s_polynomial(&state.polys[pair.i], &state.polys[pair.j], order);
+        let remainder = normal_form(&s_poly, &state.basis(), order);
+        if !remainder.is_zero() {
+            state.insert(remainder);
+        }
+    }
+    state.basis()
+}
+
+/// S-polynomial of f and g
+/// lcm/lt(f) f - lcm/lt(g) g where lcm is the lcm of the leading terms
+pub fn s_polynomial<K>(f: &Polynomial<K>, g: &Polynomial<K>, order: MonomialOrder) -> Polynomial<K>
+where
+    K: Field + Clone,
+{
+    match (f.leading_term(order), g.leading_term(order)) {
+        (Some(f_lead), Some(g_lead)) => {
+            let lcm = f_lead.lcm(g_lead);
+            let f_factor = lcm.quotient(f_lead).unwrap();
+            let g_factor = lcm.quotient(g_lead).unwrap();
+            f.mul_monomial(&f_factor) - g.mul_monomial(&g_factor)
+        }
+        _ => Polynomial::new(vec![]).with_dim(f.dim().max(g.dim())),
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    field::Field,
+    polynomials::{Monomial, MonomialOrder, Polynomial},
+};
+
+/// Critical pair of basis elements i and j
+/// together with the lcm of their leading terms
+pub(super) struct CriticalPair<K: Field> {
+    pub(super) i: usize,
+    pub(super) j: usize,
+    pub(super) lcm: Monomial<K>,
+}
+
+/// Basis under construction together with its critical pairs
+/// shared by the Buchberger and F4 algorithms
+pub(super) struct PairSet<K: Field> {
+    pub(super) order: MonomialOrder,
+    /// every polynomial that was ever added
+    pub(super) polys: Vec<Polynomial<K>>,
+    /// polys[i] is part of the current basis
+    /// polynomials whose leading term is divisible by a newer one are deactivated
+    pub(super) active: Vec<bool>,
+    pub(super) pairs: Vec<CriticalPair<K>>,
+}
+
+impl<K> PairSet<K>
+where
+    K: Field + Clone,
+{
+    pub(super) fn new(order: MonomialOrder) -> PairSet<K> {
+        PairSet {
+            order,
+            polys: vec![],
+            active: vec![],
+            pairs: vec![],
+        }
+    }
+
+    pub(super) fn basis(&self) -> Vec<Polynomial<K>> {
+        self.polys
+            .iter()
+            .zip(self.active.iter())
+            .filter(|(_, active)| **active)
+            .map(|(poly, _)| poly.clone())
+            .collect()
+    }
+
+    pub(super) fn lead(&self, i: usize) -> &Monomial<K> {
+        self.polys[i].leading_term(self.order).unwrap()
+    }
+
+    pub(super) fn select_pair(&mut self) -> Option<CriticalPair<K>> {
+        let (ind, _) = self
+            .pairs
+            .iter()
+            .enumerate()
+            .min_by(|(_, pair1), (_, pair2)| self.order.cmp(&pair1.lcm, &pair2.lcm))?;
+        Some(self.pairs.swap_remove(ind))
+    }
+
+    /// removes all pairs whose lcm has minimal total degree
+    pub(super) fn select_min_degree(&mut self) -> Vec<CriticalPair<K>> {
+        let deg = match self.pairs.iter().map(|pair| pair.lcm.deg()).min() {
+            None => return vec![],
+            Some(deg) => deg,
+        };
+        let (selected, rest) = std::mem::take(&mut self.pairs)
+            .into_iter()
+            .partition(|pair| pair.lcm.deg() == deg);
+        self.pairs = rest;
+        selected
+    }
+
+    /// Gebauer-Moeller update for a new nonzero basis element
+    pub(super) fn insert(&mut self, poly: Polynomial<K>) {
+        let h = self.polys.len();
+        let lead = poly.leading_term(self.order).unwrap().clone();
+        let poly = poly.scale(<K as Field>::one() / lead.coefficient.clone());
+        self.polys.push(poly);
+        self.active.push(false);
+
+        let candidates: Vec<CriticalPair<K>> = (0..h)
+            .filter(|g| self.active[*g])
+            .map(|g| CriticalPair {
+                i: g,
+                j: h,
+                lcm: self.lead(g).lcm(self.lead(h)),
+            })
+            .collect();
+
+        // keep a pair if its leading terms are coprime
+        // or no other pair with h has an lcm dividing its lcm
+        let mut kept: Vec<&CriticalPair<K>> = vec![];
+        for (ind, pair) in candidates.iter().enumerate() {
+            let coprime = self.lead(pair.i).is_coprime(self.lead(h));
+            let divided = candidates[ind + 1..]
+                .iter()
+                .chain(kept.iter().copied())
+                .any(|other| other.lcm.divides(&pair.lcm));
+            if coprime || !divided {
+                kept.push(pair);
+            }
+        }
+        // pairs with coprime leading terms reduce to zero (Buchberger's first criterion)
+        let new_pairs: Vec<CriticalPair<K>> = kept
+            .into_iter()
+            .filter(|pair| !self.lead(pair.i).is_coprime(self.lead(h)))
+            .map(|pair| CriticalPair {
+                i: pair.i,
+                j: pair.j,
+                lcm: pair.lcm.clone(),
+            })
+            .collect();
+
+        // chain criterion for the old pairs
+        let lead_h = self.lead(h).clone();
+        let old_pairs = std::mem::take(&mut self.pairs);
+        self.pairs = old_pairs
+            .into_iter()
+            .filter(|pair| {
+                !(lead_h.divides(&pair.lcm)
+                    && self.lead(pair.i).lcm(&lead_h).exponents() != pair.lcm.exponents()
+                    && self.lead(pair.j).lcm(&lead_h).exponents() != pair.lcm.exponents())
+            })
+            .collect();
+        self.pairs.extend(new_pairs);
+
+        for g in 0..h {
+            if self.active[g] && lead_h.divides(self.lead(g)) {
+                self.active[g] = false;
+            }
+        }
+        self.active[h] = true;
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    field::Field,
+    polynomials::{MonomialOrder, Polynomial},
+};
+
+/// Multivariate division with remainder
+/// returns quotients q_i and a remainder r with f = q_1 g_1 + ... + q_s g_s + r
+/// such that no term of r is divisible by a leading term of some g_i
+/// the divisors are tried in the given order
+pub fn divide<K>(
+    f: &Polynomial<K>,
+    divisors: &[Polynomial<K>],
+    order: MonomialOrder,
+) -> (Vec<Polynomial<K>>, Polynomial<K>)
+where
+    K: Field + Clone,
+{
+    let mut quotients: Vec<Polynomial<K>> = divisors
+        .iter()
+        .map(|g| Polynomial::new(vec![]).with_dim(g.dim()))
+        .collect();
+    let mut remainder = Polynomial::new(vec![]).with_dim(f.dim());
+    let mut current = f.clone();
+
+    while let Some(lead) = current.leading_term(order).cloned() {
+        let division = divisors.iter().enumerate().find_map(|(ind, g)| {
+            g.leading_term(order)
+                .and_then(|g_lead| lead.quotient(g_lead))
+                .map(|quot| (ind, quot))
+        });
+        match division {
+            Some((ind, quot)) => {
+                current = current - divisors[ind].mul_monomial(&quot);
+                quotients[ind] = quotients[ind].clone() + quot.into();
+            }
+            None => {
+                current = current - lead.clone().into();
+                remainder = remainder + lead.into();
+            }
+        }
+    }
+    (quotients, remainder)
+}
+
+/// Remainder of the division of f by the divisors
+/// the same as divide without keeping track of quotients
+pub fn normal_form<K>(
+    f: &Polynomial<K>,
+    divisors: &[Polynomial<K>],
+    order: MonomialOrder,
+) -> Polynomial<K>
+where
+    K: Field + Clone,
+{
+    let leads: Vec<_> = divisors
+        .iter()
+        .filter_map(|g| g.leading_term(order).map(|lead| (g, lead)))
+        .collect();
+    let mut remainder = vec![];
+    let mut current = f.clone();
+
+    while let Some(lead) = current.leading_term(order).cloned() {
+        match leads
+            .iter()
+            .find_map(|(g, g_lead)| lead.quotient(g_lead).map(|quot| (g, quot)))
+        {
+            Some((g, quot)) => current = current - g.mul_monomial(&quot),
+            None => {
+                current = current - lead.clone().into();
+                remainder.push(lead);
+            }
+        }
+    }
+    Polynomial::new(remainder).with_dim(f.dim())
+}
+
+#[cfg(test)]
+mod division_tests {
+    use super::divide;
+    use crate::{polynomials::MonomialOrder, test_helpers::poly};
+
+    #[test]
+    fn divide_single() {
+        // x^2 + x y + 1 = (x + y) x + 1
+        let f = poly(vec![(1, vec![2, 0]), (1, vec![1, 1]), (1, vec![0, 0])]);
+        let g = poly(vec![(1, vec![1, 0]), (1, vec![0, 1])]);
+        let (quots, rem) = divide(&f, &[g], MonomialOrder::Lex);
+        assert_eq!(quots, vec![poly(vec![(1, vec![1, 0])])]);
+        assert_eq!(rem, poly(vec![(1, vec![0, 0])]))
+    }
+
+    #[test]
+    fn divide_two() {
+        // Cox-Little-O'Shea: x^2 y + x y^2 + y^2 by x y - 1, y^2 - 1
+        let f = poly(vec![(1, vec![2, 1]), (1, vec![1, 2]), (1, vec![0, 2])]);
+        let g1 = poly(vec![(1, vec![1, 1]), (-1, vec![0, 0])]);
+        let g2 = poly(vec![(1, vec![0, 2]), (-1, vec![0, 0])]);
+        let (quots, rem) = divide(&f, &[g1.clone(), g2.clone()], MonomialOrder::Lex);
+        let expected_rem = poly(vec![(1, vec![1, 0]), (1, vec![0, 1]), (1, vec![0, 0])]);
+        assert_eq!(rem, expected_rem);
+        let recombined = quots[0].clone() * g1 + quots[1].clone() * g2 + rem;
+        assert_eq!(recombined, f)
+    }
+}
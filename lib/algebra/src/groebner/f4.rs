@@ -0,0 +1,219 @@
+use super::{critical_pairs::PairSet, division::normal_form};
+use crate::{
+    field::{Field, FiniteField},
+    matrix::sparse::{sub_multiple, SparseRow},
+    polynomials::{Monomial, MonomialOrder, Polynomial},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Faugere's F4 algorithm over finite fields
+/// returns a (not necessarily reduced) Groebner basis of the ideal generated by generators
+/// all critical pairs of minimal degree are reduced at once
+/// by row reducing a sparse matrix whose columns are monomials
+pub fn f4<K>(generators: Vec<Polynomial<K>>, order: MonomialOrder) -> Vec<Polynomial<K>>
+where
+    K: FiniteField,
+{
+    let mut state = PairSet::new(order);
+    for gen in generators.into_iter() {
+        let gen = normal_form(&gen, &state.basis(), order);
+        if !gen.is_zero() {
+            state.insert(gen);
+        }
+    }
+
+    loop {
+        let selected = state.select_min_degree();
+        if selected.is_empty() {
+            break;
+        }
+        let mut rows = vec![];
+        for pair in selected.iter() {
+            for ind in [pair.i, pair.j] {
+                let factor = pair.lcm.quotient(state.lead(ind)).unwrap();
+                rows.push(state.polys[ind].mul_monomial(&factor));
+            }
+        }
+        for new_poly in reduce_rows(&state, rows).into_iter() {
+            state.insert(new_poly);
+        }
+    }
+    state.basis()
+}
+
+/// Symbolic preprocessing and row reduction
+/// returns the reduced rows with leading terms not in the leading terms of the input rows
+fn reduce_rows<K>(state: &PairSet<K>, rows: Vec<Polynomial<K>>) -> Vec<Polynomial<K>>
+where
+    K: FiniteField,
+{
+    let order = state.order;
+    let basis = state.basis();
+
+    // add a reducer for every monomial divisible by a leading term of the basis
+    let mut monomials: HashSet<Vec<usize>> = HashSet::new();
+    let mut done: HashSet<Vec<usize>> = HashSet::new();
+    let mut todo: Vec<Vec<usize>> = vec![];
+    for row in rows.iter() {
+        done.insert(row.leading_term(order).unwrap().powers());
+        for mono in row.terms() {
+            if monomials.insert(mono.powers()) {
+                todo.push(mono.powers());
+            }
+        }
+    }
+    let mut reducers = vec![];
+    while let Some(powers) = todo.pop() {
+        if !done.insert(powers.clone()) {
+            continue;
+        }
+        let mono = Monomial::new(<K as Field>::one(), powers);
+        let reducer = basis.iter().find_map(|g| {
+            mono.quotient(g.leading_term(order).unwrap())
+                .map(|factor| g.mul_monomial(&factor))
+        });
+        if let Some(reducer) = reducer {
+            for mono in reducer.terms() {
+                if monomials.insert(mono.powers()) {
+                    todo.push(mono.powers());
+                }
+            }
+            reducers.push(reducer);
+        }
+    }
+
+    // columns are sorted descending, so the first entry of a row is its leading term
+    let mut columns: Vec<Vec<usize>> = monomials.into_iter().collect();
+    columns.sort_by(|powers1, powers2| order.cmp_powers(powers2, powers1));
+    let column_index: HashMap<Vec<usize>, usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(ind, powers)| (powers.clone(), ind))
+        .collect();
+    let to_row = |poly: &Polynomial<K>| -> SparseRow<K> {
+        let mut row: SparseRow<K> = poly
+            .terms()
+            .iter()
+            .map(|mono| (column_index[mono.exponents()], mono.coefficient.clone()))
+            .collect();
+        row.sort_by_key(|(col, _)| *col);
+        row
+    };
+
+    let mut pivots: Vec<Option<SparseRow<K>>> = vec![None; columns.len()];
+    let mut old_leads: HashSet<usize> = HashSet::new();
+    for row in reducers.iter().chain(rows.iter()) {
+        old_leads.insert(column_index[row.leading_term(order).unwrap().exponents()]);
+    }
+    for reducer in reducers.iter() {
+        let row = to_row(reducer);
+        let lead = row[0].0;
+        pivots[lead] = Some(row);
+    }
+
+    let mut new_leads = vec![];
+    for row in rows.iter() {
+        let mut row = to_row(row);
+        while let Some((lead, coeff)) = row.first().cloned() {
+            match &pivots[lead] {
+                Some(pivot) => row = sub_multiple(&row, coeff, pivot),
+                None => break,
+            }
+        }
+        if let Some((lead, coeff)) = row.first().cloned() {
+            let inv = coeff.inverse();
+            let row = row
+                .into_iter()
+                .map(|(col, c)| (col, c * inv.clone()))
+                .collect();
+            pivots[lead] = Some(row);
+            if !old_leads.contains(&lead) {
+                new_leads.push(lead);
+            }
+        }
+    }
+
+    new_leads
+        .into_iter()
+        .map(|lead| {
+            let row = pivots[lead].take().unwrap();
+            Polynomial::new(
+                row.into_iter()
+                    .map(|(col, c)| Monomial::new(c, columns[col].clone()))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod f4_tests {
+    use super::super::{buchberger, reduce_basis};
+    use super::f4;
+    use crate::{
+        polynomials::{MonomialOrder, Polynomial},
+        prime_field::PrimeField,
+        test_helpers::poly_over,
+    };
+
+    type F = PrimeField<32003>;
+
+    fn compare(generators: Vec<Polynomial<F>>, order: MonomialOrder) {
+        let expected = reduce_basis(buchberger(generators.clone(), order), order);
+        let result = reduce_basis(f4(generators, order), order);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn twisted_cubic() {
+        let f1 = poly_over(vec![(1, vec![0, 1, 0]), (-1, vec![2, 0, 0])]);
+        let f2 = poly_over(vec![(1, vec![0, 0, 1]), (-1, vec![3, 0, 0])]);
+        compare(vec![f1.clone(), f2.clone()], MonomialOrder::GRevLex);
+        compare(vec![f1, f2], MonomialOrder::Lex)
+    }
+
+    #[test]
+    fn cyclic_3() {
+        // x + y + z, xy + yz + zx, xyz - 1
+        let f1 = poly_over(vec![
+            (1, vec![1, 0, 0]),
+            (1, vec![0, 1, 0]),
+            (1, vec![0, 0, 1]),
+        ]);
+        let f2 = poly_over(vec![
+            (1, vec![1, 1, 0]),
+            (1, vec![0, 1, 1]),
+            (1, vec![1, 0, 1]),
+        ]);
+        let f3 = poly_over(vec![(1, vec![1, 1, 1]), (-1, vec![0, 0, 0])]);
+        compare(
+            vec![f1.clone(), f2.clone(), f3.clone()],
+            MonomialOrder::GRevLex,
+        );
+        compare(vec![f1, f2, f3], MonomialOrder::Lex)
+    }
+
+    #[test]
+    fn cyclic_4() {
+        let f1 = poly_over(vec![
+            (1, vec![1, 0, 0, 0]),
+            (1, vec![0, 1, 0, 0]),
+            (1, vec![0, 0, 1, 0]),
+            (1, vec![0, 0, 0, 1]),
+        ]);
+        let f2 = poly_over(vec![
+            (1, vec![1, 1, 0, 0]),
+            (1, vec![0, 1, 1, 0]),
+            (1, vec![0, 0, 1, 1]),
+            (1, vec![1, 0, 0, 1]),
+        ]);
+        let f3 = poly_over(vec![
+            (1, vec![1, 1, 1, 0]),
+            (1, vec![0, 1, 1, 1]),
+            (1, vec![1, 0, 1, 1]),
+            (1, vec![1, 1, 0, 1]),
+        ]);
+        let f4 = poly_over(vec![(1, vec![1, 1, 1, 1]), (-1, vec![0, 0, 0, 0])]);
+        compare(vec![f1, f2, f3, f4], MonomialOrder::GRevLex)
+    }
+}
@@ -0,0 +1,300 @@
+pub mod buchberger;
+mod critical_pairs;
+pub mod division;
+pub mod f4;
+
+pub use buchberger::{buchberger, s_polynomial};
+pub use division::{divide, normal_form};
+pub use f4::f4;
+
+use crate::{
+    field::{Field, FiniteField},
+    polynomials::{Monomial, MonomialOrder, Polynomial},
+};
+use std::fmt;
+
+/// Reduced Groebner basis of a polynomial ideal with respect to a monomial order
+/// every element is monic, no term of an element is divisible by the leading term of another
+/// and elements are sorted descending by leading term
+/// this makes the basis unique for a given ideal and order
+#[derive(Clone, Debug)]
+pub struct GroebnerBasis<K: Field> {
+    order: MonomialOrder,
+    basis: Vec<Polynomial<K>>,
+}
+
+impl<K> GroebnerBasis<K>
+where
+    K: Field + Clone,
+{
+    pub fn new(generators: Vec<Polynomial<K>>, order: MonomialOrder) -> GroebnerBasis<K> {
+        let dim = generators.iter().map(|gen| gen.dim()).max().unwrap_or(0);
+        let generators = generators
+            .into_iter()
+            .map(|gen| gen.with_dim(dim))
+            .collect();
+        GroebnerBasis {
+            order,
+            basis: reduce_basis(buchberger(generators, order), order),
+        }
+    }
+
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    pub fn basis(&self) -> &[Polynomial<K>] {
+        &self.basis
+    }
+
+    pub fn leading_terms(&self) -> Vec<Monomial<K>> {
+        self.basis
+            .iter()
+            .filter_map(|g| g.leading_term(self.order).cloned())
+            .collect()
+    }
+
+    /// unique representative of f modulo the ideal
+    pub fn normal_form(&self, f: &Polynomial<K>) -> Polynomial<K> {
+        normal_form(f, &self.basis, self.order)
+    }
+
+    /// ideal membership
+    pub fn contains(&self, f: &Polynomial<K>) -> bool {
+        self.normal_form(f).is_zero()
+    }
+
+    /// checks if the ideal of other is contained in the ideal of self
+    pub fn contains_ideal(&self, other: &GroebnerBasis<K>) -> bool {
+        other.basis.iter().all(|g| self.contains(g))
+    }
+
+    /// the ideal is the whole ring
+    pub fn is_unit_ideal(&self) -> bool {
+        self.basis
+            .iter()
+            .any(|g| g.total_degree() == 0 && !g.is_zero())
+    }
+}
+
+impl<K> GroebnerBasis<K>
+where
+    K: FiniteField,
+{
+    /// Same as new, but computed with the F4 algorithm
+    pub fn new_f4(generators: Vec<Polynomial<K>>, order: MonomialOrder) -> GroebnerBasis<K> {
+        let dim = generators.iter().map(|gen| gen.dim()).max().unwrap_or(0);
+        let generators = generators
+            .into_iter()
+            .map(|gen| gen.with_dim(dim))
+            .collect();
+        GroebnerBasis {
+            order,
+            basis: reduce_basis(f4(generators, order), order),
+        }
+    }
+}
+
+/// Equality of the generated ideals
+/// for the same order this is equality of reduced bases
+impl<K> PartialEq for GroebnerBasis<K>
+where
+    K: Field + Clone,
+{
+    fn eq(&self, other: &GroebnerBasis<K>) -> bool {
+        if self.order == other.order {
+            self.basis == other.basis
+        } else {
+            self.contains_ideal(other) && other.contains_ideal(self)
+        }
+    }
+}
+
+/// Turns a Groebner basis into the reduced Groebner basis of the same ideal
+pub fn reduce_basis<K>(basis: Vec<Polynomial<K>>, order: MonomialOrder) -> Vec<Polynomial<K>>
+where
+    K: Field + Clone,
+{
+    let mut basis: Vec<Polynomial<K>> = basis.into_iter().filter(|g| !g.is_zero()).collect();
+    basis.sort_by(|f, g| {
+        order.cmp(
+            f.leading_term(order).unwrap(),
+            g.leading_term(order).unwrap(),
+        )
+    });
+
+    // minimal basis: no leading term divides another one
+    let mut minimal: Vec<Polynomial<K>> = vec![];
+    for g in basis.into_iter() {
+        let lead = g.leading_term(order).unwrap();
+        if !minimal
+            .iter()
+            .any(|f| f.leading_term(order).unwrap().divides(lead))
+        {
+            minimal.push(g);
+        }
+    }
+
+    let mut reduced = vec![];
+    for ind in 0..minimal.len() {
+        let others: Vec<Polynomial<K>> = minimal
+            .iter()
+            .enumerate()
+            .filter(|(other_ind, _)| *other_ind != ind)
+            .map(|(_, g)| g.clone())
+            .collect();
+        let g = normal_form(&minimal[ind], &others, order);
+        reduced.push(make_monic(g, order));
+    }
+    reduced.reverse();
+    reduced
+}
+
+/// scales a polynomial so its leading coefficient is one
+pub fn make_monic<K>(f: Polynomial<K>, order: MonomialOrder) -> Polynomial<K>
+where
+    K: Field + Clone,
+{
+    match f.leading_term(order) {
+        None => f,
+        Some(lead) => {
+            let factor = <K as Field>::one() / lead.coefficient.clone();
+            f.scale(factor)
+        }
+    }
+}
+
+impl<K> fmt::Display for GroebnerBasis<K>
+where
+    K: Field,
+    K: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let basis_str: Vec<String> = self.basis.iter().map(|g| format!("{}", g)).collect();
+        write!(f, "<{}>", basis_str.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod groebner_tests {
+    use super::GroebnerBasis;
+    use crate::{
+        field::Field,
+        polynomials::{Monomial, MonomialOrder, Polynomial},
+        rational::Rational,
+        test_helpers::poly,
+    };
+
+    #[test]
+    fn twisted_cubic_grevlex() {
+        // y - x^2, z - x^3 in variables x, y, z
+        let f1 = poly(vec![(1, vec![0, 1, 0]), (-1, vec![2, 0, 0])]);
+        let f2 = poly(vec![(1, vec![0, 0, 1]), (-1, vec![3, 0, 0])]);
+        let gb = GroebnerBasis::new(vec![f1, f2], MonomialOrder::GRevLex);
+        let expected = vec![
+            poly(vec![(1, vec![2, 0, 0]), (-1, vec![0, 1, 0])]),
+            poly(vec![(1, vec![1, 1, 0]), (-1, vec![0, 0, 1])]),
+            poly(vec![(1, vec![0, 2, 0]), (-1, vec![1, 0, 1])]),
+        ];
+        assert_eq!(gb.basis(), expected.as_slice())
+    }
+
+    #[test]
+    fn lex_elimination() {
+        // x^2 + y^2 - 1, x - y in lex with x > y gives x - y, y^2 - 1/2
+        let f1 = poly(vec![(1, vec![2, 0]), (1, vec![0, 2]), (-1, vec![0, 0])]);
+        let f2 = poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])]);
+        let gb = GroebnerBasis::new(vec![f1, f2], MonomialOrder::Lex);
+        let half = Rational::new(-1, 2).unwrap();
+        let expected = vec![
+            poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])]),
+            Polynomial::new(vec![
+                Monomial::new(1.into(), vec![0, 2]),
+                Monomial::new(half, vec![0, 0]),
+            ]),
+        ];
+        assert_eq!(gb.basis(), expected.as_slice())
+    }
+
+    #[test]
+    fn membership() {
+        let f1 = poly(vec![(1, vec![1, 1]), (-1, vec![0, 0])]);
+        let f2 = poly(vec![(1, vec![0, 2]), (-1, vec![0, 0])]);
+        let gb = GroebnerBasis::new(vec![f1, f2], MonomialOrder::GrLex);
+        // x - y = y (x y - 1) - x (y^2 - 1)
+        assert!(gb.contains(&poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])])));
+        assert!(!gb.contains(&poly(vec![(1, vec![1, 0])])))
+    }
+
+    #[test]
+    fn unit_ideal() {
+        let f1 = poly(vec![(1, vec![1, 0]), (-1, vec![0, 0])]);
+        let f2 = poly(vec![(1, vec![1, 0])]);
+        let gb = GroebnerBasis::new(vec![f1, f2], MonomialOrder::GRevLex);
+        assert!(gb.is_unit_ideal());
+        assert_eq!(gb.basis(), &[poly(vec![(1, vec![0, 0])])])
+    }
+
+    #[test]
+    fn ideal_equality_across_orders() {
+        let f1 = poly(vec![(1, vec![1, 1]), (-1, vec![0, 0])]);
+        let f2 = poly(vec![(1, vec![0, 2]), (-1, vec![0, 0])]);
+        let g1 = poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])]);
+        let gb1 = GroebnerBasis::new(vec![f1, f2.clone()], MonomialOrder::Lex);
+        let gb2 = GroebnerBasis::new(vec![g1, f2], MonomialOrder::GRevLex);
+        assert_eq!(gb1, gb2)
+    }
+
+    /// u_0 + 2 u_1 + ... + 2 u_n - 1 and sum_l u_|l| u_|m-l| - u_m for m < n
+    fn katsura(n: usize) -> Vec<Polynomial<Rational>> {
+        let dim = n + 1;
+        let u = |i: usize| Polynomial::<Rational>::variable(i, dim);
+        let mut linear = u(0) - Polynomial::constant(<Rational as Field>::one()).with_dim(dim);
+        for i in 1..=n {
+            linear = linear + u(i).scale(Rational::from(2));
+        }
+        let mut generators = vec![linear];
+        for m in 0..n as i64 {
+            let mut relation = -u(m as usize);
+            for l in -(n as i64)..=n as i64 {
+                if (m - l).unsigned_abs() as usize <= n {
+                    relation = relation
+                        + u(l.unsigned_abs() as usize) * u((m - l).unsigned_abs() as usize);
+                }
+            }
+            generators.push(relation);
+        }
+        generators
+    }
+
+    /// some coefficient of the basis does not fit into i64
+    fn beyond_i64(gb: &GroebnerBasis<Rational>) -> bool {
+        gb.basis().iter().flat_map(|g| g.terms()).any(|m| {
+            m.coefficient.num().to_i64().is_none() || m.coefficient.den().to_i64().is_none()
+        })
+    }
+
+    #[test]
+    fn katsura_coefficient_growth() {
+        // intermediate coefficients do not fit into i64, the reduced bases do
+        let generators = katsura(4);
+        let gb = GroebnerBasis::new(generators.clone(), MonomialOrder::GrLex);
+        assert!(generators.iter().all(|f| gb.contains(f)));
+        assert_eq!(gb.basis().len(), 16);
+        let generators = katsura(3);
+        let gb = GroebnerBasis::new(generators.clone(), MonomialOrder::Lex);
+        assert!(generators.iter().all(|f| gb.contains(f)));
+        assert_eq!(gb.basis().len(), 4)
+    }
+
+    /// the coefficients of the lex basis have about 140 digits
+    #[test]
+    #[ignore = "takes minutes, run with --ignored"]
+    fn katsura_4_lex() {
+        let generators = katsura(4);
+        let gb = GroebnerBasis::new(generators.clone(), MonomialOrder::Lex);
+        assert!(generators.iter().all(|f| gb.contains(f)));
+        assert_eq!(gb.basis().len(), 5);
+        assert!(beyond_i64(&gb))
+    }
+}
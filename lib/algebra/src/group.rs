@@ -1,4 +1,8 @@
-use std::ops::{Add, Mul, Neg};
+use super::errors::Error;
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg},
+};
 
 /// Abelian Group
 /// Elements need to be able to be added
@@ -26,27 +30,380 @@ pub trait Group: Mul<Output = Self> + PartialEq + Sized {
     fn inverse(self) -> Self;
 }
 
-///Subgroup of a group G
-///implemented with an element check
-pub struct SubGroup<G>
+/// Groups in which finitely many elements generate a finite subgroup
+/// this can not be checked by the compiler, so this is a marker
+/// subgroups are only enumerated for these groups, otherwise the enumeration would not terminate
+pub trait FiniteGroup: Group {}
+
+/// Abelian groups in which finitely many elements generate a finite subgroup
+pub trait FiniteAbelianGroup: AbelianGroup {}
+
+/// Subgroup of a group G generated by finitely many elements
+/// G has to be a FiniteGroup, the elements are enumerated on construction
+/// so membership is decided by lookup
+#[derive(Clone, Debug)]
+pub struct SubGroup<G: Group> {
+    generators: Vec<G>,
+    elements: Vec<G>,
+}
+
+impl<G: FiniteGroup + Clone> SubGroup<G> {
+    /// the smallest subgroup containing all generators
+    /// in a finite group the closure under multiplication is already closed under inverses
+    pub fn generated_by(generators: Vec<G>) -> SubGroup<G> {
+        let mut elements = vec![G::one()];
+        let mut next = 0;
+        while next < elements.len() {
+            for gen in generators.iter() {
+                let product = elements[next].clone() * gen.clone();
+                if !elements.contains(&product) {
+                    elements.push(product);
+                }
+            }
+            next += 1;
+        }
+        SubGroup {
+            generators,
+            elements,
+        }
+    }
+
+    /// the subgroup {1}
+    pub fn trivial() -> SubGroup<G> {
+        SubGroup::generated_by(vec![])
+    }
+
+    /// subgroup with the given elements
+    /// generators are picked greedily among them
+    fn from_elements(elements: &[G]) -> SubGroup<G> {
+        let mut subgroup = SubGroup::trivial();
+        for elem in elements.iter() {
+            if !subgroup.contains(elem) {
+                let mut generators = subgroup.generators;
+                generators.push(elem.clone());
+                subgroup = SubGroup::generated_by(generators);
+            }
+        }
+        subgroup
+    }
+
+    pub fn intersection(&self, other: &SubGroup<G>) -> SubGroup<G> {
+        let common: Vec<G> = self
+            .elements
+            .iter()
+            .filter(|elem| other.contains(elem))
+            .cloned()
+            .collect();
+        SubGroup::from_elements(&common)
+    }
+
+    /// the subgroup generated by self and other
+    pub fn join(&self, other: &SubGroup<G>) -> SubGroup<G> {
+        let mut generators = self.generators.clone();
+        generators.extend(other.generators.iter().cloned());
+        SubGroup::generated_by(generators)
+    }
+}
+
+impl<G: Group + Clone> SubGroup<G> {
+    pub fn generators(&self) -> &[G] {
+        &self.generators
+    }
+
+    /// all elements, starting with the identity
+    pub fn elements(&self) -> &[G] {
+        &self.elements
+    }
+
+    /// number of elements
+    pub fn order(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn contains(&self, elem: &G) -> bool {
+        self.elements.contains(elem)
+    }
+
+    /// checks if all generators of self lie in other
+    pub fn is_subgroup_of(&self, other: &SubGroup<G>) -> bool {
+        self.generators.iter().all(|gen| other.contains(gen))
+    }
+
+    /// the left coset gH
+    pub fn left_coset(&self, representative: G) -> Coset<G> {
+        Coset {
+            representative,
+            subgroup: self.clone(),
+        }
+    }
+
+    /// all left cosets of self in group, the coset of the identity first
+    pub fn left_cosets(&self, group: &SubGroup<G>) -> Result<Vec<Coset<G>>, Error> {
+        if !self.is_subgroup_of(group) {
+            return Err(Error::NotASubgroup);
+        }
+        let mut cosets: Vec<Coset<G>> = vec![];
+        for elem in group.elements.iter() {
+            if cosets.iter().all(|coset| !coset.contains(elem)) {
+                cosets.push(self.left_coset(elem.clone()));
+            }
+        }
+        Ok(cosets)
+    }
+
+    /// [group : self], the number of cosets
+    pub fn index_in(&self, group: &SubGroup<G>) -> Result<usize, Error> {
+        if !self.is_subgroup_of(group) {
+            return Err(Error::NotASubgroup);
+        }
+        Ok(group.order() / self.order())
+    }
+
+    /// checks if self is a normal subgroup of group
+    /// it suffices to conjugate generators by generators
+    pub fn is_normal_in(&self, group: &SubGroup<G>) -> bool {
+        self.is_subgroup_of(group)
+            && group.generators.iter().all(|g| {
+                self.generators.iter().all(|h| {
+                    let conjugate = g.clone() * h.clone() * g.clone().inverse();
+                    self.contains(&conjugate)
+                })
+            })
+    }
+
+    /// the quotient group by self, self has to be normal in group
+    pub fn quotient(&self, group: &SubGroup<G>) -> Result<QuotientGroup<G>, Error> {
+        if !self.is_normal_in(group) {
+            return Err(Error::NotNormal);
+        }
+        Ok(QuotientGroup {
+            cosets: self.left_cosets(group)?,
+            normal_subgroup: self.clone(),
+        })
+    }
+}
+
+/// subgroups are equal if they have the same elements
+impl<G: Group + Clone> PartialEq for SubGroup<G> {
+    fn eq(&self, other: &SubGroup<G>) -> bool {
+        self.order() == other.order() && self.is_subgroup_of(other)
+    }
+}
+
+impl<G> fmt::Display for SubGroup<G>
 where
-    G: Group,
+    G: Group + fmt::Display,
 {
-    pub elem: Box<dyn Fn(&G) -> bool>,
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gen_str: Vec<String> = self
+            .generators
+            .iter()
+            .map(|gen| format!("{}", gen))
+            .collect();
+        write!(f, "<{}>", gen_str.join(", "))
+    }
+}
+
+/// Left coset gH of a subgroup H
+#[derive(Clone, Debug)]
+pub struct Coset<G: Group> {
+    representative: G,
+    subgroup: SubGroup<G>,
 }
 
-///Abelian Subgroup of a group G
-pub struct AbelianSubGroup<G>
+impl<G: Group + Clone> Coset<G> {
+    pub fn representative(&self) -> &G {
+        &self.representative
+    }
+
+    pub fn subgroup(&self) -> &SubGroup<G> {
+        &self.subgroup
+    }
+
+    /// the elements gh in the order of the elements h of H
+    pub fn elements(&self) -> Vec<G> {
+        self.subgroup
+            .elements
+            .iter()
+            .map(|h| self.representative.clone() * h.clone())
+            .collect()
+    }
+
+    /// x lies in gH if g^-1 x lies in H
+    pub fn contains(&self, elem: &G) -> bool {
+        let quotient = self.representative.clone().inverse() * elem.clone();
+        self.subgroup.contains(&quotient)
+    }
+}
+
+/// cosets of the same subgroup are equal if they share an element
+impl<G: Group + Clone> PartialEq for Coset<G> {
+    fn eq(&self, other: &Coset<G>) -> bool {
+        self.subgroup == other.subgroup && self.contains(&other.representative)
+    }
+}
+
+/// (gN)(hN) = ghN
+/// only well defined for cosets of the same normal subgroup N
+impl<G: Group + Clone> Mul for Coset<G> {
+    type Output = Coset<G>;
+    fn mul(self, other: Coset<G>) -> Coset<G> {
+        Coset {
+            representative: self.representative * other.representative,
+            subgroup: self.subgroup,
+        }
+    }
+}
+
+/// The quotient group G/N of a finite group by a normal subgroup
+/// elements are the cosets of N
+#[derive(Clone, Debug)]
+pub struct QuotientGroup<G: Group> {
+    normal_subgroup: SubGroup<G>,
+    cosets: Vec<Coset<G>>,
+}
+
+impl<G: Group + Clone> QuotientGroup<G> {
+    pub fn normal_subgroup(&self) -> &SubGroup<G> {
+        &self.normal_subgroup
+    }
+
+    /// all elements, the identity coset N first
+    pub fn cosets(&self) -> &[Coset<G>] {
+        &self.cosets
+    }
+
+    pub fn order(&self) -> usize {
+        self.cosets.len()
+    }
+
+    pub fn identity(&self) -> Coset<G> {
+        self.cosets[0].clone()
+    }
+
+    /// the coset gN, None if g does not lie in G
+    pub fn coset_of(&self, elem: &G) -> Option<Coset<G>> {
+        self.cosets
+            .iter()
+            .find(|coset| coset.contains(elem))
+            .cloned()
+    }
+}
+
+/// Subgroup of an abelian group generated by finitely many elements
+/// the subgroup of the multiplicative view, see AsMultGroup
+#[derive(Clone, Debug)]
+pub struct AbelianSubGroup<G: AbelianGroup> {
+    subgroup: SubGroup<AsMultGroup<G>>,
+}
+
+impl<G: FiniteAbelianGroup + Clone> AbelianSubGroup<G> {
+    pub fn generated_by(generators: Vec<G>) -> AbelianSubGroup<G> {
+        AbelianSubGroup {
+            subgroup: SubGroup::generated_by(
+                generators.into_iter().map(AsMultGroup::from).collect(),
+            ),
+        }
+    }
+
+    pub fn intersection(&self, other: &AbelianSubGroup<G>) -> AbelianSubGroup<G> {
+        AbelianSubGroup {
+            subgroup: self.subgroup.intersection(&other.subgroup),
+        }
+    }
+
+    /// the sum H + K, generated by both subgroups
+    pub fn sum(&self, other: &AbelianSubGroup<G>) -> AbelianSubGroup<G> {
+        AbelianSubGroup {
+            subgroup: self.subgroup.join(&other.subgroup),
+        }
+    }
+}
+
+impl<G: AbelianGroup + Clone> AbelianSubGroup<G> {
+    pub fn generators(&self) -> Vec<G> {
+        self.subgroup
+            .generators
+            .iter()
+            .map(|gen| gen.elem.clone())
+            .collect()
+    }
+
+    /// all elements, starting with zero
+    pub fn elements(&self) -> Vec<G> {
+        self.subgroup
+            .elements
+            .iter()
+            .map(|elem| elem.elem.clone())
+            .collect()
+    }
+
+    pub fn order(&self) -> usize {
+        self.subgroup.order()
+    }
+
+    pub fn contains(&self, elem: &G) -> bool {
+        self.subgroup.contains(&elem.clone().into())
+    }
+
+    /// the multiplicative view, for cosets and quotients
+    /// every subgroup of an abelian group is normal
+    pub fn as_subgroup(&self) -> &SubGroup<AsMultGroup<G>> {
+        &self.subgroup
+    }
+}
+
+impl<G: AbelianGroup + Clone> PartialEq for AbelianSubGroup<G> {
+    fn eq(&self, other: &AbelianSubGroup<G>) -> bool {
+        self.subgroup == other.subgroup
+    }
+}
+
+impl<G> fmt::Display for AbelianSubGroup<G>
 where
-    G: AbelianGroup,
+    G: AbelianGroup + Clone + fmt::Display,
 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gen_str: Vec<String> = self
+            .generators()
+            .iter()
+            .map(|gen| format!("{}", gen))
+            .collect();
+        write!(f, "<{}>", gen_str.join(", "))
+    }
+}
+
+/// Subgroup given only by a membership test
+/// for subgroups without finite generating sets or with infinitely many elements
+pub struct PredicateSubGroup<G> {
     pub elem: Box<dyn Fn(&G) -> bool>,
 }
 
+impl<G: 'static> PredicateSubGroup<G> {
+    pub fn contains(&self, elem: &G) -> bool {
+        (self.elem)(elem)
+    }
+
+    /// elements satisfying both predicates
+    pub fn intersection(self, other: PredicateSubGroup<G>) -> PredicateSubGroup<G> {
+        PredicateSubGroup {
+            elem: Box::new(move |g| (self.elem)(g) && (other.elem)(g)),
+        }
+    }
+}
+
+impl<G: Group + Clone + 'static> From<SubGroup<G>> for PredicateSubGroup<G> {
+    fn from(subgroup: SubGroup<G>) -> PredicateSubGroup<G> {
+        PredicateSubGroup {
+            elem: Box::new(move |g| subgroup.contains(g)),
+        }
+    }
+}
+
 /// Treat a group as multiplicative group
 /// i.e. forget its abelian
 /// simply wraps an element
-#[derive(PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct AsMultGroup<G>
 where
     G: AbelianGroup,
@@ -54,6 +411,13 @@ where
     elem: G,
 }
 
+impl<G: AbelianGroup> AsMultGroup<G> {
+    /// the wrapped element of the abelian group
+    pub fn into_inner(self) -> G {
+        self.elem
+    }
+}
+
 ///Abelian groups can be treated as mutliplicative groups
 impl<G> From<G> for AsMultGroup<G>
 where
@@ -69,6 +433,8 @@ where
     G: AbelianGroup,
 {
     type Output = Self;
+    /// the group product is the addition of the abelian group
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, other: AsMultGroup<G>) -> Self {
         (self.elem + other.elem).into()
     }
@@ -86,3 +452,72 @@ where
         (-self.elem).into()
     }
 }
+
+impl<G: FiniteAbelianGroup> FiniteGroup for AsMultGroup<G> {}
+
+#[cfg(test)]
+mod group_tests {
+    use super::{AbelianSubGroup, AsMultGroup, PredicateSubGroup};
+    use crate::galois_field::GaloisField;
+
+    // the additive group of F_9 is (Z/3)^2
+    type F9 = GaloisField<3, 2>;
+
+    fn elem(a: u64, b: u64) -> F9 {
+        GaloisField::new([a, b])
+    }
+
+    #[test]
+    fn generated_subgroups() {
+        let line = AbelianSubGroup::generated_by(vec![elem(1, 1)]);
+        assert_eq!(line.order(), 3);
+        assert!(line.contains(&elem(2, 2)));
+        assert!(!line.contains(&elem(1, 0)));
+        let plane = AbelianSubGroup::generated_by(vec![elem(1, 0), elem(0, 1)]);
+        assert_eq!(plane.order(), 9);
+        assert_eq!(
+            line.sum(&AbelianSubGroup::generated_by(vec![elem(1, 2)])),
+            plane
+        )
+    }
+
+    #[test]
+    fn intersections() {
+        let first = AbelianSubGroup::generated_by(vec![elem(1, 0)]);
+        let second = AbelianSubGroup::generated_by(vec![elem(0, 1)]);
+        let third = AbelianSubGroup::generated_by(vec![elem(2, 0), elem(0, 1)]);
+        assert_eq!(first.intersection(&second).order(), 1);
+        assert_eq!(first.intersection(&third), first);
+        assert_eq!(first.intersection(&third).generators().len(), 1)
+    }
+
+    #[test]
+    fn cosets_and_quotient() {
+        let group = AbelianSubGroup::generated_by(vec![elem(1, 0), elem(0, 1)]);
+        let line = AbelianSubGroup::generated_by(vec![elem(1, 1)]);
+        let cosets = line.as_subgroup().left_cosets(group.as_subgroup()).unwrap();
+        assert_eq!(cosets.len(), 3);
+        assert!(cosets[1].contains(&(cosets[1].representative().clone() * elem(2, 2).into())));
+        let quotient = line.as_subgroup().quotient(group.as_subgroup()).unwrap();
+        assert_eq!(quotient.order(), 3);
+        // (1, 0) + L generates the quotient
+        let generator = quotient.coset_of(&elem(1, 0).into()).unwrap();
+        let square = generator.clone() * generator.clone();
+        assert_eq!(square, quotient.coset_of(&elem(2, 0).into()).unwrap());
+        assert_eq!(square * generator, quotient.identity());
+        assert!(group.as_subgroup().left_cosets(line.as_subgroup()).is_err())
+    }
+
+    #[test]
+    fn predicates() {
+        let first_coordinate_zero = PredicateSubGroup {
+            elem: Box::new(|g: &AsMultGroup<F9>| g.clone().into_inner().coefficients()[0] == 0),
+        };
+        let line = AbelianSubGroup::generated_by(vec![elem(1, 1)])
+            .as_subgroup()
+            .clone();
+        let both = first_coordinate_zero.intersection(line.into());
+        assert!(both.contains(&elem(0, 0).into()));
+        assert!(!both.contains(&elem(0, 1).into()))
+    }
+}
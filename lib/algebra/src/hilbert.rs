@@ -0,0 +1,288 @@
+use super::{
+    field::Field,
+    group::AbelianGroup,
+    ideal::PolynomialIdeal,
+    polynomials::{Monomial, Polynomial},
+    rational::Rational,
+    ring::Ring,
+};
+use std::fmt;
+
+/// Hilbert series of K[X_0,...,X_(n-1)]/I for a homogeneous ideal I
+/// stored as N(t)/(1-t)^n with integer numerator N
+/// computed from the leading term ideal, which has the same Hilbert function
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HilbertSeries {
+    numerator: Vec<i64>,
+    num_variables: usize,
+}
+
+/// Hilbert polynomial as polynomial in one variable with rational coefficients
+/// agrees with the Hilbert function for large degrees
+#[derive(Clone, Debug, PartialEq)]
+pub struct HilbertPolynomial {
+    poly: Polynomial<Rational>,
+}
+
+impl HilbertSeries {
+    pub fn new<K>(ideal: &PolynomialIdeal<K>) -> HilbertSeries
+    where
+        K: Field + Clone,
+    {
+        let leads: Vec<Vec<usize>> = ideal
+            .groebner_basis()
+            .leading_terms()
+            .into_iter()
+            .map(|mono| mono.powers())
+            .collect();
+        HilbertSeries::from_monomial_ideal(&leads, ideal.dim())
+    }
+
+    /// Hilbert series of the quotient by a monomial ideal given by its generators
+    pub fn from_monomial_ideal(generators: &[Vec<usize>], num_variables: usize) -> HilbertSeries {
+        let generators = generators
+            .iter()
+            .map(|powers| Monomial::new(Rational::from(1), powers.clone()))
+            .collect();
+        HilbertSeries {
+            numerator: monomial_numerator(minimize(generators)),
+            num_variables,
+        }
+    }
+
+    /// coefficients of N(t), lowest degree first
+    pub fn numerator(&self) -> Vec<i64> {
+        self.numerator.clone()
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// cancels factors (1-t) so that N(1) != 0
+    /// returns the new numerator and the remaining power of (1-t)
+    /// None for the zero series
+    pub fn reduced(&self) -> Option<(Vec<i64>, usize)> {
+        if self.numerator.iter().all(|c| *c == 0) {
+            return None;
+        }
+        let mut numerator = self.numerator.clone();
+        let mut power = self.num_variables;
+        while numerator.iter().sum::<i64>() == 0 {
+            // N(t) = (1-t) M(t) with M_i = N_0 + ... + N_i
+            let mut quotient = vec![];
+            let mut acc = 0;
+            for c in numerator.iter().take(numerator.len() - 1) {
+                acc += c;
+                quotient.push(acc);
+            }
+            numerator = quotient;
+            power -= 1;
+        }
+        Some((numerator, power))
+    }
+
+    /// dimension of the degree d part of the quotient ring
+    pub fn hilbert_function(&self, d: usize) -> i64 {
+        self.numerator
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i <= d)
+            .map(|(i, c)| c * count_monomials(self.num_variables, d - i))
+            .sum()
+    }
+
+    /// Krull dimension of the quotient ring
+    /// None if the quotient is the zero ring
+    pub fn krull_dimension(&self) -> Option<usize> {
+        self.reduced().map(|(_, dim)| dim)
+    }
+
+    /// degree (multiplicity) of the quotient ring, i.e. N(1) of the reduced numerator
+    pub fn degree(&self) -> i64 {
+        self.reduced()
+            .map(|(numerator, _)| numerator.iter().sum())
+            .unwrap_or(0)
+    }
+
+    /// sum_i N_i binom(s - i + d - 1, d - 1) for the reduced numerator
+    pub fn hilbert_polynomial(&self) -> HilbertPolynomial {
+        let mut poly = Polynomial::zero().with_dim(1);
+        if let Some((numerator, dim)) = self.reduced() {
+            if dim > 0 {
+                for (i, c) in numerator.iter().enumerate() {
+                    let shifted = binomial_polynomial(dim as i64 - 1 - i as i64, dim - 1);
+                    poly = poly + shifted.scale((*c).into());
+                }
+            }
+        }
+        HilbertPolynomial { poly }
+    }
+}
+
+impl HilbertPolynomial {
+    pub fn eval(&self, s: i64) -> Rational {
+        self.poly.eval(vec![s.into()]).unwrap()
+    }
+
+    pub fn degree(&self) -> usize {
+        self.poly.total_degree()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+
+    /// coefficients, lowest degree first
+    pub fn coefficients(&self) -> Vec<Rational> {
+        let mut coefficients = vec![Rational::from(0); self.degree() + 1];
+        for mono in self.poly.terms() {
+            coefficients[mono.deg()] = mono.coefficient.clone();
+        }
+        coefficients
+    }
+}
+
+/// binom(s + a, k) as polynomial in s
+fn binomial_polynomial(a: i64, k: usize) -> Polynomial<Rational> {
+    let mut res = Polynomial::one().with_dim(1);
+    for j in 1..=k {
+        let factor = Polynomial::new(vec![
+            Monomial::new(Rational::from(1), vec![1]),
+            Monomial::new(Rational::from(a - j as i64 + 1), vec![0]),
+        ]);
+        res = (res * factor).scale(Rational::new(1, j as i64).unwrap());
+    }
+    res
+}
+
+/// number of monomials of degree d in n variables
+fn count_monomials(n: usize, d: usize) -> i64 {
+    if n == 0 {
+        return if d == 0 { 1 } else { 0 };
+    }
+    // binom(d + n - 1, n - 1)
+    let mut res: i64 = 1;
+    for j in 1..n {
+        res = res * (d + j) as i64 / j as i64;
+    }
+    res
+}
+
+/// removes generators divisible by another generator
+fn minimize(mut generators: Vec<Monomial<Rational>>) -> Vec<Monomial<Rational>> {
+    generators.sort_by_key(|mono| mono.deg());
+    let mut minimal: Vec<Monomial<Rational>> = vec![];
+    for gen in generators.into_iter() {
+        if !minimal.iter().any(|min| min.divides(&gen)) {
+            minimal.push(gen);
+        }
+    }
+    minimal
+}
+
+/// numerator of the Hilbert series of a monomial ideal
+/// N(<m_1,...,m_k>) = N(<m_1,...,m_(k-1)>) - t^deg(m_k) N(<m_1,...,m_(k-1)> : m_k)
+fn monomial_numerator(generators: Vec<Monomial<Rational>>) -> Vec<i64> {
+    let mut generators = generators;
+    let last = match generators.pop() {
+        None => return vec![1],
+        Some(last) => last,
+    };
+    let without = monomial_numerator(generators.clone());
+    let last = last.powers();
+    let colon = generators
+        .iter()
+        .map(|gen| {
+            let powers = gen
+                .exponents()
+                .iter()
+                .zip(last.iter())
+                .map(|(g, l)| g.saturating_sub(*l))
+                .collect();
+            Monomial::new(Rational::from(1), powers)
+        })
+        .collect();
+    let colon_numerator = monomial_numerator(minimize(colon));
+    let shift: usize = last.iter().sum();
+
+    let mut res = without;
+    res.resize(res.len().max(colon_numerator.len() + shift), 0);
+    for (i, c) in colon_numerator.into_iter().enumerate() {
+        res[i + shift] -= c;
+    }
+    while res.len() > 1 && res.last() == Some(&0) {
+        res.pop();
+    }
+    res
+}
+
+impl fmt::Display for HilbertSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self
+            .numerator
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c != 0)
+            .map(|(i, c)| format!("{}t^{}", c, i))
+            .collect();
+        write!(f, "({})/(1-t)^{}", terms.join(" + "), self.num_variables)
+    }
+}
+
+impl fmt::Display for HilbertPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self
+            .coefficients()
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| **c != Rational::from(0))
+            .map(|(i, c)| format!("{}s^{}", c, i))
+            .collect();
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join(" + "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod hilbert_tests {
+    use super::HilbertSeries;
+    use crate::rational::Rational;
+
+    #[test]
+    fn numerator_principal() {
+        let series = HilbertSeries::from_monomial_ideal(&[vec![2, 0]], 2);
+        assert_eq!(series.numerator(), vec![1, 0, -1]);
+        assert_eq!(series.reduced(), Some((vec![1, 1], 1)))
+    }
+
+    #[test]
+    fn numerator_two_generators() {
+        // <xy, xz> in three variables: 1 - 2t^2 + t^3
+        let series = HilbertSeries::from_monomial_ideal(&[vec![1, 1, 0], vec![1, 0, 1]], 3);
+        assert_eq!(series.numerator(), vec![1, 0, -2, 1])
+    }
+
+    #[test]
+    fn hilbert_function_matches_polynomial() {
+        let series = HilbertSeries::from_monomial_ideal(&[vec![1, 1, 0], vec![1, 0, 1]], 3);
+        let poly = series.hilbert_polynomial();
+        for d in 2..6 {
+            assert_eq!(
+                Rational::from(series.hilbert_function(d)),
+                poly.eval(d as i64)
+            )
+        }
+    }
+
+    #[test]
+    fn zero_ring() {
+        let series = HilbertSeries::from_monomial_ideal(&[vec![0, 0]], 2);
+        assert_eq!(series.krull_dimension(), None);
+        assert!(series.hilbert_polynomial().is_zero())
+    }
+}
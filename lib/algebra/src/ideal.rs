@@ -1,5 +1,451 @@
-use super::{group::AbelianSubGroup, ring::Ring};
+use super::{
+    arithmetic::{gcd, gcd_all, lcm},
+    domain::EuclideanDomain,
+    field::Field,
+    groebner::{divide, GroebnerBasis},
+    hilbert::HilbertSeries,
+    polynomials::{Monomial, MonomialOrder, Polynomial},
+    ring::Ring,
+};
+use std::fmt;
 
-pub struct Ideal<R: Ring> {
-    pub subgroup: AbelianSubGroup<R>,
+/// Ideals of a ring given by finitely many generators
+/// with membership decided by an algorithm for the ring at hand
+pub trait Ideal<R: Ring> {
+    fn generators(&self) -> Vec<R>;
+    fn contains(&self, elem: &R) -> bool;
+}
+
+/// Ideal <g> of a Euclidean domain
+/// every ideal is principal, generated by the gcd of its generators
+/// the generator is kept in normal form, so derived equality compares ideals
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrincipalIdeal<R: EuclideanDomain> {
+    generator: R,
+}
+
+impl<R: EuclideanDomain> PrincipalIdeal<R> {
+    pub fn generated_by(generators: &[R]) -> PrincipalIdeal<R> {
+        PrincipalIdeal {
+            generator: gcd_all(generators),
+        }
+    }
+
+    pub fn generator(&self) -> &R {
+        &self.generator
+    }
+
+    /// elements are multiples of the generator
+    pub fn contains(&self, elem: &R) -> bool {
+        match elem.div_rem(&self.generator) {
+            Ok((_, rem)) => rem == R::zero(),
+            Err(_) => *elem == R::zero(),
+        }
+    }
+
+    /// <a> + <b> = <gcd(a, b)>
+    pub fn sum(&self, other: &PrincipalIdeal<R>) -> PrincipalIdeal<R> {
+        PrincipalIdeal {
+            generator: gcd(&self.generator, &other.generator),
+        }
+    }
+
+    /// <a> <b> = <ab>
+    pub fn product(&self, other: &PrincipalIdeal<R>) -> PrincipalIdeal<R> {
+        PrincipalIdeal {
+            generator: (self.generator.clone() * other.generator.clone()).normalize(),
+        }
+    }
+
+    /// <a> and <b> intersect in <lcm(a, b)>
+    pub fn intersection(&self, other: &PrincipalIdeal<R>) -> PrincipalIdeal<R> {
+        PrincipalIdeal {
+            generator: lcm(&self.generator, &other.generator),
+        }
+    }
+}
+
+impl<R: EuclideanDomain> Ideal<R> for PrincipalIdeal<R> {
+    fn generators(&self) -> Vec<R> {
+        vec![self.generator.clone()]
+    }
+
+    fn contains(&self, elem: &R) -> bool {
+        PrincipalIdeal::contains(self, elem)
+    }
+}
+
+impl<R> fmt::Display for PrincipalIdeal<R>
+where
+    R: EuclideanDomain + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", self.generator)
+    }
+}
+
+/// Finitely generated ideal of K[X_0,...,X_(dim-1)]
+/// keeps the given generators together with a GRevLex Groebner basis
+#[derive(Clone, Debug)]
+pub struct PolynomialIdeal<K: Field> {
+    dim: usize,
+    generators: Vec<Polynomial<K>>,
+    basis: GroebnerBasis<K>,
+}
+
+impl<K> PolynomialIdeal<K>
+where
+    K: Field + Clone,
+{
+    pub fn new(generators: Vec<Polynomial<K>>) -> PolynomialIdeal<K> {
+        let dim = generators.iter().map(|gen| gen.dim()).max().unwrap_or(0);
+        PolynomialIdeal::with_dim(generators, dim)
+    }
+
+    /// Ideal in at least dim variables
+    pub fn with_dim(generators: Vec<Polynomial<K>>, dim: usize) -> PolynomialIdeal<K> {
+        let generators: Vec<Polynomial<K>> = generators
+            .into_iter()
+            .filter(|gen| !gen.is_zero())
+            .map(|gen| gen.with_dim(dim))
+            .collect();
+        let dim = generators.iter().map(|gen| gen.dim()).max().unwrap_or(dim);
+        PolynomialIdeal {
+            dim,
+            basis: GroebnerBasis::new(generators.clone(), MonomialOrder::GRevLex),
+            generators,
+        }
+    }
+
+    /// the ideal <0>
+    pub fn zero(dim: usize) -> PolynomialIdeal<K> {
+        PolynomialIdeal::with_dim(vec![], dim)
+    }
+
+    /// the ideal <1>
+    pub fn unit(dim: usize) -> PolynomialIdeal<K> {
+        PolynomialIdeal::with_dim(vec![Polynomial::one()], dim)
+    }
+
+    /// the ideal <X_0,...,X_(dim-1)>
+    /// in projective space this is the irrelevant ideal
+    pub fn maximal_at_origin(dim: usize) -> PolynomialIdeal<K> {
+        PolynomialIdeal::with_dim(
+            (0..dim).map(|i| Polynomial::variable(i, dim)).collect(),
+            dim,
+        )
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn generators(&self) -> Vec<Polynomial<K>> {
+        self.generators.clone()
+    }
+
+    pub fn groebner_basis(&self) -> &GroebnerBasis<K> {
+        &self.basis
+    }
+
+    pub fn contains(&self, f: &Polynomial<K>) -> bool {
+        self.basis.contains(f)
+    }
+
+    pub fn contains_ideal(&self, other: &PolynomialIdeal<K>) -> bool {
+        self.basis.contains_ideal(&other.basis)
+    }
+
+    pub fn is_unit(&self) -> bool {
+        self.basis.is_unit_ideal()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.generators.is_empty()
+    }
+
+    /// I + J
+    pub fn sum(&self, other: &PolynomialIdeal<K>) -> PolynomialIdeal<K> {
+        let mut generators = self.generators();
+        generators.extend(other.generators());
+        PolynomialIdeal::with_dim(generators, self.dim.max(other.dim))
+    }
+
+    /// IJ generated by all products of generators
+    pub fn product(&self, other: &PolynomialIdeal<K>) -> PolynomialIdeal<K> {
+        let mut generators = vec![];
+        for f in self.generators.iter() {
+            for g in other.generators.iter() {
+                generators.push(f.clone() * g.clone());
+            }
+        }
+        PolynomialIdeal::with_dim(generators, self.dim.max(other.dim))
+    }
+
+    /// I ∩ K[X_n,...], with X_n,... renamed to X_0,...
+    /// computed with a Groebner basis for an elimination order
+    pub fn eliminate(&self, n: usize) -> PolynomialIdeal<K> {
+        let basis = GroebnerBasis::new(self.generators(), MonomialOrder::Elimination(n));
+        let generators = basis
+            .basis()
+            .iter()
+            .filter_map(|g| g.clone().unshift_variables(n))
+            .collect();
+        PolynomialIdeal::with_dim(generators, self.dim.saturating_sub(n))
+    }
+
+    /// I ∩ J as the elimination of t from tI + (1-t)J
+    pub fn intersection(&self, other: &PolynomialIdeal<K>) -> PolynomialIdeal<K> {
+        let dim = self.dim.max(other.dim);
+        let t = Polynomial::variable(0, dim + 1);
+        let one_minus_t = Polynomial::one() - t.clone();
+        let mut generators: Vec<Polynomial<K>> = self
+            .generators
+            .iter()
+            .map(|f| t.clone() * f.clone().shift_variables(1))
+            .collect();
+        generators.extend(
+            other
+                .generators
+                .iter()
+                .map(|g| one_minus_t.clone() * g.clone().shift_variables(1)),
+        );
+        let res = PolynomialIdeal::with_dim(generators, dim + 1).eliminate(1);
+        PolynomialIdeal::with_dim(res.generators, dim)
+    }
+
+    /// I : <f> = (I ∩ <f>)/f
+    pub fn quotient_element(&self, f: &Polynomial<K>) -> PolynomialIdeal<K> {
+        if f.is_zero() {
+            return PolynomialIdeal::unit(self.dim);
+        }
+        let order = MonomialOrder::GRevLex;
+        let principal = PolynomialIdeal::with_dim(vec![f.clone()], self.dim);
+        let generators = self
+            .intersection(&principal)
+            .generators
+            .iter()
+            .map(|g| divide(g, std::slice::from_ref(f), order).0.remove(0))
+            .collect();
+        PolynomialIdeal::with_dim(generators, self.dim.max(f.dim()))
+    }
+
+    /// I : J = {f | fJ ⊆ I}
+    /// intersection of I : <g> for all generators g of J
+    pub fn quotient(&self, other: &PolynomialIdeal<K>) -> PolynomialIdeal<K> {
+        other
+            .generators
+            .iter()
+            .fold(PolynomialIdeal::unit(self.dim.max(other.dim)), |res, g| {
+                res.intersection(&self.quotient_element(g))
+            })
+    }
+
+    /// I : f^∞ = {g | f^n g ∈ I for some n}
+    /// elimination of t from I + <1 - tf>
+    pub fn saturation_element(&self, f: &Polynomial<K>) -> PolynomialIdeal<K> {
+        let dim = self.dim.max(f.dim());
+        let mut generators: Vec<Polynomial<K>> = self
+            .generators
+            .iter()
+            .map(|g| g.clone().shift_variables(1))
+            .collect();
+        generators.push(rabinowitsch(f, dim));
+        let res = PolynomialIdeal::with_dim(generators, dim + 1).eliminate(1);
+        PolynomialIdeal::with_dim(res.generators, dim)
+    }
+
+    /// I : J^∞ = {f | fJ^n ⊆ I for some n}
+    /// intersection of I : g^∞ for all generators g of J
+    pub fn saturation(&self, other: &PolynomialIdeal<K>) -> PolynomialIdeal<K> {
+        other
+            .generators
+            .iter()
+            .fold(PolynomialIdeal::unit(self.dim.max(other.dim)), |res, g| {
+                res.intersection(&self.saturation_element(g))
+            })
+    }
+
+    /// Hilbert series of the quotient by the ideal
+    pub fn hilbert_series(&self) -> HilbertSeries {
+        HilbertSeries::new(self)
+    }
+
+    /// f^n ∈ I for some n
+    /// checks if 1 ∈ I + <1 - tf>
+    pub fn radical_contains(&self, f: &Polynomial<K>) -> bool {
+        let dim = self.dim.max(f.dim());
+        let mut generators: Vec<Polynomial<K>> = self
+            .generators
+            .iter()
+            .map(|g| g.clone().shift_variables(1))
+            .collect();
+        generators.push(rabinowitsch(f, dim));
+        PolynomialIdeal::with_dim(generators, dim + 1).is_unit()
+    }
+}
+
+/// 1 - t f with t = X_0 and f shifted by one variable
+fn rabinowitsch<K>(f: &Polynomial<K>, dim: usize) -> Polynomial<K>
+where
+    K: Field + Clone,
+{
+    let t = Monomial::new(<K as Ring>::one(), vec![1]);
+    Polynomial::one().with_dim(dim + 1) - f.clone().shift_variables(1).mul_monomial(&t)
+}
+
+/// Equality of ideals
+impl<K> PartialEq for PolynomialIdeal<K>
+where
+    K: Field + Clone,
+{
+    fn eq(&self, other: &PolynomialIdeal<K>) -> bool {
+        self.basis == other.basis
+    }
+}
+
+/// membership is decided with a Groebner basis
+impl<K> Ideal<Polynomial<K>> for PolynomialIdeal<K>
+where
+    K: Field + Clone,
+{
+    fn generators(&self) -> Vec<Polynomial<K>> {
+        PolynomialIdeal::generators(self)
+    }
+
+    fn contains(&self, f: &Polynomial<K>) -> bool {
+        PolynomialIdeal::contains(self, f)
+    }
+}
+
+impl<K> fmt::Display for PolynomialIdeal<K>
+where
+    K: Field,
+    K: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gen_str: Vec<String> = self
+            .generators
+            .iter()
+            .map(|gen| format!("{}", gen))
+            .collect();
+        write!(f, "<{}>", gen_str.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod ideal_tests {
+    use super::{Ideal, PolynomialIdeal, PrincipalIdeal};
+    use crate::{
+        gaussian_integer::GaussianInteger,
+        polynomials::Polynomial,
+        rational::Rational,
+        test_helpers::{ideal, poly},
+    };
+
+    #[test]
+    fn sum() {
+        let result = ideal(vec![vec![(1, vec![1, 0])]]).sum(&ideal(vec![vec![(1, vec![0, 1])]]));
+        let expected = PolynomialIdeal::maximal_at_origin(2);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn product() {
+        let i = ideal(vec![vec![(1, vec![1, 0])], vec![(1, vec![0, 1])]]);
+        let result = i.product(&i);
+        let expected = ideal(vec![
+            vec![(1, vec![2, 0])],
+            vec![(1, vec![1, 1])],
+            vec![(1, vec![0, 2])],
+        ]);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn intersection_coordinate_axes() {
+        let result =
+            ideal(vec![vec![(1, vec![1, 0])]]).intersection(&ideal(vec![vec![(1, vec![0, 1])]]));
+        let expected = ideal(vec![vec![(1, vec![1, 1])]]);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn intersection_not_product() {
+        // <x^2, xy> ∩ <x> = <x^2, xy>
+        let i = ideal(vec![vec![(1, vec![2, 0])], vec![(1, vec![1, 1])]]);
+        let result = i.intersection(&ideal(vec![vec![(1, vec![1, 0])]]));
+        assert_eq!(result, i)
+    }
+
+    #[test]
+    fn quotient() {
+        // <xy> : <x> = <y>
+        let i = ideal(vec![vec![(1, vec![1, 1])]]);
+        let result = i.quotient(&ideal(vec![vec![(1, vec![1, 0])]]));
+        let expected = ideal(vec![vec![(1, vec![0, 1])]]);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn saturation() {
+        // <x^2 y, x y^2> : <x>^∞ = <y>
+        let i = ideal(vec![vec![(1, vec![2, 1])], vec![(1, vec![1, 2])]]);
+        let result = i.saturation(&ideal(vec![vec![(1, vec![1, 0])]]));
+        let expected = ideal(vec![vec![(1, vec![0, 1])]]);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn saturation_irrelevant() {
+        // <x^2, xy> defines the point [0:1] with an embedded point at the origin
+        let i = ideal(vec![vec![(1, vec![2, 0])], vec![(1, vec![1, 1])]]);
+        let result = i.saturation(&PolynomialIdeal::maximal_at_origin(2));
+        let expected = ideal(vec![vec![(1, vec![1, 0])]]);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn radical_membership() {
+        let i = ideal(vec![
+            vec![(1, vec![2, 0]), (-1, vec![0, 1])],
+            vec![(1, vec![0, 2])],
+        ]);
+        assert!(i.radical_contains(&poly(vec![(1, vec![1, 0])])));
+        assert!(!i.contains(&poly(vec![(1, vec![1, 0])])));
+        assert!(!i.radical_contains(&poly(vec![(1, vec![1, 0]), (1, vec![0, 0])])))
+    }
+
+    #[test]
+    fn principal_ideals_of_integers() {
+        let ideal = PrincipalIdeal::generated_by(&[12i64, -18]);
+        assert_eq!(*ideal.generator(), 6);
+        assert!(ideal.contains(&-30));
+        assert!(!ideal.contains(&4));
+        let other = PrincipalIdeal::generated_by(&[4i64]);
+        assert_eq!(
+            ideal.intersection(&other),
+            PrincipalIdeal::generated_by(&[12])
+        );
+        assert_eq!(ideal.sum(&other), PrincipalIdeal::generated_by(&[2]));
+        assert_eq!(ideal.product(&other).generators(), vec![24])
+    }
+
+    #[test]
+    fn gaussian_ideal() {
+        // 2 = -i (1 + i)^2, so <2, 1 + i> = <1 + i>
+        let ideal =
+            PrincipalIdeal::generated_by(&[GaussianInteger::from(2), GaussianInteger::new(1, 1)]);
+        assert_eq!(*ideal.generator(), GaussianInteger::new(1, 1));
+        assert!(ideal.contains(&GaussianInteger::new(3, 1)));
+        assert!(!ideal.contains(&GaussianInteger::new(1, 0)))
+    }
+
+    #[test]
+    fn polynomial_ideal_as_ideal() {
+        let ideal: &dyn Ideal<Polynomial<Rational>> =
+            &PolynomialIdeal::new(vec![poly(vec![(1, vec![1, 0]), (-1, vec![0, 1])])]);
+        assert!(ideal.contains(&poly(vec![(1, vec![2, 0]), (-1, vec![0, 2])])));
+        assert_eq!(ideal.generators().len(), 1)
+    }
 }
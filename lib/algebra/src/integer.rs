@@ -0,0 +1,70 @@
+use super::{
+    domain::{EuclideanDomain, IntegralDomain, UniqueFactorizationDomain},
+    errors::Error,
+    group::AbelianGroup,
+    ring::{CommutativeRing, Ring},
+};
+
+/// i64 and i128 as the integers
+/// overflow is not checked, as for the primitive operations
+macro_rules! integer_impls {
+    ($($int:ty),*) => {$(
+        impl AbelianGroup for $int {
+            fn zero() -> $int {
+                0
+            }
+        }
+
+        impl Ring for $int {
+            fn one() -> $int {
+                1
+            }
+        }
+
+        impl CommutativeRing for $int {}
+
+        impl IntegralDomain for $int {}
+
+        impl UniqueFactorizationDomain for $int {}
+
+        /// the remainder is non-negative and smaller than |divisor|
+        /// normal forms are non-negative
+        impl EuclideanDomain for $int {
+            fn div_rem(&self, divisor: &$int) -> Result<($int, $int), Error> {
+                if *divisor == 0 {
+                    return Err(Error::DivisionByZero);
+                }
+                Ok((self.div_euclid(*divisor), self.rem_euclid(*divisor)))
+            }
+
+            fn normal_unit(&self) -> $int {
+                if *self < 0 {
+                    -1
+                } else {
+                    1
+                }
+            }
+        }
+    )*};
+}
+
+integer_impls!(i64, i128);
+
+#[cfg(test)]
+mod integer_tests {
+    use crate::domain::EuclideanDomain;
+
+    #[test]
+    fn euclidean_division() {
+        assert_eq!(17i64.div_rem(&5).unwrap(), (3, 2));
+        assert_eq!((-17i64).div_rem(&5).unwrap(), (-4, 3));
+        assert_eq!(17i64.div_rem(&-5).unwrap(), (-3, 2));
+        assert!(1i64.div_rem(&0).is_err())
+    }
+
+    #[test]
+    fn normal_forms() {
+        assert_eq!((-4i128).normalize(), 4);
+        assert_eq!(0i64.normalize(), 0)
+    }
+}
@@ -1,10 +1,32 @@
+pub mod action;
+pub mod affine;
 pub mod arithmetic;
+pub mod big_integer;
 pub mod complex;
+pub mod decomposition;
+pub mod domain;
+pub mod elimination;
 pub mod errors;
 pub mod field;
+pub mod finite_group;
+pub mod galois_field;
+pub mod gaussian_integer;
+pub mod graded_module;
 pub mod graded_ring;
+pub mod groebner;
 pub mod group;
+pub mod hilbert;
 pub mod ideal;
+pub mod integer;
+pub mod matrix;
+pub mod numerical;
 pub mod polynomials;
+pub mod prime_field;
 pub mod projective;
+pub mod quotient_ring;
+pub mod rational;
 pub mod ring;
+pub mod toric;
+
+#[cfg(test)]
+mod test_helpers;
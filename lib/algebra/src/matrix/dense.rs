@@ -0,0 +1,395 @@
+use crate::{errors::Error, field::Field, projective::ProjectivePoint};
+use std::{fmt, ops::Mul};
+
+/// Dense matrices over a field
+/// stored as a list of rows
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<K: Field> {
+    num_rows: usize,
+    num_cols: usize,
+    rows: Vec<Vec<K>>,
+}
+
+impl<K: Field + Clone> Matrix<K> {
+    /// all rows need to have the same length
+    pub fn new(rows: Vec<Vec<K>>) -> Result<Matrix<K>, Error> {
+        let num_cols = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some(row) = rows.iter().find(|row| row.len() != num_cols) {
+            return Err(Error::DimensionMismatch {
+                found: row.len(),
+                expected: num_cols,
+            });
+        }
+        Ok(Matrix {
+            num_rows: rows.len(),
+            num_cols,
+            rows,
+        })
+    }
+
+    pub fn zero(num_rows: usize, num_cols: usize) -> Matrix<K> {
+        Matrix {
+            num_rows,
+            num_cols,
+            rows: vec![vec![K::zero(); num_cols]; num_rows],
+        }
+    }
+
+    pub fn identity(n: usize) -> Matrix<K> {
+        let mut identity = Matrix::zero(n, n);
+        for i in 0..n {
+            identity.rows[i][i] = <K as Field>::one();
+        }
+        identity
+    }
+
+    /// matrix with the given vectors as columns
+    pub fn from_columns(columns: Vec<Vec<K>>) -> Result<Matrix<K>, Error> {
+        Ok(Matrix::new(columns)?.transpose())
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    pub fn entry(&self, i: usize, j: usize) -> &K {
+        &self.rows[i][j]
+    }
+
+    pub fn set_entry(&mut self, i: usize, j: usize, value: K) {
+        self.rows[i][j] = value;
+    }
+
+    pub fn row(&self, i: usize) -> &[K] {
+        &self.rows[i]
+    }
+
+    pub fn rows(&self) -> &[Vec<K>] {
+        &self.rows
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<K>> {
+        self.rows
+    }
+
+    pub fn column(&self, j: usize) -> Vec<K> {
+        self.rows.iter().map(|row| row[j].clone()).collect()
+    }
+
+    pub fn transpose(&self) -> Matrix<K> {
+        Matrix {
+            num_rows: self.num_cols,
+            num_cols: self.num_rows,
+            rows: (0..self.num_cols).map(|j| self.column(j)).collect(),
+        }
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.num_rows == self.num_cols
+    }
+
+    /// Gaussian elimination to row echelon form
+    /// returns the pivot columns and the sign of the row permutation
+    /// pivots are not normalized and entries above pivots are kept
+    pub fn gaussian_elimination(&mut self) -> (Vec<usize>, bool) {
+        let mut pivots = vec![];
+        let mut even = true;
+        let mut row = 0;
+        for col in 0..self.num_cols {
+            if row == self.num_rows {
+                break;
+            }
+            let Some(pivot) = (row..self.num_rows).find(|i| self.rows[*i][col] != K::zero()) else {
+                continue;
+            };
+            if pivot != row {
+                self.rows.swap(row, pivot);
+                even = !even;
+            }
+            let pivot_inverse = self.rows[row][col].clone().inverse();
+            for i in row + 1..self.num_rows {
+                if self.rows[i][col] == K::zero() {
+                    continue;
+                }
+                let factor = self.rows[i][col].clone() * pivot_inverse.clone();
+                self.sub_row_multiple(i, row, factor);
+            }
+            pivots.push(col);
+            row += 1;
+        }
+        (pivots, even)
+    }
+
+    /// row echelon form
+    pub fn row_echelon_form(&self) -> Matrix<K> {
+        let mut echelon = self.clone();
+        echelon.gaussian_elimination();
+        echelon
+    }
+
+    /// reduced row echelon form together with the pivot columns
+    /// pivots are one and the only nonzero entries of their columns
+    pub fn rref_with_pivots(&self) -> (Matrix<K>, Vec<usize>) {
+        let mut reduced = self.clone();
+        let (pivots, _) = reduced.gaussian_elimination();
+        for (row, col) in pivots.iter().enumerate().rev() {
+            let pivot_inverse = reduced.rows[row][*col].clone().inverse();
+            reduced.rows[row] = reduced.rows[row]
+                .iter()
+                .map(|elem| elem.clone() * pivot_inverse.clone())
+                .collect();
+            for i in 0..row {
+                if reduced.rows[i][*col] != K::zero() {
+                    let factor = reduced.rows[i][*col].clone();
+                    reduced.sub_row_multiple(i, row, factor);
+                }
+            }
+        }
+        (reduced, pivots)
+    }
+
+    /// reduced row echelon form
+    pub fn rref(&self) -> Matrix<K> {
+        self.rref_with_pivots().0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().gaussian_elimination().0.len()
+    }
+
+    /// basis of the kernel {v | Av = 0}
+    /// one vector for each non-pivot column of the reduced row echelon form
+    pub fn kernel(&self) -> Vec<Vec<K>> {
+        let (reduced, pivots) = self.rref_with_pivots();
+        (0..self.num_cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vector = vec![K::zero(); self.num_cols];
+                vector[free] = <K as Field>::one();
+                for (row, col) in pivots.iter().enumerate() {
+                    vector[*col] = -reduced.rows[row][free].clone();
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// basis of the image {Av}
+    /// the columns of A in pivot positions
+    pub fn image(&self) -> Vec<Vec<K>> {
+        let (pivots, _) = self.clone().gaussian_elimination();
+        pivots.into_iter().map(|col| self.column(col)).collect()
+    }
+
+    pub fn determinant(&self) -> Result<K, Error> {
+        if !self.is_square() {
+            return Err(Error::DimensionMismatch {
+                found: self.num_cols,
+                expected: self.num_rows,
+            });
+        }
+        let mut echelon = self.clone();
+        let (pivots, even) = echelon.gaussian_elimination();
+        if pivots.len() < self.num_rows {
+            return Ok(K::zero());
+        }
+        let det = (0..self.num_rows).fold(<K as Field>::one(), |det, i| {
+            det * echelon.rows[i][i].clone()
+        });
+        Ok(if even { det } else { -det })
+    }
+
+    /// inverse by Gauss-Jordan elimination of [A | I]
+    pub fn inverse(&self) -> Result<Matrix<K>, Error> {
+        if !self.is_square() {
+            return Err(Error::DimensionMismatch {
+                found: self.num_cols,
+                expected: self.num_rows,
+            });
+        }
+        let n = self.num_rows;
+        let augmented = Matrix {
+            num_rows: n,
+            num_cols: 2 * n,
+            rows: self
+                .rows
+                .iter()
+                .zip(Matrix::<K>::identity(n).rows)
+                .map(|(row, identity_row)| row.iter().cloned().chain(identity_row).collect())
+                .collect(),
+        };
+        let (reduced, pivots) = augmented.rref_with_pivots();
+        if pivots != (0..n).collect::<Vec<usize>>() {
+            return Err(Error::NotInvertible);
+        }
+        Ok(Matrix {
+            num_rows: n,
+            num_cols: n,
+            rows: reduced
+                .rows
+                .into_iter()
+                .map(|row| row.into_iter().skip(n).collect())
+                .collect(),
+        })
+    }
+
+    /// the product Av
+    pub fn mul_vec(&self, vector: &[K]) -> Result<Vec<K>, Error> {
+        if vector.len() != self.num_cols {
+            return Err(Error::DimensionMismatch {
+                found: vector.len(),
+                expected: self.num_cols,
+            });
+        }
+        Ok(self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(vector.iter())
+                    .fold(K::zero(), |sum, (a, b)| sum + a.clone() * b.clone())
+            })
+            .collect())
+    }
+
+    /// applies the matrix to the homogeneous coordinates of a point
+    /// fails if the point lies in the projectivized kernel
+    pub fn apply(&self, pt: &ProjectivePoint<K>) -> Result<ProjectivePoint<K>, Error> {
+        ProjectivePoint::new(self.mul_vec(&pt.clone().as_arr())?)
+    }
+
+    /// row_i = row_i - factor * row_j
+    fn sub_row_multiple(&mut self, i: usize, j: usize, factor: K) {
+        let new_row = self.rows[i]
+            .iter()
+            .zip(self.rows[j].iter())
+            .map(|(a, b)| a.clone() + -(factor.clone() * b.clone()))
+            .collect();
+        self.rows[i] = new_row;
+    }
+}
+
+/// matrix product, panics if the dimensions do not match
+impl<K: Field + Clone> Mul for Matrix<K> {
+    type Output = Matrix<K>;
+    fn mul(self, other: Matrix<K>) -> Matrix<K> {
+        if self.num_cols != other.num_rows {
+            panic!(
+                "{}",
+                Error::DimensionMismatch {
+                    found: other.num_rows,
+                    expected: self.num_cols,
+                }
+            )
+        }
+        let columns: Vec<Vec<K>> = (0..other.num_cols)
+            .map(|j| self.mul_vec(&other.column(j)).unwrap())
+            .collect();
+        Matrix {
+            num_rows: self.num_rows,
+            num_cols: other.num_cols,
+            rows: (0..self.num_rows)
+                .map(|i| columns.iter().map(|col| col[i].clone()).collect())
+                .collect(),
+        }
+    }
+}
+
+impl<K> fmt::Display for Matrix<K>
+where
+    K: Field + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let row_strs: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let entries: Vec<String> = row.iter().map(|elem| format!("{}", elem)).collect();
+                format!("[{}]", entries.join(" "))
+            })
+            .collect();
+        write!(f, "{}", row_strs.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod dense_tests {
+    use super::Matrix;
+    use crate::{prime_field::PrimeField, projective::ProjectivePoint, rational::Rational};
+
+    fn matrix(rows: Vec<Vec<i64>>) -> Matrix<Rational> {
+        Matrix::new(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(Rational::from).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn vector(entries: Vec<i64>) -> Vec<Rational> {
+        entries.into_iter().map(Rational::from).collect()
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert!(Matrix::new(vec![vector(vec![1, 2]), vector(vec![1])]).is_err())
+    }
+
+    #[test]
+    fn rref_rank() {
+        let m = matrix(vec![vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+        let expected = matrix(vec![vec![1, 0, 1], vec![0, 1, 1], vec![0, 0, 0]]);
+        assert_eq!(m.rref(), expected);
+        assert_eq!(m.rank(), 2)
+    }
+
+    #[test]
+    fn kernel_and_image() {
+        let m = matrix(vec![vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+        let kernel = m.kernel();
+        assert_eq!(kernel, vec![vector(vec![-1, -1, 1])]);
+        assert_eq!(m.mul_vec(&kernel[0]).unwrap(), vector(vec![0, 0, 0]));
+        assert_eq!(
+            m.image(),
+            vec![vector(vec![1, 2, 1]), vector(vec![2, 4, 0])]
+        )
+    }
+
+    #[test]
+    fn determinant_with_swap() {
+        let m = matrix(vec![vec![0, 1, 2], vec![1, 0, 3], vec![4, -3, 8]]);
+        assert_eq!(m.determinant().unwrap(), Rational::from(-2));
+        assert!(matrix(vec![vec![1, 2]]).determinant().is_err())
+    }
+
+    #[test]
+    fn inverse_product() {
+        let m = matrix(vec![vec![2, 1], vec![5, 3]]);
+        let inverse = m.inverse().unwrap();
+        assert_eq!(inverse, matrix(vec![vec![3, -1], vec![-5, 2]]));
+        assert_eq!(m * inverse, Matrix::identity(2))
+    }
+
+    #[test]
+    fn singular_inverse() {
+        let m: Matrix<PrimeField<7>> =
+            Matrix::new(vec![vec![1.into(), 3.into()], vec![2.into(), 6.into()]]).unwrap();
+        assert!(m.inverse().is_err());
+        assert_eq!(m.determinant().unwrap(), 0.into())
+    }
+
+    #[test]
+    fn apply_to_point() {
+        let m = matrix(vec![vec![1, 1], vec![0, 1]]);
+        let pt = ProjectivePoint::new(vector(vec![1, 2])).unwrap();
+        let expected = ProjectivePoint::new(vector(vec![3, 2])).unwrap();
+        assert_eq!(m.apply(&pt).unwrap(), expected);
+        let projection = matrix(vec![vec![1, 0], vec![0, 0]]);
+        let kernel_pt = ProjectivePoint::new(vector(vec![0, 1])).unwrap();
+        assert!(projection.apply(&kernel_pt).is_err())
+    }
+}
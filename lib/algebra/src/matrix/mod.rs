@@ -0,0 +1,5 @@
+pub mod dense;
+pub mod sparse;
+
+pub use dense::Matrix;
+pub use sparse::SparseMatrix;
@@ -0,0 +1,343 @@
+use super::Matrix;
+use crate::{errors::Error, field::Field, projective::ProjectivePoint};
+
+/// Sparse matrix row
+/// pairs of column index and nonzero entry, sorted by column
+pub type SparseRow<K> = Vec<(usize, K)>;
+
+/// Sparse matrices over a field
+/// only nonzero entries are stored, row by row
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix<K: Field> {
+    num_cols: usize,
+    rows: Vec<SparseRow<K>>,
+}
+
+impl<K: Field + Clone> SparseMatrix<K> {
+    /// entries of a row may be given in any order
+    /// zero entries are dropped and entries in the same position are added
+    pub fn new(num_cols: usize, rows: Vec<SparseRow<K>>) -> Result<SparseMatrix<K>, Error> {
+        let mut normalized = vec![];
+        for mut row in rows.into_iter() {
+            if let Some((col, _)) = row.iter().find(|(col, _)| *col >= num_cols) {
+                return Err(Error::DimensionMismatch {
+                    found: *col,
+                    expected: num_cols,
+                });
+            }
+            row.sort_by_key(|(col, _)| *col);
+            let mut combined: SparseRow<K> = vec![];
+            for (col, entry) in row.into_iter() {
+                match combined.last_mut() {
+                    Some((last_col, last)) if *last_col == col => {
+                        *last = last.clone() + entry;
+                    }
+                    _ => combined.push((col, entry)),
+                }
+            }
+            combined.retain(|(_, entry)| *entry != K::zero());
+            normalized.push(combined);
+        }
+        Ok(SparseMatrix {
+            num_cols,
+            rows: normalized,
+        })
+    }
+
+    pub fn from_dense(matrix: &Matrix<K>) -> SparseMatrix<K> {
+        SparseMatrix {
+            num_cols: matrix.num_cols(),
+            rows: matrix
+                .rows()
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|(_, entry)| **entry != K::zero())
+                        .map(|(col, entry)| (col, entry.clone()))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_dense(&self) -> Matrix<K> {
+        let mut dense = Matrix::zero(self.num_rows(), self.num_cols);
+        for (i, row) in self.rows.iter().enumerate() {
+            for (j, entry) in row.iter() {
+                dense.set_entry(i, *j, entry.clone());
+            }
+        }
+        dense
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// number of nonzero entries
+    pub fn num_nonzero(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).sum()
+    }
+
+    pub fn entry(&self, i: usize, j: usize) -> K {
+        self.rows[i]
+            .binary_search_by_key(&j, |(col, _)| *col)
+            .map(|ind| self.rows[i][ind].1.clone())
+            .unwrap_or(K::zero())
+    }
+
+    pub fn rows(&self) -> &[SparseRow<K>] {
+        &self.rows
+    }
+
+    pub fn transpose(&self) -> SparseMatrix<K> {
+        let mut rows = vec![vec![]; self.num_cols];
+        for (i, row) in self.rows.iter().enumerate() {
+            for (j, entry) in row.iter() {
+                rows[*j].push((i, entry.clone()));
+            }
+        }
+        SparseMatrix {
+            num_cols: self.num_rows(),
+            rows,
+        }
+    }
+
+    /// reduced row echelon form together with the pivot columns
+    /// zero rows are removed
+    pub fn rref_with_pivots(&self) -> (SparseMatrix<K>, Vec<usize>) {
+        // pivot rows by leading column, normalized to leading entry one
+        let mut pivots: Vec<Option<SparseRow<K>>> = vec![None; self.num_cols];
+        for row in self.rows.iter() {
+            let mut row = row.clone();
+            while let Some((lead, coeff)) = row.first().cloned() {
+                match &pivots[lead] {
+                    Some(pivot) => row = sub_multiple(&row, coeff, pivot),
+                    None => break,
+                }
+            }
+            if let Some((lead, coeff)) = row.first().cloned() {
+                let inverse = coeff.inverse();
+                pivots[lead] = Some(
+                    row.into_iter()
+                        .map(|(col, entry)| (col, entry * inverse.clone()))
+                        .collect(),
+                );
+            }
+        }
+
+        // back substitution, starting with the last pivot
+        let pivot_cols: Vec<usize> = (0..self.num_cols)
+            .filter(|col| pivots[*col].is_some())
+            .collect();
+        for (ind, col) in pivot_cols.iter().enumerate().rev() {
+            let pivot = pivots[*col].clone().unwrap();
+            for other in pivot_cols[..ind].iter() {
+                let row = pivots[*other].as_ref().unwrap();
+                if let Ok(pos) = row.binary_search_by_key(col, |(c, _)| *c) {
+                    let coeff = row[pos].1.clone();
+                    pivots[*other] = Some(sub_multiple(row, coeff, &pivot));
+                }
+            }
+        }
+
+        let rows = pivots.into_iter().flatten().collect();
+        (
+            SparseMatrix {
+                num_cols: self.num_cols,
+                rows,
+            },
+            pivot_cols,
+        )
+    }
+
+    pub fn rref(&self) -> SparseMatrix<K> {
+        self.rref_with_pivots().0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref_with_pivots().1.len()
+    }
+
+    /// basis of the kernel, one vector for each non-pivot column
+    pub fn kernel(&self) -> Vec<Vec<K>> {
+        let (reduced, pivots) = self.rref_with_pivots();
+        (0..self.num_cols)
+            .filter(|col| pivots.binary_search(col).is_err())
+            .map(|free| {
+                let mut vector = vec![K::zero(); self.num_cols];
+                vector[free] = <K as Field>::one();
+                for (row, col) in reduced.rows.iter().zip(pivots.iter()) {
+                    if let Ok(pos) = row.binary_search_by_key(&free, |(c, _)| *c) {
+                        vector[*col] = -row[pos].1.clone();
+                    }
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// basis of the image, the columns in pivot positions
+    pub fn image(&self) -> Vec<Vec<K>> {
+        let transposed = self.transpose();
+        self.rref_with_pivots()
+            .1
+            .into_iter()
+            .map(|col| {
+                let mut column = vec![K::zero(); self.num_rows()];
+                for (i, entry) in transposed.rows[col].iter() {
+                    column[*i] = entry.clone();
+                }
+                column
+            })
+            .collect()
+    }
+
+    /// determinant, computed on the dense matrix
+    pub fn determinant(&self) -> Result<K, Error> {
+        self.to_dense().determinant()
+    }
+
+    /// inverse, computed on the dense matrix
+    pub fn inverse(&self) -> Result<SparseMatrix<K>, Error> {
+        Ok(SparseMatrix::from_dense(&self.to_dense().inverse()?))
+    }
+
+    /// the product Av
+    pub fn mul_vec(&self, vector: &[K]) -> Result<Vec<K>, Error> {
+        if vector.len() != self.num_cols {
+            return Err(Error::DimensionMismatch {
+                found: vector.len(),
+                expected: self.num_cols,
+            });
+        }
+        Ok(self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter().fold(K::zero(), |sum, (col, entry)| {
+                    sum + entry.clone() * vector[*col].clone()
+                })
+            })
+            .collect())
+    }
+
+    /// applies the matrix to the homogeneous coordinates of a point
+    pub fn apply(&self, pt: &ProjectivePoint<K>) -> Result<ProjectivePoint<K>, Error> {
+        ProjectivePoint::new(self.mul_vec(&pt.clone().as_arr())?)
+    }
+}
+
+/// row - c * pivot
+pub(crate) fn sub_multiple<K>(row: &SparseRow<K>, c: K, pivot: &SparseRow<K>) -> SparseRow<K>
+where
+    K: Field + Clone,
+{
+    let mut res = Vec::with_capacity(row.len() + pivot.len());
+    let (mut i, mut j) = (0, 0);
+    while i < row.len() || j < pivot.len() {
+        let row_col = row.get(i).map(|(col, _)| *col).unwrap_or(usize::MAX);
+        let pivot_col = pivot.get(j).map(|(col, _)| *col).unwrap_or(usize::MAX);
+        if row_col < pivot_col {
+            res.push(row[i].clone());
+            i += 1;
+        } else if pivot_col < row_col {
+            res.push((pivot_col, -(c.clone() * pivot[j].1.clone())));
+            j += 1;
+        } else {
+            let entry = row[i].1.clone() + -(c.clone() * pivot[j].1.clone());
+            if entry != K::zero() {
+                res.push((row_col, entry));
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod sparse_tests {
+    use super::super::Matrix;
+    use super::SparseMatrix;
+    use crate::{prime_field::PrimeField, rational::Rational};
+
+    type F = PrimeField<5>;
+
+    fn dense(rows: Vec<Vec<i64>>) -> Matrix<F> {
+        Matrix::new(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(F::from).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_combines_entries() {
+        let m = SparseMatrix::new(
+            3,
+            vec![vec![(2, F::from(1)), (0, F::from(2)), (2, F::from(4))]],
+        )
+        .unwrap();
+        assert_eq!(m.rows()[0], vec![(0, F::from(2))]);
+        assert!(SparseMatrix::new(2, vec![vec![(2, F::from(1))]]).is_err())
+    }
+
+    #[test]
+    fn rref_agrees_with_dense() {
+        let m = dense(vec![
+            vec![1, 2, 0, 3],
+            vec![2, 4, 1, 1],
+            vec![3, 1, 1, 4],
+            vec![1, 0, 0, 0],
+        ]);
+        let sparse = SparseMatrix::from_dense(&m);
+        let (reduced, pivots) = sparse.rref_with_pivots();
+        let (dense_reduced, dense_pivots) = m.rref_with_pivots();
+        assert_eq!(pivots, dense_pivots);
+        let nonzero_rows: Vec<Vec<F>> = dense_reduced.rows()[..pivots.len()].to_vec();
+        assert_eq!(reduced.to_dense(), Matrix::new(nonzero_rows).unwrap());
+        assert_eq!(sparse.rank(), m.rank())
+    }
+
+    #[test]
+    fn kernel_vectors() {
+        let m = SparseMatrix::from_dense(&dense(vec![vec![1, 1, 0, 0], vec![0, 0, 1, 1]]));
+        let kernel = m.kernel();
+        assert_eq!(kernel.len(), 2);
+        for vector in kernel.iter() {
+            assert_eq!(m.mul_vec(vector).unwrap(), vec![F::from(0); 2])
+        }
+        assert_eq!(m.image().len(), 2)
+    }
+
+    #[test]
+    fn transpose_entries() {
+        let m = SparseMatrix::from_dense(&dense(vec![vec![0, 1], vec![2, 0], vec![0, 3]]));
+        let transposed = m.transpose();
+        assert_eq!(transposed.num_rows(), 2);
+        assert_eq!(transposed.entry(1, 2), F::from(3));
+        assert_eq!(transposed.num_nonzero(), 3)
+    }
+
+    #[test]
+    fn determinant_rational() {
+        let m: SparseMatrix<Rational> = SparseMatrix::new(
+            2,
+            vec![
+                vec![(1, Rational::from(2))],
+                vec![(0, Rational::from(3)), (1, Rational::from(1))],
+            ],
+        )
+        .unwrap();
+        assert_eq!(m.determinant().unwrap(), Rational::from(-6));
+        let inverse = m.inverse().unwrap();
+        assert_eq!(inverse.to_dense() * m.to_dense(), Matrix::identity(2))
+    }
+}
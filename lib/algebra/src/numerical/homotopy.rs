@@ -0,0 +1,478 @@
+use super::{norm, solve_linear};
+use crate::{
+    complex::{ApproxEq, Complex},
+    errors::Error,
+    polynomials::{HomogeneousPolynomial, Monomial},
+    projective::{ProjectivePoint, ProjectiveScheme},
+};
+use std::f64::consts::PI;
+
+/// largest step in t
+const MAX_STEP: f64 = 0.05;
+/// paths stop once the step size falls below this
+const MIN_STEP: f64 = 1e-12;
+/// Newton steps of the corrector
+const CORRECTOR_STEPS: usize = 4;
+/// Newton steps refining the end point of a path
+/// near singular solutions Newton's method only converges linearly
+const REFINEMENT_STEPS: usize = 60;
+/// end points with a larger residual are dropped
+const MAX_RESIDUAL: f64 = 1e-8;
+/// solutions equal up to this tolerance are identified
+const SAME_SOLUTION: f64 = 1e-6;
+/// seed for gamma and the affine chart in solve_numerically
+const DEFAULT_SEED: u64 = 1;
+
+/// Approximate solution of a polynomial system
+/// the residual is max |f_i(x)| with the coordinates scaled to unit norm
+#[derive(Clone, Debug)]
+pub struct NumericalSolution {
+    point: ProjectivePoint<Complex>,
+    residual: f64,
+}
+
+impl NumericalSolution {
+    pub fn point(&self) -> &ProjectivePoint<Complex> {
+        &self.point
+    }
+
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+}
+
+fn unit(angle: f64) -> Complex {
+    Complex::from_polar(1.0, angle)
+}
+
+/// pseudo random angles in [0, 2 pi) from a linear congruential generator
+fn random_angles(seed: u64, count: usize) -> Vec<f64> {
+    let mut state = seed;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            2.0 * PI * (state >> 11) as f64 / (1u64 << 53) as f64
+        })
+        .collect()
+}
+
+fn scale(vector: &[Complex], c: f64) -> Vec<Complex> {
+    vector
+        .iter()
+        .map(|z| Complex::new(c * z.re, c * z.im))
+        .collect()
+}
+
+fn add(left: &[Complex], right: &[Complex]) -> Vec<Complex> {
+    left.iter()
+        .zip(right.iter())
+        .map(|(a, b)| a.clone() + b.clone())
+        .collect()
+}
+
+/// polynomials together with their Jacobian
+struct System {
+    polys: Vec<HomogeneousPolynomial<Complex>>,
+    jacobian: Vec<Vec<HomogeneousPolynomial<Complex>>>,
+}
+
+impl System {
+    fn new(polys: Vec<HomogeneousPolynomial<Complex>>) -> System {
+        let jacobian = polys.iter().map(|poly| poly.gradient()).collect();
+        System { polys, jacobian }
+    }
+
+    fn eval(&self, x: &[Complex]) -> Vec<Complex> {
+        self.polys
+            .iter()
+            .map(|poly| poly.eval(x.to_vec()).unwrap())
+            .collect()
+    }
+
+    fn eval_jacobian(&self, x: &[Complex]) -> Vec<Vec<Complex>> {
+        self.jacobian
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| entry.eval(x.to_vec()).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// H(x, t) = (1 - t) gamma G(x) + t F(x) together with an affine chart a.x = 1
+/// gamma and a are pseudo random points on the unit circle
+/// for all but finitely many gamma the paths stay away from singularities for t < 1
+struct Homotopy {
+    target: System,
+    start: System,
+    gamma: Complex,
+    chart: Vec<Complex>,
+}
+
+impl Homotopy {
+    fn value(&self, x: &[Complex], t: f64) -> Vec<Complex> {
+        let start = self.start.eval(x);
+        let target = self.target.eval(x);
+        let mut value: Vec<Complex> = start
+            .into_iter()
+            .zip(target)
+            .map(|(g, f)| {
+                Complex::new(1.0 - t, 0.0) * self.gamma.clone() * g + Complex::new(t, 0.0) * f
+            })
+            .collect();
+        let chart = self
+            .chart
+            .iter()
+            .zip(x.iter())
+            .fold(Complex::new(-1.0, 0.0), |sum, (a, x)| {
+                sum + a.clone() * x.clone()
+            });
+        value.push(chart);
+        value
+    }
+
+    fn derivative_x(&self, x: &[Complex], t: f64) -> Vec<Vec<Complex>> {
+        let start = self.start.eval_jacobian(x);
+        let target = self.target.eval_jacobian(x);
+        let mut rows: Vec<Vec<Complex>> = start
+            .into_iter()
+            .zip(target)
+            .map(|(g_row, f_row)| {
+                g_row
+                    .into_iter()
+                    .zip(f_row)
+                    .map(|(g, f)| {
+                        Complex::new(1.0 - t, 0.0) * self.gamma.clone() * g
+                            + Complex::new(t, 0.0) * f
+                    })
+                    .collect()
+            })
+            .collect();
+        rows.push(self.chart.clone());
+        rows
+    }
+
+    fn derivative_t(&self, x: &[Complex]) -> Vec<Complex> {
+        let mut derivative: Vec<Complex> = self
+            .start
+            .eval(x)
+            .into_iter()
+            .zip(self.target.eval(x))
+            .map(|(g, f)| f - self.gamma.clone() * g)
+            .collect();
+        derivative.push(Complex::new(0.0, 0.0));
+        derivative
+    }
+
+    /// Newton's method at fixed t
+    /// None if the last correction is not small
+    fn correct(&self, mut x: Vec<Complex>, t: f64, steps: usize) -> Option<Vec<Complex>> {
+        let mut last_correction = f64::INFINITY;
+        for _ in 0..steps {
+            let rhs = scale(&self.value(&x, t), -1.0);
+            let correction = solve_linear(self.derivative_x(&x, t), rhs)?;
+            last_correction = norm(&correction);
+            x = add(&x, &correction);
+            if last_correction < 1e-13 * (1.0 + norm(&x)) {
+                break;
+            }
+        }
+        if last_correction < 1e-8 * (1.0 + norm(&x)) {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// tracks a path from t = 0 to t = 1 with an Euler predictor and Newton corrector
+    /// the step size is adapted, a path that cannot be followed any further
+    /// stops at its last approximation, usually close to a singular solution at t = 1
+    fn track(&self, mut x: Vec<Complex>) -> Vec<Complex> {
+        let mut t = 0.0;
+        let mut step = MAX_STEP / 4.0;
+        while t < 1.0 {
+            let h = step.min(1.0 - t);
+            let rhs = scale(&self.derivative_t(&x), -1.0);
+            let predicted = solve_linear(self.derivative_x(&x, t), rhs)
+                .map(|tangent| add(&x, &scale(&tangent, h)))
+                .and_then(|predicted| self.correct(predicted, t + h, CORRECTOR_STEPS));
+            match predicted {
+                Some(corrected) => {
+                    x = corrected;
+                    t += h;
+                    step = (2.0 * step).min(MAX_STEP);
+                }
+                None => {
+                    step /= 2.0;
+                    if step < MIN_STEP {
+                        break;
+                    }
+                }
+            }
+        }
+        x
+    }
+
+    /// Newton's method for the target system
+    /// keeps the iterate with the smallest residual, as singular solutions
+    /// are only approximated up to about the square root of the machine precision
+    fn refine(&self, mut x: Vec<Complex>) -> Vec<Complex> {
+        let mut best = (norm(&self.value(&x, 1.0)), x.clone());
+        for _ in 0..REFINEMENT_STEPS {
+            let rhs = scale(&self.value(&x, 1.0), -1.0);
+            let Some(correction) = solve_linear(self.derivative_x(&x, 1.0), rhs) else {
+                break;
+            };
+            x = add(&x, &correction);
+            let residual = norm(&self.value(&x, 1.0));
+            if residual < best.0 {
+                best = (residual, x.clone());
+            }
+        }
+        best.1
+    }
+}
+
+/// coordinates divided by the coordinate of largest absolute value
+fn normalize(x: &[Complex]) -> Vec<Complex> {
+    let largest = x
+        .iter()
+        .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+        .unwrap()
+        .clone();
+    x.iter().map(|z| z.clone() / largest.clone()).collect()
+}
+
+impl ProjectiveScheme<Complex> {
+    /// max |f_i(x)| over all generators, with x scaled to unit norm
+    pub fn residual(&self, pt: &ProjectivePoint<Complex>) -> Result<f64, Error> {
+        if pt.dim() != self.num_variables() {
+            return Err(Error::DimensionMismatch {
+                found: pt.dim(),
+                expected: self.num_variables(),
+            });
+        }
+        let coordinates = pt.clone().as_arr();
+        let x = scale(&coordinates, 1.0 / norm(&coordinates));
+        let mut residual: f64 = 0.0;
+        for gen in self.ideal_generators().iter() {
+            residual = residual.max(gen.eval(x.clone())?.abs());
+        }
+        Ok(residual)
+    }
+
+    /// checks if the point lies on the scheme up to the given tolerance of the residual
+    pub fn contains_approx(
+        &self,
+        pt: &ProjectivePoint<Complex>,
+        tolerance: f64,
+    ) -> Result<bool, Error> {
+        Ok(self.residual(pt)? <= tolerance)
+    }
+
+    /// numerical solutions by total degree homotopy continuation
+    /// the scheme has to be cut out by n generators in P^n and be zero-dimensional
+    /// the paths start at the solutions of X_i^(d_i) - X_0^(d_i)
+    /// every solution is listed once, regardless of its multiplicity
+    /// multiple solutions are only accurate to about half the machine precision
+    pub fn solve_numerically(&self) -> Result<Vec<NumericalSolution>, Error> {
+        self.solve_numerically_with_seed(DEFAULT_SEED)
+    }
+
+    /// solve_numerically with gamma and the affine chart drawn from the given seed
+    /// another seed helps if a path of the default homotopy fails
+    pub fn solve_numerically_with_seed(&self, seed: u64) -> Result<Vec<NumericalSolution>, Error> {
+        let generators = self.ideal_generators();
+        let n = self.num_variables();
+        if generators.len() + 1 != n {
+            return Err(Error::DimensionMismatch {
+                found: generators.len(),
+                expected: n.saturating_sub(1),
+            });
+        }
+        let degrees: Vec<usize> = generators.iter().map(|gen| gen.deg()).collect();
+        let start_polys = degrees
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                let mut x_i = vec![0; n];
+                x_i[i + 1] = *d;
+                let mut x_0 = vec![0; n];
+                x_0[0] = *d;
+                HomogeneousPolynomial::new(vec![
+                    Monomial::new(Complex::new(1.0, 0.0), x_i),
+                    Monomial::new(Complex::new(-1.0, 0.0), x_0),
+                ])
+                .map(|poly| poly.with_dim(n))
+            })
+            .collect::<Result<Vec<HomogeneousPolynomial<Complex>>, Error>>()?;
+        let angles = random_angles(seed, n + 1);
+        let homotopy = Homotopy {
+            target: System::new(generators),
+            start: System::new(start_polys),
+            gamma: unit(angles[0]),
+            chart: angles[1..].iter().map(|angle| unit(*angle)).collect(),
+        };
+
+        // start solutions [1 : w_1 : ... : w_n] with w_i a d_i-th root of unity
+        let mut starts: Vec<Vec<Complex>> = vec![vec![Complex::new(1.0, 0.0)]];
+        for d in degrees.iter() {
+            starts = starts
+                .into_iter()
+                .flat_map(|start| {
+                    (0..*d).map(move |k| {
+                        let mut start = start.clone();
+                        start.push(unit(2.0 * PI * k as f64 / *d as f64));
+                        start
+                    })
+                })
+                .collect();
+        }
+
+        let mut solutions: Vec<NumericalSolution> = vec![];
+        for start in starts.into_iter() {
+            let chart_value = homotopy
+                .chart
+                .iter()
+                .zip(start.iter())
+                .fold(Complex::new(0.0, 0.0), |sum, (a, x)| {
+                    sum + a.clone() * x.clone()
+                });
+            let start: Vec<Complex> = start.into_iter().map(|x| x / chart_value.clone()).collect();
+            let end = homotopy.refine(homotopy.track(start));
+            let Ok(point) = ProjectivePoint::new(normalize(&end)) else {
+                continue;
+            };
+            let residual = self.residual(&point)?;
+            if residual.is_nan() || residual > MAX_RESIDUAL {
+                continue;
+            }
+            if solutions
+                .iter()
+                .all(|sol| !sol.point.approx_eq(&point, SAME_SOLUTION))
+            {
+                solutions.push(NumericalSolution { point, residual });
+            }
+        }
+        Ok(solutions)
+    }
+}
+
+#[cfg(test)]
+mod homotopy_tests {
+    use crate::{
+        complex::{ApproxEq, Complex, EPSILON},
+        polynomials::{HomogeneousPolynomial, Monomial},
+        projective::{ProjectivePoint, ProjectiveScheme},
+    };
+
+    fn homogeneous(terms: Vec<(f64, Vec<usize>)>) -> HomogeneousPolynomial<Complex> {
+        HomogeneousPolynomial::new(
+            terms
+                .into_iter()
+                .map(|(coeff, powers)| Monomial::new(coeff.into(), powers))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn real_point(coordinates: Vec<f64>) -> ProjectivePoint<Complex> {
+        ProjectivePoint::new(coordinates.into_iter().map(Complex::from).collect()).unwrap()
+    }
+
+    #[test]
+    fn four_points() {
+        // x^2 - z^2 = y^2 - 4z^2 = 0 at [+-1 : +-2 : 1]
+        let scheme = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1.0, vec![2, 0, 0]), (-1.0, vec![0, 0, 2])]),
+            homogeneous(vec![(1.0, vec![0, 2, 0]), (-4.0, vec![0, 0, 2])]),
+        ])
+        .unwrap();
+        let solutions = scheme.solve_numerically().unwrap();
+        assert_eq!(solutions.len(), 4);
+        assert!(solutions.iter().all(|sol| sol.residual() < 1e-9));
+        for (x, y) in [(1.0, 2.0), (-1.0, 2.0), (1.0, -2.0), (-1.0, -2.0)] {
+            let expected = real_point(vec![x, y, 1.0]);
+            assert!(scheme.contains_approx(&expected, 1e-12).unwrap());
+            assert!(solutions
+                .iter()
+                .any(|sol| sol.point().approx_eq(&expected, EPSILON)))
+        }
+    }
+
+    #[test]
+    fn line_meets_conic() {
+        // x = y and x^2 + y^2 = 2z^2 meet in [1:1:1] and [1:1:-1]
+        let scheme = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1.0, vec![1, 0, 0]), (-1.0, vec![0, 1, 0])]),
+            homogeneous(vec![
+                (1.0, vec![2, 0, 0]),
+                (1.0, vec![0, 2, 0]),
+                (-2.0, vec![0, 0, 2]),
+            ]),
+        ])
+        .unwrap();
+        let solutions = scheme.solve_numerically().unwrap();
+        assert_eq!(solutions.len(), 2);
+        assert!(solutions.iter().all(|sol| sol.residual() < 1e-9));
+        assert!(!scheme
+            .contains_approx(&real_point(vec![1.0, 0.0, 1.0]), 1e-6)
+            .unwrap())
+    }
+
+    #[test]
+    fn complex_solutions() {
+        // x^2 + z^2 = 0, y = 0 has no real solutions
+        let scheme = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1.0, vec![2, 0, 0]), (1.0, vec![0, 0, 2])]),
+            homogeneous(vec![(1.0, vec![0, 1, 0])]),
+        ])
+        .unwrap();
+        let solutions = scheme.solve_numerically().unwrap();
+        assert_eq!(solutions.len(), 2);
+        for sol in solutions.iter() {
+            let coords = sol.point().clone().as_arr();
+            let ratio = coords[0].clone() / coords[2].clone();
+            assert!(ratio.re.abs() < 1e-8);
+            assert!((ratio.im.abs() - 1.0).abs() < 1e-8)
+        }
+    }
+
+    #[test]
+    fn independent_of_seed() {
+        // x^2 - z^2 = xy - 2z^2 = 0 at [1:2:1], [-1:-2:1] and the double point [0:1:0]
+        let scheme = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1.0, vec![2, 0, 0]), (-1.0, vec![0, 0, 2])]),
+            homogeneous(vec![(1.0, vec![1, 1, 0]), (-2.0, vec![0, 0, 2])]),
+        ])
+        .unwrap();
+        let expected = scheme.solve_numerically().unwrap();
+        assert_eq!(expected.len(), 3);
+        for point in [
+            real_point(vec![1.0, 2.0, 1.0]),
+            real_point(vec![-1.0, -2.0, 1.0]),
+            real_point(vec![0.0, 1.0, 0.0]),
+        ] {
+            assert!(expected
+                .iter()
+                .any(|sol| sol.point().approx_eq(&point, 1e-6)))
+        }
+        for seed in 2..6 {
+            let solutions = scheme.solve_numerically_with_seed(seed).unwrap();
+            assert_eq!(solutions.len(), 3);
+            assert!(solutions.iter().all(|sol| expected
+                .iter()
+                .any(|other| sol.point().approx_eq(other.point(), 1e-6))))
+        }
+    }
+
+    #[test]
+    fn not_square() {
+        let scheme = ProjectiveScheme::new(vec![homogeneous(vec![(1.0, vec![1, 0, 0])])]).unwrap();
+        assert!(scheme.solve_numerically().is_err())
+    }
+}
@@ -0,0 +1,48 @@
+pub mod homotopy;
+pub mod roots;
+
+pub use homotopy::NumericalSolution;
+pub use roots::aberth;
+
+use crate::complex::Complex;
+
+/// Euclidean norm of a complex vector
+pub(crate) fn norm(vector: &[Complex]) -> f64 {
+    vector.iter().map(Complex::norm_sqr).sum::<f64>().sqrt()
+}
+
+/// solves Ax = b by Gaussian elimination with partial pivoting
+/// None if A is numerically singular
+pub(crate) fn solve_linear(
+    mut matrix: Vec<Vec<Complex>>,
+    mut rhs: Vec<Complex>,
+) -> Option<Vec<Complex>> {
+    let n = rhs.len();
+    for col in 0..n {
+        let pivot =
+            (col..n).max_by(|i, j| matrix[*i][col].abs().total_cmp(&matrix[*j][col].abs()))?;
+        if matrix[pivot][col].abs() < 1e-14 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for i in col + 1..n {
+            let factor = matrix[i][col].clone() / pivot_row[col].clone();
+            for (entry, pivot_entry) in matrix[i].iter_mut().zip(pivot_row.iter()).skip(col) {
+                *entry = entry.clone() - factor.clone() * pivot_entry.clone();
+            }
+            let sub = factor * rhs[col].clone();
+            rhs[i] = rhs[i].clone() - sub;
+        }
+    }
+    let mut solution = vec![Complex::new(0.0, 0.0); n];
+    for i in (0..n).rev() {
+        let mut sum = rhs[i].clone();
+        for j in i + 1..n {
+            sum = sum - matrix[i][j].clone() * solution[j].clone();
+        }
+        solution[i] = sum / matrix[i][i].clone();
+    }
+    Some(solution)
+}
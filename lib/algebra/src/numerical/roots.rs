@@ -0,0 +1,107 @@
+use super::norm;
+use crate::{complex::Complex, field::Field, polynomials::UnivariatePolynomial};
+use std::f64::consts::PI;
+
+/// maximal number of Aberth iterations
+const MAX_ITERATIONS: usize = 500;
+
+/// Aberth-Ehrlich method
+/// simultaneously approximates all complex roots of a nonconstant polynomial,
+/// repeated roots are listed with their multiplicity
+/// stops once all corrections are smaller than tolerance
+pub fn aberth(poly: &UnivariatePolynomial<Complex>, tolerance: f64) -> Vec<Complex> {
+    let degree = match poly.degree() {
+        None | Some(0) => return vec![],
+        Some(degree) => degree,
+    };
+    let poly = poly.clone().make_monic();
+    let derivative = poly.derivative();
+
+    // initial guesses on a circle containing all roots (Cauchy bound)
+    let radius = 1.0
+        + poly.coefficients()[..degree]
+            .iter()
+            .map(|c| c.abs())
+            .fold(0.0, f64::max);
+    let mut roots: Vec<Complex> = (0..degree)
+        .map(|k| Complex::from_polar(radius, 2.0 * PI * k as f64 / degree as f64 + 0.4))
+        .collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut corrections = vec![];
+        for k in 0..degree {
+            let z = roots[k].clone();
+            let value = poly.eval(&z);
+            if value.abs() == 0.0 {
+                corrections.push(Complex::new(0.0, 0.0));
+                continue;
+            }
+            let newton = value * reciprocal(derivative.eval(&z));
+            let repulsion = (0..degree)
+                .filter(|j| *j != k)
+                .fold(Complex::new(0.0, 0.0), |sum, j| {
+                    sum + reciprocal(z.clone() - roots[j].clone())
+                });
+            let denominator = Complex::new(1.0, 0.0) - newton.clone() * repulsion;
+            let correction = newton / denominator;
+            roots[k] = z - correction.clone();
+            corrections.push(correction);
+        }
+        if norm(&corrections) < tolerance {
+            break;
+        }
+    }
+    roots
+}
+
+/// 1/z, or 0 for z = 0
+fn reciprocal(z: Complex) -> Complex {
+    if z.norm_sqr() == 0.0 {
+        Complex::new(0.0, 0.0)
+    } else {
+        z.inverse()
+    }
+}
+
+impl UnivariatePolynomial<Complex> {
+    /// all complex roots with multiplicity, see aberth
+    pub fn roots(&self) -> Vec<Complex> {
+        aberth(self, 1e-12)
+    }
+}
+
+#[cfg(test)]
+mod roots_tests {
+    use crate::{complex::Complex, polynomials::UnivariatePolynomial};
+
+    fn poly(coefficients: Vec<f64>) -> UnivariatePolynomial<Complex> {
+        UnivariatePolynomial::new(coefficients.into_iter().map(Complex::from).collect())
+    }
+
+    #[test]
+    fn cube_roots_of_unity() {
+        let f = poly(vec![-1.0, 0.0, 0.0, 1.0]);
+        let roots = f.roots();
+        assert_eq!(roots.len(), 3);
+        assert!(roots.iter().all(|z| f.eval(z).abs() < 1e-10));
+        assert!(roots
+            .iter()
+            .any(|z| (z.re - 1.0).abs() < 1e-10 && z.im.abs() < 1e-10))
+    }
+
+    #[test]
+    fn real_roots() {
+        // (x - 1)(x - 2)(x + 3)
+        let f = poly(vec![6.0, -7.0, 0.0, 1.0]);
+        let mut roots: Vec<f64> = f.roots().into_iter().map(|z| z.re).collect();
+        roots.sort_by(f64::total_cmp);
+        for (root, expected) in roots.into_iter().zip([-3.0, 1.0, 2.0]) {
+            assert!((root - expected).abs() < 1e-10)
+        }
+    }
+
+    #[test]
+    fn constant_has_no_roots() {
+        assert!(poly(vec![2.0]).roots().is_empty())
+    }
+}
@@ -0,0 +1,312 @@
+use super::UnivariatePolynomial;
+use crate::{
+    errors::Error,
+    field::{Field, FiniteField},
+    ring::Ring,
+};
+
+/// Factorization of univariate polynomials into monic irreducible factors
+/// implemented for finite fields and the rationals
+/// over finite fields it never fails, over the rationals the coefficients may become too large
+pub trait Factorization: Sized {
+    /// pairwise coprime monic squarefree factors g_i with multiplicities i
+    /// such that f = lc(f) prod g_i^i, sorted by multiplicity
+    fn squarefree_factorization(&self) -> Result<Vec<(Self, usize)>, Error>;
+
+    /// monic irreducible factors with multiplicities
+    /// such that f = lc(f) prod g^e
+    fn factor(&self) -> Result<Vec<(Self, usize)>, Error>;
+
+    /// nonconstant and without nontrivial factors
+    fn is_irreducible(&self) -> Result<bool, Error> {
+        let factors = self.factor()?;
+        Ok(factors.len() == 1 && factors[0].1 == 1)
+    }
+}
+
+/// square and multiply for field elements
+fn pow_elem<K: Field + Clone>(mut base: K, mut n: u64) -> K {
+    let mut res = <K as Field>::one();
+    while n > 0 {
+        if n % 2 == 1 {
+            res = res * base.clone();
+        }
+        base = base.clone() * base;
+        n /= 2;
+    }
+    res
+}
+
+/// Squarefree factorization over finite fields
+/// factors whose multiplicity is divisible by p are found by taking p-th roots
+fn squarefree_finite<K: FiniteField>(
+    f: &UnivariatePolynomial<K>,
+) -> Vec<(UnivariatePolynomial<K>, usize)> {
+    let f = f.clone().make_monic();
+    let p = K::characteristic();
+    let one = UnivariatePolynomial::one();
+    let mut factors = vec![];
+    let derivative = f.derivative();
+    let mut c = if derivative.is_zero() {
+        f
+    } else {
+        let mut c = f.gcd(&derivative);
+        let mut w = f.div_rem(&c).unwrap().0;
+        let mut i = 1;
+        while w != one {
+            let y = w.gcd(&c);
+            let factor = w.div_rem(&y).unwrap().0;
+            if factor != one {
+                factors.push((factor, i));
+            }
+            i += 1;
+            w = y.clone();
+            c = c.div_rem(&y).unwrap().0;
+        }
+        c
+    };
+    if c != one {
+        c = pth_root(&c);
+        for (factor, mult) in squarefree_finite(&c) {
+            factors.push((factor, mult * p as usize));
+        }
+    }
+    merge_multiplicities(factors)
+}
+
+/// g with g^p = f, f has to be a polynomial in x^p
+/// the p-th root of a in GF(q) is a^(q/p)
+fn pth_root<K: FiniteField>(f: &UnivariatePolynomial<K>) -> UnivariatePolynomial<K> {
+    let p = K::characteristic() as usize;
+    let root_exponent = K::order() / K::characteristic();
+    UnivariatePolynomial::new(
+        f.coefficients()
+            .iter()
+            .step_by(p)
+            .map(|c| pow_elem(c.clone(), root_exponent))
+            .collect(),
+    )
+}
+
+/// multiplies factors with the same multiplicity and sorts by multiplicity
+fn merge_multiplicities<K: Field + Clone>(
+    factors: Vec<(UnivariatePolynomial<K>, usize)>,
+) -> Vec<(UnivariatePolynomial<K>, usize)> {
+    let mut merged: Vec<(UnivariatePolynomial<K>, usize)> = vec![];
+    for (factor, mult) in factors.into_iter() {
+        match merged.iter_mut().find(|(_, other)| *other == mult) {
+            Some((other_factor, _)) => *other_factor = other_factor.clone() * factor,
+            None => merged.push((factor, mult)),
+        }
+    }
+    merged.sort_by_key(|(_, mult)| *mult);
+    merged
+}
+
+/// Distinct degree factorization of a monic squarefree polynomial
+/// returns products of all irreducible factors of degree d together with d
+fn distinct_degree<K: FiniteField>(
+    f: &UnivariatePolynomial<K>,
+) -> Vec<(UnivariatePolynomial<K>, usize)> {
+    let q = K::order();
+    let x = UnivariatePolynomial::x();
+    let one = UnivariatePolynomial::one();
+    let mut rest = f.clone();
+    let mut h = x.rem(&rest).unwrap();
+    let mut factors = vec![];
+    let mut d = 1;
+    while rest.degree().unwrap_or(0) >= 2 * d {
+        // h = x^(q^d) mod rest
+        h = h.pow_mod(q, &rest).unwrap();
+        let g = rest.gcd(&(h.clone() - x.clone()));
+        if g != one {
+            rest = rest.div_rem(&g).unwrap().0;
+            h = h.rem(&rest).unwrap();
+            factors.push((g, d));
+        }
+        d += 1;
+    }
+    if let Some(deg) = rest.degree().filter(|deg| *deg > 0) {
+        factors.push((rest, deg));
+    }
+    factors
+}
+
+/// xorshift pseudo random numbers, so factorizations are reproducible
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+/// Cantor-Zassenhaus splitting of a monic squarefree product of irreducibles of degree d
+fn equal_degree<K: FiniteField>(
+    f: &UnivariatePolynomial<K>,
+    d: usize,
+    elements: &[K],
+    random: &mut Random,
+) -> Vec<UnivariatePolynomial<K>> {
+    let n = f.degree().unwrap();
+    if n == d {
+        return vec![f.clone()];
+    }
+    let q = K::order();
+    let one = UnivariatePolynomial::one();
+    loop {
+        let a = UnivariatePolynomial::new(
+            (0..n)
+                .map(|_| elements[(random.next() % q) as usize].clone())
+                .collect(),
+        );
+        if a.degree().unwrap_or(0) == 0 {
+            continue;
+        }
+        let mut g = a.gcd(f);
+        if g == one {
+            let b = if q % 2 == 1 {
+                // a^((q^d-1)/2) = (a a^q ... a^(q^(d-1)))^((q-1)/2)
+                let mut frobenius = a.clone();
+                let mut norm = a.clone();
+                for _ in 1..d {
+                    frobenius = frobenius.pow_mod(q, f).unwrap();
+                    norm = (norm * frobenius.clone()).rem(f).unwrap();
+                }
+                norm.pow_mod((q - 1) / 2, f).unwrap() - one.clone()
+            } else {
+                // trace a + a^2 + a^4 + ... + a^(2^(kd-1)) where q = 2^k
+                let k = q.trailing_zeros() as usize;
+                let mut square = a.clone();
+                let mut trace = a.clone();
+                for _ in 1..k * d {
+                    square = square.pow_mod(2, f).unwrap();
+                    trace = trace + square.clone();
+                }
+                trace
+            };
+            g = b.gcd(f);
+        }
+        if g != one && g != *f {
+            let h = f.div_rem(&g).unwrap().0;
+            let mut factors = equal_degree(&g, d, elements, random);
+            factors.extend(equal_degree(&h, d, elements, random));
+            return factors;
+        }
+    }
+}
+
+impl<K: FiniteField> Factorization for UnivariatePolynomial<K> {
+    fn squarefree_factorization(&self) -> Result<Vec<(UnivariatePolynomial<K>, usize)>, Error> {
+        if self.degree().unwrap_or(0) == 0 {
+            return Ok(vec![]);
+        }
+        Ok(squarefree_finite(self))
+    }
+
+    /// squarefree, distinct degree and Cantor-Zassenhaus equal degree factorization
+    fn factor(&self) -> Result<Vec<(UnivariatePolynomial<K>, usize)>, Error> {
+        let elements = K::elements();
+        let mut random = Random {
+            state: 0x2545_f491_4f6c_dd1d,
+        };
+        let mut factors = vec![];
+        for (squarefree, mult) in self.squarefree_factorization()? {
+            for (product, d) in distinct_degree(&squarefree) {
+                for factor in equal_degree(&product, d, &elements, &mut random) {
+                    factors.push((factor, mult));
+                }
+            }
+        }
+        factors.sort_by_key(|(factor, mult)| (factor.degree(), *mult));
+        Ok(factors)
+    }
+}
+
+#[cfg(test)]
+mod factorization_tests {
+    use super::Factorization;
+    use crate::{
+        field::FiniteField, galois_field::GaloisField, polynomials::UnivariatePolynomial,
+        prime_field::PrimeField, ring::Ring,
+    };
+
+    type F3 = PrimeField<3>;
+    type F5 = PrimeField<5>;
+
+    fn poly<K: FiniteField + From<i64>>(coefficients: Vec<i64>) -> UnivariatePolynomial<K> {
+        UnivariatePolynomial::new(coefficients.into_iter().map(K::from).collect())
+    }
+
+    fn expand<K: FiniteField>(
+        factors: &[(UnivariatePolynomial<K>, usize)],
+    ) -> UnivariatePolynomial<K> {
+        factors
+            .iter()
+            .fold(UnivariatePolynomial::one(), |res, (factor, mult)| {
+                res * <UnivariatePolynomial<K> as Ring>::pow(factor.clone(), *mult)
+            })
+    }
+
+    #[test]
+    fn squarefree_multiplicities() {
+        // (x^2 + 1)^2 (x + 1)^3 over F_3, the cube has vanishing derivative
+        let f = <UnivariatePolynomial<F3> as Ring>::pow(poly(vec![1, 0, 1]), 2)
+            * <UnivariatePolynomial<F3> as Ring>::pow(poly(vec![1, 1]), 3);
+        let result = f.squarefree_factorization().unwrap();
+        assert_eq!(
+            result,
+            vec![(poly(vec![1, 0, 1]), 2), (poly(vec![1, 1]), 3)]
+        )
+    }
+
+    #[test]
+    fn split_into_linear_factors() {
+        // x^4 - 1 = (x-1)(x-2)(x-3)(x-4) over F_5
+        let factors = poly::<F5>(vec![-1, 0, 0, 0, 1]).factor().unwrap();
+        assert_eq!(factors.len(), 4);
+        assert!(factors
+            .iter()
+            .all(|(f, mult)| f.degree() == Some(1) && *mult == 1));
+        assert_eq!(expand(&factors), poly(vec![-1, 0, 0, 0, 1]))
+    }
+
+    #[test]
+    fn mixed_degrees() {
+        // (x^2 + 2)(x^3 + x + 1)^2 (x + 4) over F_5
+        let f = poly::<F5>(vec![2, 0, 1])
+            * <UnivariatePolynomial<F5> as Ring>::pow(poly(vec![1, 1, 0, 1]), 2)
+            * poly(vec![4, 1]);
+        let factors = f.factor().unwrap();
+        assert_eq!(
+            factors,
+            vec![
+                (poly(vec![4, 1]), 1),
+                (poly(vec![2, 0, 1]), 1),
+                (poly(vec![1, 1, 0, 1]), 2)
+            ]
+        )
+    }
+
+    #[test]
+    fn characteristic_two_extension() {
+        // x^4 - x splits into linear factors over F_4
+        type F4 = GaloisField<2, 2>;
+        let f = UnivariatePolynomial::<F4>::monomial(1.into(), 4) - UnivariatePolynomial::x();
+        let factors = f.factor().unwrap();
+        assert_eq!(factors.len(), 4);
+        assert_eq!(expand(&factors), f)
+    }
+
+    #[test]
+    fn irreducibility() {
+        assert!(poly::<F3>(vec![1, 2, 0, 1]).is_irreducible().unwrap());
+        assert!(!poly::<F3>(vec![1, 0, 1, 0, 1]).is_irreducible().unwrap());
+        assert!(!poly::<F3>(vec![1]).is_irreducible().unwrap())
+    }
+}
@@ -1,8 +1,8 @@
-use super::{monomial::Monomial, polynomial::Polynomial};
+use super::{monomial::Monomial, polynomial::Polynomial, MonomialOrder};
 use crate::{errors::Error, field::Field, projective::ProjectiveMorphism, ring::Ring};
-use std::{fmt, ops::Mul};
+use std::{cmp::Ordering, fmt, ops::Mul};
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct HomogeneousPolynomial<R: Ring> {
     dim: usize,
     deg: usize,
@@ -11,22 +11,14 @@ pub struct HomogeneousPolynomial<R: Ring> {
 
 impl<R: Ring> HomogeneousPolynomial<R> {
     pub fn new(monomials: Vec<Monomial<R>>) -> Result<HomogeneousPolynomial<R>, Error> {
-        let mono1 = monomials.first();
-        let deg = match mono1 {
-            None => {
-                return Ok(HomogeneousPolynomial {
-                    dim: 0,
-                    deg: 0,
-                    monomials: vec![],
-                })
-            }
-            Some(mono) => mono.deg(),
-        };
+        let poly = Polynomial::new(monomials);
+        let dim = poly.dim();
+        let monomials = poly.into_terms();
         Monomial::check_deg(monomials.as_slice())?;
 
         Ok(HomogeneousPolynomial {
-            dim: monomials.iter().map(|mono| mono.dim()).max().unwrap(),
-            deg,
+            dim,
+            deg: monomials.first().map(|mono| mono.deg()).unwrap_or(0),
             monomials,
         })
     }
@@ -39,6 +31,62 @@ impl<R: Ring> HomogeneousPolynomial<R> {
         self.dim
     }
 
+    pub fn is_zero(&self) -> bool {
+        self.monomials.is_empty()
+    }
+
+    /// the same polynomial in at least dim variables
+    pub fn with_dim(self, dim: usize) -> HomogeneousPolynomial<R> {
+        let dim = dim.max(self.dim);
+        HomogeneousPolynomial {
+            dim,
+            deg: self.deg,
+            monomials: self
+                .monomials
+                .into_iter()
+                .map(|mono| mono.with_dim(dim))
+                .collect(),
+        }
+    }
+
+    /// sets X_i = 1 and removes the variable
+    /// this is the restriction to the affine chart X_i != 0
+    pub fn dehomogenize(self, i: usize) -> Polynomial<R> {
+        let dim = self.dim.saturating_sub(1);
+        Polynomial::new(
+            self.monomials
+                .into_iter()
+                .map(|mono| mono.remove_variable(i))
+                .collect(),
+        )
+        .with_dim(dim)
+    }
+
+    /// formal partial derivative d/dX_i
+    /// this is homogeneous of degree deg-1, or zero
+    pub fn partial_derivative(&self, i: usize) -> HomogeneousPolynomial<R>
+    where
+        R: Clone,
+    {
+        let mut derivative = HomogeneousPolynomial::new(
+            self.monomials
+                .iter()
+                .map(|mono| mono.derivative(i))
+                .collect(),
+        )
+        .unwrap();
+        derivative.dim = derivative.dim.max(self.dim);
+        derivative
+    }
+
+    /// all partial derivatives d/dX_0,...,d/dX_(dim-1)
+    pub fn gradient(&self) -> Vec<HomogeneousPolynomial<R>>
+    where
+        R: Clone,
+    {
+        (0..self.dim).map(|i| self.partial_derivative(i)).collect()
+    }
+
     pub fn monomials(&self) -> Vec<Monomial<R>>
     where
         R: Clone,
@@ -46,6 +94,20 @@ impl<R: Ring> HomogeneousPolynomial<R> {
         self.monomials.clone()
     }
 
+    /// applies f to all coefficients, e.g. to change the coefficient ring
+    pub fn map_coefficients<S: Ring>(self, f: impl Fn(R) -> S) -> HomogeneousPolynomial<S> {
+        let dim = self.dim;
+        let mut homo = HomogeneousPolynomial::new(
+            self.monomials
+                .into_iter()
+                .map(|mono| mono.map_coefficient(&f))
+                .collect(),
+        )
+        .unwrap();
+        homo.dim = homo.dim.max(dim);
+        homo
+    }
+
     pub fn eval(&self, x: Vec<R>) -> Result<R, Error>
     where
         R: Clone,
@@ -67,6 +129,8 @@ impl<R: Ring> HomogeneousPolynomial<R> {
         Ok(res)
     }
 
+    /// pullback along a morphism
+    /// substitutes the coordinate functions of the morphism for the variables
     pub fn compose_morphism(
         self,
         morphism: &ProjectiveMorphism<R>,
@@ -74,26 +138,34 @@ impl<R: Ring> HomogeneousPolynomial<R> {
     where
         R: Field + Clone,
     {
-        let mut res = Polynomial::new(vec![]);
-        for mono in self.monomials {
-            let new_poly = mono.compose_morphism(morphism.clone())?;
-            res = res + new_poly;
+        if self.dim != morphism.dim_codomain() {
+            return Err(Error::DimensionMismatch {
+                found: morphism.dim_codomain(),
+                expected: self.dim,
+            });
         }
-        res.try_into()
+        let values: Vec<Polynomial<R>> = morphism
+            .coordinate_functions()
+            .into_iter()
+            .map(|fun| fun.into())
+            .collect();
+        let composed: HomogeneousPolynomial<R> =
+            Polynomial::from(self).substitute(&values)?.try_into()?;
+        Ok(composed.with_dim(morphism.dim_domain()))
     }
 
+    /// f(X_0,...,X_(n-1)) g(X_n,...,X_(n+m-1)) for n = self.dim()
+    #[deprecated(note = "multiply by other with its variables shifted by self.dim()")]
     pub fn product(self, other: HomogeneousPolynomial<R>) -> HomogeneousPolynomial<R>
     where
         R: Clone,
     {
-        let new_other: Vec<Monomial<R>> = other
-            .monomials()
+        let shifted = other
+            .monomials
             .into_iter()
-            .map(|mono| mono.shift_powers(self.dim))
+            .map(|mono| mono.shift_variables(self.dim))
             .collect();
-        let mut new_monomials = self.monomials;
-        new_monomials.extend(new_other);
-        HomogeneousPolynomial::new(new_monomials).unwrap()
+        self * HomogeneousPolynomial::new(shifted).unwrap()
     }
 
     pub fn check_deg(polys: &[Self]) -> Result<usize, Error> {
@@ -125,16 +197,34 @@ impl<R: Ring> HomogeneousPolynomial<R> {
     }
 }
 
+/// equality of polynomials, as for Polynomial the number of variables is ignored
+impl<R: Ring> PartialEq for HomogeneousPolynomial<R> {
+    fn eq(&self, other: &HomogeneousPolynomial<R>) -> bool {
+        self.monomials.len() == other.monomials.len()
+            && self
+                .monomials
+                .iter()
+                .zip(other.monomials.iter())
+                .all(|(mono1, mono2)| {
+                    mono1.coefficient == mono2.coefficient
+                        && MonomialOrder::Lex.cmp(mono1, mono2) == Ordering::Equal
+                })
+    }
+}
+
 impl<R: Ring> From<HomogeneousPolynomial<R>> for Polynomial<R> {
     fn from(homo: HomogeneousPolynomial<R>) -> Polynomial<R> {
-        Polynomial::new(homo.monomials)
+        Polynomial::new(homo.monomials).with_dim(homo.dim)
     }
 }
 
 impl<R: Ring + Clone> TryFrom<Polynomial<R>> for HomogeneousPolynomial<R> {
     type Error = Error;
     fn try_from(poly: Polynomial<R>) -> Result<HomogeneousPolynomial<R>, Error> {
-        HomogeneousPolynomial::new(poly.monomials())
+        let dim = poly.dim();
+        let mut homo = HomogeneousPolynomial::new(poly.into_terms())?;
+        homo.dim = homo.dim.max(dim);
+        Ok(homo)
     }
 }
 
@@ -145,20 +235,8 @@ where
 {
     type Output = HomogeneousPolynomial<R>;
     fn mul(self, other: HomogeneousPolynomial<R>) -> HomogeneousPolynomial<R> {
-        let mut new_monomials: Vec<Monomial<R>> = vec![];
-        for self_mono in self.monomials.iter() {
-            for other_mono in other.monomials.iter() {
-                let mut new_mono = self_mono.clone() * other_mono.clone();
-                if let Some(mono) = new_monomials
-                    .iter()
-                    .find(|mono| mono.powers() == new_mono.powers())
-                {
-                    new_mono.coefficient = new_mono.coefficient + mono.coefficient.clone();
-                }
-                new_monomials.push(new_mono);
-            }
-        }
-        HomogeneousPolynomial::new(new_monomials).unwrap()
+        let product = Polynomial::from(self) * Polynomial::from(other);
+        product.try_into().unwrap()
     }
 }
 
@@ -168,6 +246,9 @@ where
     R: Ring,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.monomials.is_empty() {
+            return write!(f, "0");
+        }
         let mon_str: Vec<String> = self
             .monomials
             .iter()
@@ -176,3 +257,53 @@ where
         write!(f, "{}", mon_str.join(" + "))
     }
 }
+
+#[cfg(test)]
+mod homogeneous_polynomial_tests {
+    use super::HomogeneousPolynomial;
+    use crate::{
+        polynomials::{Monomial, Polynomial},
+        rational::Rational,
+    };
+
+    /// the same form as a polynomial in dim variables
+    fn in_dim(f: &HomogeneousPolynomial<Rational>, dim: usize) -> HomogeneousPolynomial<Rational> {
+        HomogeneousPolynomial::try_from(Polynomial::from(f.clone()).with_dim(dim)).unwrap()
+    }
+
+    #[test]
+    fn equality_ignores_dim() {
+        let f = HomogeneousPolynomial::new(vec![
+            Monomial::new(Rational::from(1), vec![2, 0]),
+            Monomial::new(Rational::from(3), vec![1, 1]),
+        ])
+        .unwrap();
+        assert_eq!(in_dim(&f, 4).dim(), 4);
+        assert_eq!(in_dim(&f, 4), f);
+        assert_eq!(f, in_dim(&f, 3));
+        let g =
+            HomogeneousPolynomial::new(vec![Monomial::new(Rational::from(1), vec![2, 0])]).unwrap();
+        assert!(f != in_dim(&g, 3));
+        let zero = HomogeneousPolynomial::<Rational>::new(vec![]).unwrap();
+        assert_eq!(in_dim(&zero, 2), zero)
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn product_in_disjoint_variables() {
+        // (X_0 + X_1) times X_0, which becomes X_2
+        let f = HomogeneousPolynomial::new(vec![
+            Monomial::new(Rational::from(1), vec![1, 0]),
+            Monomial::new(Rational::from(1), vec![0, 1]),
+        ])
+        .unwrap();
+        let g =
+            HomogeneousPolynomial::new(vec![Monomial::new(Rational::from(1), vec![1])]).unwrap();
+        let expected = HomogeneousPolynomial::new(vec![
+            Monomial::new(Rational::from(1), vec![1, 0, 1]),
+            Monomial::new(Rational::from(1), vec![0, 1, 1]),
+        ])
+        .unwrap();
+        assert_eq!(f.product(g), expected)
+    }
+}
@@ -1,7 +1,14 @@
+pub mod factorization;
 pub mod homogeneous_polynomial;
 pub mod monomial;
+pub mod monomial_order;
 pub mod polynomial;
+pub mod univariate;
+mod zassenhaus;
 
+pub use factorization::Factorization;
 pub use homogeneous_polynomial::HomogeneousPolynomial;
 pub use monomial::Monomial;
+pub use monomial_order::MonomialOrder;
 pub use polynomial::Polynomial;
+pub use univariate::UnivariatePolynomial;
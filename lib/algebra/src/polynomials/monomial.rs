@@ -5,7 +5,7 @@ use std::{
     ops::{Add, Mul, Neg},
 };
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Monomial<R: Ring> {
     dim: usize,
     pub coefficient: R,
@@ -21,10 +21,163 @@ impl<R: Ring> Monomial<R> {
         }
     }
 
+    /// all monomials of degree deg in dim variables with coefficient one
+    /// sorted descending by Lex
+    pub fn all_of_degree(dim: usize, deg: usize) -> Vec<Monomial<R>> {
+        if dim == 0 {
+            return if deg == 0 {
+                vec![Monomial::new(R::one(), vec![])]
+            } else {
+                vec![]
+            };
+        }
+        let mut monomials = vec![];
+        for first in (0..=deg).rev() {
+            for rest in Monomial::<R>::all_of_degree(dim - 1, deg - first) {
+                let mut powers = vec![first];
+                powers.extend(rest.powers);
+                monomials.push(Monomial::new(R::one(), powers));
+            }
+        }
+        monomials
+    }
+
     pub fn powers(&self) -> Vec<usize> {
         self.powers.clone()
     }
 
+    /// powers without cloning
+    pub fn exponents(&self) -> &[usize] {
+        &self.powers
+    }
+
+    /// Extends the monomial to dim variables
+    /// missing variables get power 0
+    pub fn with_dim(mut self, dim: usize) -> Monomial<R> {
+        if dim > self.dim {
+            self.powers.resize(dim, 0);
+            self.dim = dim;
+        }
+        self
+    }
+
+    /// applies f to the coefficient, e.g. to change the coefficient ring
+    pub fn map_coefficient<S: Ring>(self, f: impl Fn(R) -> S) -> Monomial<S> {
+        Monomial::new(f(self.coefficient), self.powers)
+    }
+
+    /// inserts a new variable with the given power at position i
+    /// X_j -> X_(j+1) for j >= i
+    pub fn insert_variable(self, i: usize, power: usize) -> Monomial<R> {
+        let mono = self.with_dim(i);
+        let mut powers = mono.powers;
+        powers.insert(i, power);
+        Monomial::new(mono.coefficient, powers)
+    }
+
+    /// sets X_i = 1 and removes it
+    /// X_j -> X_(j-1) for j > i
+    pub fn remove_variable(self, i: usize) -> Monomial<R> {
+        let mut powers = self.powers;
+        if i < powers.len() {
+            powers.remove(i);
+        }
+        Monomial::new(self.coefficient, powers)
+    }
+
+    /// X_i -> X_(i+n)
+    /// the new variables X_0,...,X_(n-1) have power 0
+    pub fn shift_variables(self, n: usize) -> Monomial<R> {
+        let mut powers = vec![0; n];
+        powers.extend(self.powers);
+        Monomial::new(self.coefficient, powers)
+    }
+
+    #[deprecated(note = "renamed to shift_variables")]
+    pub fn shift_powers(self, n: usize) -> Monomial<R> {
+        self.shift_variables(n)
+    }
+
+    /// X_i -> X_(i-n)
+    /// None if one of X_0,...,X_(n-1) occurs
+    pub fn unshift_variables(self, n: usize) -> Option<Monomial<R>> {
+        if self.powers.iter().take(n).any(|pow| *pow != 0) {
+            return None;
+        }
+        let powers = self.powers.into_iter().skip(n).collect();
+        Some(Monomial::new(self.coefficient, powers))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficient == R::zero()
+    }
+
+    /// checks if the powers of self divide the powers of other
+    /// coefficients are ignored
+    pub fn divides(&self, other: &Monomial<R>) -> bool {
+        (0..self.dim.max(other.dim)).all(|i| self.power(i) <= other.power(i))
+    }
+
+    /// checks if self and other share no variables
+    pub fn is_coprime(&self, other: &Monomial<R>) -> bool {
+        (0..self.dim.max(other.dim)).all(|i| self.power(i) == 0 || other.power(i) == 0)
+    }
+
+    /// least common multiple of the powers
+    /// the coefficient is always one
+    pub fn lcm(&self, other: &Monomial<R>) -> Monomial<R> {
+        let powers = (0..self.dim.max(other.dim))
+            .map(|i| self.power(i).max(other.power(i)))
+            .collect();
+        Monomial::new(R::one(), powers)
+    }
+
+    /// self / other, if other divides self
+    pub fn quotient(&self, other: &Monomial<R>) -> Option<Monomial<R>>
+    where
+        R: Field + Clone,
+    {
+        if !other.divides(self) || other.is_zero() {
+            return None;
+        }
+        let powers = (0..self.dim.max(other.dim))
+            .map(|i| self.power(i) - other.power(i))
+            .collect();
+        Some(Monomial::new(
+            self.coefficient.clone() / other.coefficient.clone(),
+            powers,
+        ))
+    }
+
+    /// formal partial derivative d/dX_i
+    /// the power is multiplied into the coefficient by repeated addition
+    /// so this also works in positive characteristic
+    pub fn derivative(&self, i: usize) -> Monomial<R>
+    where
+        R: Clone,
+    {
+        let power = self.power(i);
+        let mut coefficient = R::zero();
+        for _ in 0..power {
+            coefficient = coefficient + self.coefficient.clone();
+        }
+        let mut powers = self.powers.clone();
+        if power > 0 {
+            powers[i] -= 1;
+        }
+        Monomial::new(coefficient, powers).with_dim(self.dim)
+    }
+
+    fn product_powers(&self, other: &Monomial<R>) -> Vec<usize> {
+        (0..self.dim.max(other.dim))
+            .map(|i| self.power(i) + other.power(i))
+            .collect()
+    }
+
+    fn power(&self, i: usize) -> usize {
+        self.powers.get(i).copied().unwrap_or(0)
+    }
+
     pub fn dim(&self) -> usize {
         self.dim
     }
@@ -45,7 +198,7 @@ impl<R: Ring> Monomial<R> {
         let mut res = self.coefficient.clone();
         for (next_pow, next_x) in self.powers.iter().zip(x.iter()) {
             let x_pow = next_x.clone().pow(*next_pow);
-            res = res + x_pow;
+            res = res * x_pow;
         }
         Ok(res)
     }
@@ -54,33 +207,27 @@ impl<R: Ring> Monomial<R> {
         self.powers.iter().sum()
     }
 
+    /// substitutes other for every variable of self
+    #[deprecated(note = "use Polynomial::substitute")]
     pub fn compose_monomial(self, other: Monomial<R>) -> Monomial<R>
     where
         R: Clone,
     {
-        let new_powers = other
-            .powers
-            .into_iter()
-            .map(|pow| pow * self.deg())
-            .collect();
+        let deg = self.deg();
         Monomial::new(
-            self.coefficient * other.coefficient.pow(self.dim),
-            new_powers,
+            self.coefficient * other.coefficient.pow(deg),
+            other.powers.into_iter().map(|pow| pow * deg).collect(),
         )
     }
 
+    #[deprecated(note = "use HomogeneousPolynomial::compose_morphism")]
     pub fn compose_morphism(self, morphism: ProjectiveMorphism<R>) -> Result<Polynomial<R>, Error>
     where
         R: Field + Clone,
     {
-        let mut res = Polynomial::new(vec![]);
-        for j in 0..self.dim {
-            let nth_coordinate = morphism.nth_coordinate(j)?;
-            for mono in nth_coordinate.monomials() {
-                res = res + self.clone().compose_monomial(mono.clone()).into();
-            }
-        }
-        Ok(res)
+        Ok(HomogeneousPolynomial::from(self)
+            .compose_morphism(&morphism)?
+            .into())
     }
 
     pub fn check_deg(monos: &[Monomial<R>]) -> Result<(), Error> {
@@ -96,13 +243,6 @@ impl<R: Ring> Monomial<R> {
             }),
         }
     }
-
-    pub fn shift_powers(self, n: usize) -> Monomial<R> {
-        Monomial::new(
-            self.coefficient,
-            self.powers.into_iter().map(|pow| pow + n).collect(),
-        )
-    }
 }
 
 impl<R: Ring> From<Monomial<R>> for Polynomial<R> {
@@ -165,10 +305,38 @@ where
 {
     type Output = Monomial<<C as Mul>::Output>;
     fn mul(self, other: Self) -> Self::Output {
-        let mut new_powers = vec![];
-        for i in 0..self.dim {
-            new_powers.push(self.powers[i] + other.powers[i]);
-        }
+        let new_powers = self.product_powers(&other);
         Monomial::new(self.coefficient * other.coefficient, new_powers)
     }
 }
+
+#[cfg(test)]
+mod monomial_tests {
+    use super::Monomial;
+    use crate::{
+        polynomials::{HomogeneousPolynomial, Polynomial},
+        prime_field::PrimeField,
+        projective::ProjectiveMorphism,
+    };
+
+    type F5 = PrimeField<5>;
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_compositions() {
+        let mono = Monomial::new(F5::new(1), vec![1, 2]);
+        let other = Monomial::new(F5::new(2), vec![1, 1]);
+        assert_eq!(
+            mono.clone().compose_monomial(other),
+            Monomial::new(F5::new(3), vec![3, 3])
+        );
+        // swapping the coordinates of P^1
+        let coordinate = |powers| HomogeneousPolynomial::from(Monomial::new(F5::new(1), powers));
+        let morphism =
+            ProjectiveMorphism::new(vec![coordinate(vec![0, 1]), coordinate(vec![1, 0])]).unwrap();
+        assert_eq!(
+            mono.compose_morphism(morphism).unwrap(),
+            Polynomial::from(Monomial::new(F5::new(1), vec![2, 1]))
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use super::Monomial;
+use crate::ring::Ring;
+use std::cmp::Ordering;
+
+/// Monomial orders
+/// compare monomials by their powers only, coefficients are ignored
+/// missing variables are treated as having power 0
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MonomialOrder {
+    /// lexicographic order with X_0 > X_1 > ... > X_n
+    Lex,
+    /// total degree first, ties broken by Lex
+    GrLex,
+    /// total degree first, ties broken by the reversed last variable
+    GRevLex,
+    /// block order eliminating the first k variables
+    /// GRevLex on X_0..X_(k-1), ties broken by GRevLex on the remaining variables
+    /// every monomial containing one of the first k variables is larger
+    /// than all monomials in the remaining variables
+    Elimination(usize),
+}
+
+impl MonomialOrder {
+    pub fn cmp_powers(&self, left: &[usize], right: &[usize]) -> Ordering {
+        match self {
+            MonomialOrder::Lex => lex(left, right),
+            MonomialOrder::GrLex => deg(left).cmp(&deg(right)).then_with(|| lex(left, right)),
+            MonomialOrder::GRevLex => deg(left).cmp(&deg(right)).then_with(|| revlex(left, right)),
+            MonomialOrder::Elimination(k) => {
+                let (left_first, left_rest) = left.split_at((*k).min(left.len()));
+                let (right_first, right_rest) = right.split_at((*k).min(right.len()));
+                MonomialOrder::GRevLex
+                    .cmp_powers(left_first, right_first)
+                    .then_with(|| MonomialOrder::GRevLex.cmp_powers(left_rest, right_rest))
+            }
+        }
+    }
+
+    pub fn cmp<R: Ring>(&self, left: &Monomial<R>, right: &Monomial<R>) -> Ordering {
+        self.cmp_powers(left.exponents(), right.exponents())
+    }
+}
+
+fn power(powers: &[usize], i: usize) -> usize {
+    powers.get(i).copied().unwrap_or(0)
+}
+
+fn deg(powers: &[usize]) -> usize {
+    powers.iter().sum()
+}
+
+fn lex(left: &[usize], right: &[usize]) -> Ordering {
+    (0..left.len().max(right.len()))
+        .map(|i| power(left, i).cmp(&power(right, i)))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// the monomial with the smaller power in the last differing variable is larger
+fn revlex(left: &[usize], right: &[usize]) -> Ordering {
+    (0..left.len().max(right.len()))
+        .rev()
+        .map(|i| power(right, i).cmp(&power(left, i)))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod monomial_order_tests {
+    use super::MonomialOrder;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn lex_first_variable() {
+        let result = MonomialOrder::Lex.cmp_powers(&[1, 0, 0], &[0, 3, 3]);
+        assert_eq!(result, Ordering::Greater)
+    }
+
+    #[test]
+    fn grlex_degree_first() {
+        let result = MonomialOrder::GrLex.cmp_powers(&[1, 0, 0], &[0, 1, 1]);
+        assert_eq!(result, Ordering::Less)
+    }
+
+    #[test]
+    fn grlex_ties() {
+        let result = MonomialOrder::GrLex.cmp_powers(&[1, 0, 1], &[0, 2, 0]);
+        assert_eq!(result, Ordering::Greater)
+    }
+
+    #[test]
+    fn grevlex_ties() {
+        let result = MonomialOrder::GRevLex.cmp_powers(&[1, 0, 1], &[0, 2, 0]);
+        assert_eq!(result, Ordering::Less)
+    }
+
+    #[test]
+    fn elimination_first_block() {
+        let result = MonomialOrder::Elimination(1).cmp_powers(&[1, 0, 0], &[0, 3, 3]);
+        assert_eq!(result, Ordering::Greater)
+    }
+
+    #[test]
+    fn elimination_second_block() {
+        let result = MonomialOrder::Elimination(1).cmp_powers(&[1, 0, 1], &[1, 1, 0]);
+        assert_eq!(result, Ordering::Less)
+    }
+
+    #[test]
+    fn different_lengths() {
+        let result = MonomialOrder::GRevLex.cmp_powers(&[1], &[1, 0, 0]);
+        assert_eq!(result, Ordering::Equal)
+    }
+}
@@ -1,11 +1,26 @@
-use super::{homogeneous_polynomial::HomogeneousPolynomial, monomial::Monomial};
-use crate::{errors::Error, graded_ring::GradedRing, group::AbelianGroup, ring::Ring};
+use super::{
+    homogeneous_polynomial::HomogeneousPolynomial, monomial::Monomial,
+    monomial_order::MonomialOrder,
+};
+use crate::{
+    domain::IntegralDomain,
+    errors::Error,
+    graded_ring::GradedRing,
+    group::AbelianGroup,
+    ring::{CommutativeRing, Ring},
+};
 use std::{
+    cmp::Ordering,
     fmt,
-    ops::{Add, Mul, Neg},
+    ops::{Add, Mul, Neg, Sub},
 };
 
-#[derive(PartialEq, Clone)]
+/// Polynomials in dim variables
+/// monomials are kept in normal form:
+/// all monomials have the same dimension, like terms are combined,
+/// zero terms are removed and terms are sorted descending by GRevLex
+/// so equality can compare terms one by one
+#[derive(Clone, Debug)]
 pub struct Polynomial<C: Ring> {
     dim: usize,
     monomials: Vec<Monomial<C>>,
@@ -13,10 +28,221 @@ pub struct Polynomial<C: Ring> {
 
 impl<C: Ring> Polynomial<C> {
     pub fn new(monomials: Vec<Monomial<C>>) -> Polynomial<C> {
+        let dim = monomials.iter().map(|mono| mono.dim()).max().unwrap_or(0);
+        let mut monomials: Vec<Monomial<C>> = monomials
+            .into_iter()
+            .map(|mono| mono.with_dim(dim))
+            .collect();
+        monomials.sort_by(|mono1, mono2| MonomialOrder::GRevLex.cmp(mono2, mono1));
+
+        let mut combined: Vec<Monomial<C>> = vec![];
+        for mono in monomials.into_iter() {
+            match combined.last_mut() {
+                Some(last) if last.exponents() == mono.exponents() => {
+                    let coefficient = std::mem::replace(&mut last.coefficient, C::zero());
+                    last.coefficient = coefficient + mono.coefficient;
+                }
+                _ => combined.push(mono),
+            }
+        }
+        combined.retain(|mono| !mono.is_zero());
+
         Polynomial {
-            dim: monomials.len(),
-            monomials,
+            dim,
+            monomials: combined,
+        }
+    }
+
+    /// The polynomial X_i in dim variables
+    pub fn variable(i: usize, dim: usize) -> Polynomial<C> {
+        let mut powers = vec![0; dim.max(i + 1)];
+        powers[i] = 1;
+        Monomial::new(C::one(), powers).into()
+    }
+
+    pub fn constant(c: C) -> Polynomial<C> {
+        Monomial::new(c, vec![]).into()
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Extends the polynomial to dim variables
+    pub fn with_dim(self, dim: usize) -> Polynomial<C> {
+        let dim = dim.max(self.dim);
+        Polynomial {
+            dim,
+            monomials: self
+                .monomials
+                .into_iter()
+                .map(|mono| mono.with_dim(dim))
+                .collect(),
+        }
+    }
+
+    /// applies f to all coefficients, e.g. to change the coefficient ring
+    pub fn map_coefficients<S: Ring>(self, f: impl Fn(C) -> S) -> Polynomial<S> {
+        let dim = self.dim;
+        Polynomial::new(
+            self.monomials
+                .into_iter()
+                .map(|mono| mono.map_coefficient(&f))
+                .collect(),
+        )
+        .with_dim(dim)
+    }
+
+    /// substitutes values[i] for X_i
+    pub fn substitute(&self, values: &[Polynomial<C>]) -> Result<Polynomial<C>, Error>
+    where
+        C: Clone,
+    {
+        if self.dim > values.len() {
+            return Err(Error::DimensionMismatch {
+                found: values.len(),
+                expected: self.dim,
+            });
         }
+        let dim = values.iter().map(|val| val.dim()).max().unwrap_or(0);
+        let mut res = Polynomial::zero().with_dim(dim);
+        for mono in self.monomials.iter() {
+            let mut term = Polynomial::constant(mono.coefficient.clone());
+            for (pow, val) in mono.exponents().iter().zip(values.iter()) {
+                term = term * val.clone().pow(*pow);
+            }
+            res = res + term;
+        }
+        Ok(res)
+    }
+
+    /// inserts a new variable X_i, the homogenizing variable
+    /// every term is multiplied with the power of X_i making it of total degree
+    pub fn homogenize(&self, i: usize) -> HomogeneousPolynomial<C>
+    where
+        C: Clone,
+    {
+        let deg = self.total_degree();
+        let dim = self.dim.max(i) + 1;
+        let homo: Polynomial<C> = Polynomial::new(
+            self.monomials
+                .iter()
+                .map(|mono| mono.clone().insert_variable(i, deg - mono.deg()))
+                .collect(),
+        )
+        .with_dim(dim);
+        homo.try_into().unwrap()
+    }
+
+    /// X_i -> X_(i+n)
+    pub fn shift_variables(self, n: usize) -> Polynomial<C> {
+        let dim = self.dim + n;
+        Polynomial::new(
+            self.monomials
+                .into_iter()
+                .map(|mono| mono.shift_variables(n))
+                .collect(),
+        )
+        .with_dim(dim)
+    }
+
+    /// X_i -> X_(i-n)
+    /// None if one of X_0,...,X_(n-1) occurs
+    pub fn unshift_variables(self, n: usize) -> Option<Polynomial<C>> {
+        let dim = self.dim.saturating_sub(n);
+        let monomials = self
+            .monomials
+            .into_iter()
+            .map(|mono| mono.unshift_variables(n))
+            .collect::<Option<Vec<Monomial<C>>>>()?;
+        Some(Polynomial::new(monomials).with_dim(dim))
+    }
+
+    /// checks if none of the first n variables occurs
+    pub fn avoids_variables(&self, n: usize) -> bool {
+        self.monomials
+            .iter()
+            .all(|mono| mono.exponents().iter().take(n).all(|pow| *pow == 0))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.monomials.is_empty()
+    }
+
+    /// monomials without cloning
+    pub fn terms(&self) -> &[Monomial<C>] {
+        &self.monomials
+    }
+
+    pub fn into_terms(self) -> Vec<Monomial<C>> {
+        self.monomials
+    }
+
+    /// largest monomial with respect to a given order
+    /// None for the zero polynomial
+    pub fn leading_term(&self, order: MonomialOrder) -> Option<&Monomial<C>> {
+        self.monomials
+            .iter()
+            .max_by(|mono1, mono2| order.cmp(mono1, mono2))
+    }
+
+    /// maximal degree of all monomials
+    /// the zero polynomial has degree 0
+    pub fn total_degree(&self) -> usize {
+        self.monomials
+            .iter()
+            .map(|mono| mono.deg())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// formal partial derivative d/dX_i
+    pub fn partial_derivative(&self, i: usize) -> Polynomial<C>
+    where
+        C: Clone,
+    {
+        Polynomial::new(
+            self.monomials
+                .iter()
+                .map(|mono| mono.derivative(i))
+                .collect(),
+        )
+        .with_dim(self.dim)
+    }
+
+    /// all partial derivatives d/dX_0,...,d/dX_(dim-1)
+    pub fn gradient(&self) -> Vec<Polynomial<C>>
+    where
+        C: Clone,
+    {
+        (0..self.dim).map(|i| self.partial_derivative(i)).collect()
+    }
+
+    pub fn scale(self, c: C) -> Polynomial<C>
+    where
+        C: Clone,
+    {
+        let dim = self.dim;
+        Polynomial::new(
+            self.monomials
+                .into_iter()
+                .map(|mono| Monomial::new(c.clone(), vec![]) * mono)
+                .collect(),
+        )
+        .with_dim(dim)
+    }
+
+    pub fn mul_monomial(&self, mono: &Monomial<C>) -> Polynomial<C>
+    where
+        C: Clone,
+    {
+        Polynomial::new(
+            self.monomials
+                .iter()
+                .map(|self_mono| mono.clone() * self_mono.clone())
+                .collect(),
+        )
+        .with_dim(self.dim.max(mono.dim()))
     }
 
     pub fn monomials(&self) -> Vec<Monomial<C>>
@@ -41,16 +267,32 @@ impl<C: Ring> Polynomial<C> {
 
         let mut res = C::zero();
         for mono in self.monomials.iter() {
-            let eval_res = mono.eval(x.clone())?;
+            let eval_res = mono.clone().with_dim(self.dim).eval(x.clone())?;
             res = res + eval_res
         }
         Ok(res)
     }
 }
 
+/// polynomials in fewer variables are considered as polynomials in more variables
+/// so the dimension is ignored
+impl<C: Ring> PartialEq for Polynomial<C> {
+    fn eq(&self, other: &Polynomial<C>) -> bool {
+        self.monomials.len() == other.monomials.len()
+            && self
+                .monomials
+                .iter()
+                .zip(other.monomials.iter())
+                .all(|(mono1, mono2)| {
+                    mono1.coefficient == mono2.coefficient
+                        && MonomialOrder::Lex.cmp(mono1, mono2) == Ordering::Equal
+                })
+    }
+}
+
 impl<C: Ring> AbelianGroup for Polynomial<C> {
     fn zero() -> Polynomial<C> {
-        Polynomial::new(vec![Monomial::new(C::zero(), vec![])])
+        Polynomial::new(vec![])
     }
 }
 
@@ -63,12 +305,19 @@ where
     }
 }
 
+impl<C: CommutativeRing + Clone> CommutativeRing for Polynomial<C> {}
+
+impl<C: IntegralDomain + Clone> IntegralDomain for Polynomial<C> {}
+
 impl<C> fmt::Display for Polynomial<C>
 where
     C: fmt::Display,
     C: Ring,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.monomials.is_empty() {
+            return write!(f, "0");
+        }
         let mon_str: Vec<String> = self
             .monomials
             .iter()
@@ -82,7 +331,10 @@ impl<C: Ring> Neg for Polynomial<C> {
     type Output = Self;
     fn neg(self) -> Self {
         let monomials = self.monomials.into_iter().map(|mono| -mono).collect();
-        Polynomial::new(monomials)
+        Polynomial {
+            dim: self.dim,
+            monomials,
+        }
     }
 }
 
@@ -94,23 +346,15 @@ where
     type Output = Polynomial<C>;
     fn add(self, other: Self) -> Self::Output {
         let mut new_monomials = self.monomials;
-        for other_mono in other.monomials.into_iter() {
-            match new_monomials
-                .iter()
-                .enumerate()
-                .find(|(_, mono)| mono.powers() == other_mono.powers())
-            {
-                None => {
-                    new_monomials.push(other_mono);
-                }
-                Some((ind, _)) => {
-                    let old_mono = new_monomials.remove(ind);
-                    let new_poly = old_mono + other_mono;
-                    new_monomials.extend(new_poly.monomials);
-                }
-            }
-        }
-        Polynomial::new(new_monomials)
+        new_monomials.extend(other.monomials);
+        Polynomial::new(new_monomials).with_dim(self.dim.max(other.dim))
+    }
+}
+
+impl<C: Ring> Sub for Polynomial<C> {
+    type Output = Polynomial<C>;
+    fn sub(self, other: Self) -> Self::Output {
+        self + (-other)
     }
 }
 
@@ -125,17 +369,10 @@ where
         let mut new_monomials: Vec<Monomial<C>> = vec![];
         for self_mono in self.monomials.iter() {
             for other_mono in other.monomials.iter() {
-                let mut new_mono = self_mono.clone() * other_mono.clone();
-                if let Some(mono) = new_monomials
-                    .iter()
-                    .find(|mono| mono.powers() == new_mono.powers())
-                {
-                    new_mono.coefficient = new_mono.coefficient + mono.coefficient.clone();
-                }
-                new_monomials.push(new_mono);
+                new_monomials.push(self_mono.clone() * other_mono.clone());
             }
         }
-        Polynomial::new(new_monomials)
+        Polynomial::new(new_monomials).with_dim(self.dim.max(other.dim))
     }
 }
 
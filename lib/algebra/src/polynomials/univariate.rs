@@ -0,0 +1,409 @@
+use super::{Monomial, Polynomial};
+use crate::{
+    arithmetic,
+    domain::{EuclideanDomain, IntegralDomain, UniqueFactorizationDomain},
+    errors::Error,
+    field::Field,
+    group::AbelianGroup,
+    ring::{CommutativeRing, Ring},
+};
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Polynomials in one variable x
+/// coefficients[i] is the coefficient of x^i
+/// trailing zero coefficients are removed, so the zero polynomial has no coefficients
+/// and derived equality compares polynomials
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnivariatePolynomial<K: Ring> {
+    coefficients: Vec<K>,
+}
+
+impl<K: Ring> UnivariatePolynomial<K> {
+    pub fn new(mut coefficients: Vec<K>) -> UnivariatePolynomial<K> {
+        while coefficients.last().is_some_and(|c| *c == K::zero()) {
+            coefficients.pop();
+        }
+        UnivariatePolynomial { coefficients }
+    }
+
+    /// the polynomial x
+    pub fn x() -> UnivariatePolynomial<K> {
+        UnivariatePolynomial::new(vec![K::zero(), K::one()])
+    }
+
+    pub fn constant(c: K) -> UnivariatePolynomial<K> {
+        UnivariatePolynomial::new(vec![c])
+    }
+
+    /// c x^deg
+    pub fn monomial(c: K, deg: usize) -> UnivariatePolynomial<K>
+    where
+        K: Clone,
+    {
+        let mut coefficients = vec![K::zero(); deg];
+        coefficients.push(c);
+        UnivariatePolynomial::new(coefficients)
+    }
+
+    pub fn coefficients(&self) -> &[K] {
+        &self.coefficients
+    }
+
+    /// None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<&K> {
+        self.coefficients.last()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// evaluation at x by Horner's scheme
+    pub fn eval(&self, x: &K) -> K
+    where
+        K: Clone,
+    {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(K::zero(), |res, c| res * x.clone() + c.clone())
+    }
+
+    /// formal derivative
+    pub fn derivative(&self) -> UnivariatePolynomial<K>
+    where
+        K: Clone,
+    {
+        let mut multiple = K::zero();
+        let mut coefficients = vec![];
+        for c in self.coefficients.iter().skip(1) {
+            multiple = multiple + K::one();
+            coefficients.push(multiple.clone() * c.clone());
+        }
+        UnivariatePolynomial::new(coefficients)
+    }
+
+    /// applies f to all coefficients, e.g. to change the coefficient ring
+    pub fn map_coefficients<S: Ring>(self, f: impl Fn(K) -> S) -> UnivariatePolynomial<S> {
+        UnivariatePolynomial::new(self.coefficients.into_iter().map(f).collect())
+    }
+}
+
+impl<K: Field + Clone> UnivariatePolynomial<K> {
+    pub fn scale(self, c: K) -> UnivariatePolynomial<K> {
+        self.map_coefficients(|coeff| coeff * c.clone())
+    }
+
+    /// divides by the leading coefficient
+    /// the zero polynomial stays zero
+    pub fn make_monic(self) -> UnivariatePolynomial<K> {
+        match self.leading_coefficient().cloned() {
+            None => self,
+            Some(lead) => self.scale(lead.inverse()),
+        }
+    }
+
+    pub fn is_monic(&self) -> bool {
+        self.leading_coefficient() == Some(&<K as Field>::one())
+    }
+
+    /// q, r with self = q * divisor + r and deg r < deg divisor
+    pub fn div_rem(
+        &self,
+        divisor: &UnivariatePolynomial<K>,
+    ) -> Result<(UnivariatePolynomial<K>, UnivariatePolynomial<K>), Error> {
+        let divisor_deg = divisor.degree().ok_or(Error::DivisionByZero)?;
+        let lead_inverse = divisor.coefficients[divisor_deg].clone().inverse();
+        let mut rem = self.coefficients.clone();
+        if rem.len() <= divisor_deg {
+            return Ok((UnivariatePolynomial::zero(), self.clone()));
+        }
+        let mut quotient = vec![K::zero(); rem.len() - divisor_deg];
+        for shift in (0..quotient.len()).rev() {
+            let factor = rem[shift + divisor_deg].clone() * lead_inverse.clone();
+            if factor == K::zero() {
+                continue;
+            }
+            for (i, c) in divisor.coefficients.iter().enumerate() {
+                rem[shift + i] = rem[shift + i].clone() + -(factor.clone() * c.clone());
+            }
+            quotient[shift] = factor;
+        }
+        rem.truncate(divisor_deg);
+        Ok((
+            UnivariatePolynomial::new(quotient),
+            UnivariatePolynomial::new(rem),
+        ))
+    }
+
+    /// remainder of the division by divisor
+    pub fn rem(&self, divisor: &UnivariatePolynomial<K>) -> Result<UnivariatePolynomial<K>, Error> {
+        Ok(self.div_rem(divisor)?.1)
+    }
+
+    /// monic greatest common divisor
+    /// the gcd of 0 and 0 is 0
+    pub fn gcd(&self, other: &UnivariatePolynomial<K>) -> UnivariatePolynomial<K> {
+        arithmetic::gcd(self, other)
+    }
+
+    /// extended Euclidean algorithm
+    /// returns (g, s, t) with g = s * self + t * other the monic gcd
+    pub fn xgcd(
+        &self,
+        other: &UnivariatePolynomial<K>,
+    ) -> (
+        UnivariatePolynomial<K>,
+        UnivariatePolynomial<K>,
+        UnivariatePolynomial<K>,
+    ) {
+        arithmetic::xgcd(self, other)
+    }
+
+    /// self^n mod modulus by repeated squaring
+    pub fn pow_mod(
+        &self,
+        mut n: u64,
+        modulus: &UnivariatePolynomial<K>,
+    ) -> Result<UnivariatePolynomial<K>, Error> {
+        let mut base = self.rem(modulus)?;
+        let mut res = UnivariatePolynomial::one().rem(modulus)?;
+        while n > 0 {
+            if n % 2 == 1 {
+                res = (res * base.clone()).rem(modulus)?;
+            }
+            base = (base.clone() * base).rem(modulus)?;
+            n /= 2;
+        }
+        Ok(res)
+    }
+}
+
+impl<K: Ring> AbelianGroup for UnivariatePolynomial<K> {
+    fn zero() -> UnivariatePolynomial<K> {
+        UnivariatePolynomial::new(vec![])
+    }
+}
+
+impl<K: Ring + Clone> Ring for UnivariatePolynomial<K> {
+    fn one() -> UnivariatePolynomial<K> {
+        UnivariatePolynomial::new(vec![K::one()])
+    }
+}
+
+impl<K: CommutativeRing + Clone> CommutativeRing for UnivariatePolynomial<K> {}
+
+impl<K: IntegralDomain + Clone> IntegralDomain for UnivariatePolynomial<K> {}
+
+impl<K: Field + Clone> UniqueFactorizationDomain for UnivariatePolynomial<K> {}
+
+/// polynomial division, normal forms are monic
+impl<K: Field + Clone> EuclideanDomain for UnivariatePolynomial<K> {
+    fn div_rem(
+        &self,
+        divisor: &UnivariatePolynomial<K>,
+    ) -> Result<(UnivariatePolynomial<K>, UnivariatePolynomial<K>), Error> {
+        UnivariatePolynomial::div_rem(self, divisor)
+    }
+
+    fn normal_unit(&self) -> UnivariatePolynomial<K> {
+        match self.leading_coefficient() {
+            None => UnivariatePolynomial::one(),
+            Some(lead) => UnivariatePolynomial::constant(lead.clone().inverse()),
+        }
+    }
+}
+
+impl<K: Ring> Neg for UnivariatePolynomial<K> {
+    type Output = UnivariatePolynomial<K>;
+    fn neg(self) -> UnivariatePolynomial<K> {
+        self.map_coefficients(|c| -c)
+    }
+}
+
+impl<K: Ring> Add for UnivariatePolynomial<K> {
+    type Output = UnivariatePolynomial<K>;
+    fn add(self, other: UnivariatePolynomial<K>) -> UnivariatePolynomial<K> {
+        let (mut long, short) = if self.coefficients.len() >= other.coefficients.len() {
+            (self.coefficients, other.coefficients)
+        } else {
+            (other.coefficients, self.coefficients)
+        };
+        for (i, c) in short.into_iter().enumerate() {
+            let coeff = std::mem::replace(&mut long[i], K::zero());
+            long[i] = coeff + c;
+        }
+        UnivariatePolynomial::new(long)
+    }
+}
+
+impl<K: Ring> Sub for UnivariatePolynomial<K> {
+    type Output = UnivariatePolynomial<K>;
+    fn sub(self, other: UnivariatePolynomial<K>) -> UnivariatePolynomial<K> {
+        self + (-other)
+    }
+}
+
+impl<K: Ring + Clone> Mul for UnivariatePolynomial<K> {
+    type Output = UnivariatePolynomial<K>;
+    fn mul(self, other: UnivariatePolynomial<K>) -> UnivariatePolynomial<K> {
+        if self.is_zero() || other.is_zero() {
+            return UnivariatePolynomial::zero();
+        }
+        let mut coefficients =
+            vec![K::zero(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                let coeff = std::mem::replace(&mut coefficients[i + j], K::zero());
+                coefficients[i + j] = coeff + a.clone() * b.clone();
+            }
+        }
+        UnivariatePolynomial::new(coefficients)
+    }
+}
+
+/// the polynomial in the single variable X_0
+impl<K: Ring> From<UnivariatePolynomial<K>> for Polynomial<K> {
+    fn from(poly: UnivariatePolynomial<K>) -> Polynomial<K> {
+        Polynomial::new(
+            poly.coefficients
+                .into_iter()
+                .enumerate()
+                .map(|(deg, c)| Monomial::new(c, vec![deg]))
+                .collect(),
+        )
+        .with_dim(1)
+    }
+}
+
+/// fails if a variable other than X_0 occurs
+impl<K: Ring + Clone> TryFrom<Polynomial<K>> for UnivariatePolynomial<K> {
+    type Error = Error;
+    fn try_from(poly: Polynomial<K>) -> Result<UnivariatePolynomial<K>, Error> {
+        let mut res = UnivariatePolynomial::zero();
+        for mono in poly.into_terms() {
+            if mono.exponents().iter().skip(1).any(|pow| *pow != 0) {
+                return Err(Error::DimensionMismatch {
+                    found: mono.dim(),
+                    expected: 1,
+                });
+            }
+            let deg = mono.exponents().first().copied().unwrap_or(0);
+            res = res + UnivariatePolynomial::monomial(mono.coefficient, deg);
+        }
+        Ok(res)
+    }
+}
+
+impl<K> fmt::Display for UnivariatePolynomial<K>
+where
+    K: Ring + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| **c != K::zero())
+            .map(|(deg, c)| match deg {
+                0 => format!("{}", c),
+                1 => format!("{}x", c),
+                _ => format!("{}x^{}", c, deg),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+#[cfg(test)]
+mod univariate_tests {
+    use super::UnivariatePolynomial;
+    use crate::{
+        polynomials::{Monomial, Polynomial},
+        prime_field::PrimeField,
+        rational::Rational,
+    };
+
+    fn poly(coefficients: Vec<i64>) -> UnivariatePolynomial<Rational> {
+        UnivariatePolynomial::new(coefficients.into_iter().map(Rational::from).collect())
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        assert_eq!(poly(vec![1, 2, 0, 0]).degree(), Some(1));
+        assert_eq!(poly(vec![0]).degree(), None)
+    }
+
+    #[test]
+    fn division_with_remainder() {
+        // x^3 - 2x + 1 = (x^2 + x - 1)(x - 1) + 0
+        let (q, r) = poly(vec![1, -2, 0, 1]).div_rem(&poly(vec![-1, 1])).unwrap();
+        assert_eq!(q, poly(vec![-1, 1, 1]));
+        assert!(r.is_zero());
+        let (q, r) = poly(vec![1, 0, 1]).div_rem(&poly(vec![0, 2])).unwrap();
+        assert_eq!(
+            q,
+            UnivariatePolynomial::monomial(Rational::new(1, 2).unwrap(), 1)
+        );
+        assert_eq!(r, poly(vec![1]));
+        assert!(poly(vec![1]).div_rem(&poly(vec![])).is_err())
+    }
+
+    #[test]
+    fn extended_gcd() {
+        let f = poly(vec![-1, 0, 1]);
+        let g = poly(vec![1, 2, 1]);
+        let (d, s, t) = f.xgcd(&g);
+        assert_eq!(d, poly(vec![1, 1]));
+        assert_eq!(s * f + t * g, d)
+    }
+
+    #[test]
+    fn coprime_gcd() {
+        let f: UnivariatePolynomial<PrimeField<5>> =
+            UnivariatePolynomial::new(vec![1.into(), 0.into(), 1.into()]);
+        let g = UnivariatePolynomial::new(vec![1.into(), 1.into()]);
+        assert_eq!(f.gcd(&g), UnivariatePolynomial::new(vec![1.into()]))
+    }
+
+    #[test]
+    fn derivative_and_eval() {
+        let f = poly(vec![1, 0, 3, 1]);
+        assert_eq!(f.derivative(), poly(vec![0, 6, 3]));
+        assert_eq!(f.eval(&Rational::from(2)), Rational::from(21))
+    }
+
+    #[test]
+    fn pow_mod_fermat() {
+        // x^5 = x mod x^5 - x over F_5
+        let x: UnivariatePolynomial<PrimeField<5>> = UnivariatePolynomial::x();
+        let modulus = UnivariatePolynomial::monomial(1.into(), 5) - x.clone();
+        assert_eq!(x.pow_mod(5, &modulus).unwrap(), x)
+    }
+
+    #[test]
+    fn multivariate_conversion() {
+        let f = poly(vec![2, 0, 1]);
+        let multivariate = Polynomial::from(f.clone());
+        assert_eq!(multivariate.dim(), 1);
+        assert_eq!(UnivariatePolynomial::try_from(multivariate).unwrap(), f);
+        let y = Polynomial::from(Monomial::new(Rational::from(1), vec![0, 1]));
+        assert!(UnivariatePolynomial::try_from(y).is_err())
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", poly(vec![1, 0, -2, 1])), "1x^3 + -2x^2 + 1")
+    }
+}
@@ -0,0 +1,615 @@
+use super::{factorization::Factorization, UnivariatePolynomial};
+use crate::{
+    arithmetic::gcd, big_integer::BigInteger, errors::Error, prime_field::PrimeField,
+    projective::minors::subsets, rational::Rational, ring::Ring,
+};
+
+/// integer polynomials, coefficients[i] is the coefficient of x^i
+type IntPoly = Vec<i128>;
+
+/// moduli p^k for Hensel lifting stay below this bound
+/// so products of two residues fit into i128
+const MAX_MODULUS: i128 = 1 << 62;
+
+/// primes tried for the modular factorization
+const PRIMES: [u64; 24] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+impl Factorization for UnivariatePolynomial<Rational> {
+    /// Yun's algorithm, valid in characteristic zero
+    /// applied to the primitive integer multiple, the gcds are computed modulo large primes
+    fn squarefree_factorization(
+        &self,
+    ) -> Result<Vec<(UnivariatePolynomial<Rational>, usize)>, Error> {
+        if self.degree().unwrap_or(0) == 0 {
+            return Ok(vec![]);
+        }
+        squarefree_integer(&primitive_part(self)?)?
+            .into_iter()
+            .map(|(g, mult)| Ok((to_rational(&g)?, mult)))
+            .collect()
+    }
+
+    /// Zassenhaus' algorithm
+    /// each squarefree part is factored modulo a small prime,
+    /// the factors are Hensel lifted and recombined to factors over Z
+    /// fails if the coefficient bound of a factor does not fit into 62 bits
+    fn factor(&self) -> Result<Vec<(UnivariatePolynomial<Rational>, usize)>, Error> {
+        if self.degree().unwrap_or(0) == 0 {
+            return Ok(vec![]);
+        }
+        let mut factors = vec![];
+        for (squarefree, mult) in squarefree_integer(&primitive_part(self)?)? {
+            for factor in factor_squarefree(&squarefree)? {
+                factors.push((to_rational(&factor)?, mult));
+            }
+        }
+        factors.sort_by_key(|(factor, mult)| (factor.degree(), *mult));
+        Ok(factors)
+    }
+}
+
+/// the monic rational polynomial, if the coefficients fit into i64
+fn to_rational(f: &[i128]) -> Result<UnivariatePolynomial<Rational>, Error> {
+    let coefficients = f
+        .iter()
+        .map(|c| {
+            i64::try_from(*c)
+                .map(Rational::from)
+                .map_err(|_| Error::RationalOverflow)
+        })
+        .collect::<Result<Vec<Rational>, Error>>()?;
+    Ok(UnivariatePolynomial::new(coefficients).make_monic())
+}
+
+/// integer multiple of f with coprime coefficients and positive leading coefficient
+fn primitive_part(f: &UnivariatePolynomial<Rational>) -> Result<IntPoly, Error> {
+    let integer = |n: &BigInteger| n.to_i128().ok_or(Error::RationalOverflow);
+    let lcm = f.coefficients().iter().try_fold(1i128, |lcm, c| {
+        let den = integer(c.den())?;
+        (lcm / gcd(&lcm, &den))
+            .checked_mul(den)
+            .ok_or(Error::RationalOverflow)
+    })?;
+    let coefficients = f
+        .coefficients()
+        .iter()
+        .map(|c| {
+            integer(c.num())?
+                .checked_mul(lcm / integer(c.den())?)
+                .ok_or(Error::RationalOverflow)
+        })
+        .collect::<Result<IntPoly, Error>>()?;
+    Ok(make_primitive(coefficients))
+}
+
+/// divides by the content, the leading coefficient becomes positive
+fn make_primitive(f: IntPoly) -> IntPoly {
+    let content = f.iter().fold(0, |content, c| gcd(&content, c));
+    if content == 0 {
+        return f;
+    }
+    let sign = if f.last().is_some_and(|lead| *lead < 0) {
+        -1
+    } else {
+        1
+    };
+    f.into_iter().map(|c| sign * c / content).collect()
+}
+
+fn derivative(f: &[i128]) -> Result<IntPoly, Error> {
+    let coefficients = f
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| c.checked_mul(i as i128).ok_or(Error::RationalOverflow))
+        .collect::<Result<IntPoly, Error>>()?;
+    Ok(trim(coefficients))
+}
+
+fn difference(f: &[i128], g: &[i128]) -> Result<IntPoly, Error> {
+    let coefficients = (0..f.len().max(g.len()))
+        .map(|i| {
+            f.get(i)
+                .copied()
+                .unwrap_or(0)
+                .checked_sub(g.get(i).copied().unwrap_or(0))
+                .ok_or(Error::RationalOverflow)
+        })
+        .collect::<Result<IntPoly, Error>>()?;
+    Ok(trim(coefficients))
+}
+
+/// coefficients reduced into [0, p)
+fn reduce(f: &[i128], p: i128) -> IntPoly {
+    trim(f.iter().map(|c| c.rem_euclid(p)).collect())
+}
+
+/// monic gcd modulo the prime p by the euclidean algorithm
+fn gcd_mod(f: &[i128], g: &[i128], p: i128) -> IntPoly {
+    let (mut a, mut b) = (reduce(f, p), reduce(g, p));
+    while !b.is_empty() {
+        let inverse = inverse_mod(*b.last().unwrap(), p);
+        while a.len() >= b.len() {
+            let factor = (a.last().unwrap() * inverse) % p;
+            let shift = a.len() - b.len();
+            for (i, c) in b.iter().enumerate() {
+                a[shift + i] = (a[shift + i] - factor * c).rem_euclid(p);
+            }
+            a = trim(a);
+        }
+        (a, b) = (b, a);
+    }
+    let inverse = inverse_mod(*a.last().unwrap(), p);
+    a.iter().map(|c| (c * inverse) % p).collect()
+}
+
+/// primes below 2^31 in decreasing order
+fn large_primes() -> impl Iterator<Item = i128> {
+    ((1 << 30)..(1i128 << 31)).rev().filter(|n| {
+        n % 2 == 1
+            && (3..)
+                .step_by(2)
+                .take_while(|d| d * d <= *n)
+                .all(|d| n % d != 0)
+    })
+}
+
+/// primitive gcd over Z by the small primes modular algorithm
+/// the images modulo primes of minimal degree are combined by chinese remaindering
+/// until the primitive part divides f and g
+fn modular_gcd(f: &[i128], g: &[i128]) -> Result<IntPoly, Error> {
+    if f.is_empty() || g.is_empty() {
+        return Ok(make_primitive(trim([f, g].concat())));
+    }
+    let lead = gcd(f.last().unwrap(), g.last().unwrap());
+    let mut image: Option<(IntPoly, i128)> = None;
+    for p in large_primes() {
+        if f.last().unwrap() % p == 0 || g.last().unwrap() % p == 0 {
+            continue;
+        }
+        let h: IntPoly = gcd_mod(f, g, p)
+            .iter()
+            .map(|c| (c * lead.rem_euclid(p)) % p)
+            .collect();
+        if h.len() == 1 {
+            return Ok(vec![1]);
+        }
+        let (residues, modulus) = match image {
+            Some((residues, modulus)) if residues.len() == h.len() => {
+                // the combined modulus bounds every intermediate value
+                let combined = modulus.checked_mul(p).ok_or(Error::RationalOverflow)?;
+                let inverse = inverse_mod(modulus, p);
+                let residues = residues
+                    .iter()
+                    .zip(h.iter())
+                    .map(|(a, b)| {
+                        let lift = (b - a).rem_euclid(p) * inverse % p;
+                        modulus
+                            .checked_mul(lift)
+                            .and_then(|shift| shift.checked_add(*a))
+                            .ok_or(Error::RationalOverflow)
+                    })
+                    .collect::<Result<IntPoly, Error>>()?;
+                (residues, combined)
+            }
+            Some((residues, modulus)) if residues.len() < h.len() => (residues, modulus),
+            _ => (h, p),
+        };
+        let candidate = make_primitive(symmetric(&residues, modulus));
+        if candidate.len() == residues.len()
+            && exact_division(f, &candidate).is_some()
+            && exact_division(g, &candidate).is_some()
+        {
+            return Ok(candidate);
+        }
+        image = Some((residues, modulus));
+    }
+    Err(Error::RationalOverflow)
+}
+
+/// Yun's algorithm over Z for a primitive f
+/// the divisions by the primitive gcds are exact by Gauss' lemma
+fn squarefree_integer(f: &[i128]) -> Result<Vec<(IntPoly, usize)>, Error> {
+    let quotient = |f: &[i128], g: &[i128]| exact_division(f, g).ok_or(Error::RationalOverflow);
+    let derivative_f = derivative(f)?;
+    let a = modular_gcd(f, &derivative_f)?;
+    let mut b = quotient(f, &a)?;
+    let c = quotient(&derivative_f, &a)?;
+    let mut d = difference(&c, &derivative(&b)?)?;
+    let mut factors = vec![];
+    let mut i = 1;
+    while b.len() > 1 {
+        let a = modular_gcd(&b, &d)?;
+        b = quotient(&b, &a)?;
+        let c = quotient(&d, &a)?;
+        d = difference(&c, &derivative(&b)?)?;
+        if a.len() > 1 {
+            factors.push((a, i));
+        }
+        i += 1;
+    }
+    Ok(factors)
+}
+
+fn trim(mut f: IntPoly) -> IntPoly {
+    while f.last() == Some(&0) {
+        f.pop();
+    }
+    f
+}
+
+/// product with coefficients reduced modulo m into [0, m)
+fn mul_mod(f: &[i128], g: &[i128], m: i128) -> IntPoly {
+    if f.is_empty() || g.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![0; f.len() + g.len() - 1];
+    for (i, a) in f.iter().enumerate() {
+        for (j, b) in g.iter().enumerate() {
+            let term = (a.rem_euclid(m) * b.rem_euclid(m)) % m;
+            product[i + j] = (product[i + j] + term) % m;
+        }
+    }
+    trim(product)
+}
+
+/// coefficients modulo m in (-m/2, m/2]
+fn symmetric(f: &[i128], m: i128) -> IntPoly {
+    trim(
+        f.iter()
+            .map(|c| {
+                let c = c.rem_euclid(m);
+                if c > m / 2 {
+                    c - m
+                } else {
+                    c
+                }
+            })
+            .collect(),
+    )
+}
+
+/// f / g if g divides f over Z
+/// None as well if a wrong candidate g makes the remainders overflow
+fn exact_division(f: &[i128], g: &[i128]) -> Option<IntPoly> {
+    let deg_g = g.len() - 1;
+    if f.is_empty() {
+        return Some(vec![]);
+    }
+    if f.len() < g.len() {
+        return None;
+    }
+    let lead = g[deg_g];
+    let mut rem = f.to_vec();
+    let mut quotient = vec![0; f.len() - deg_g];
+    for shift in (0..quotient.len()).rev() {
+        let c = rem[shift + deg_g];
+        if c % lead != 0 {
+            return None;
+        }
+        let factor = c / lead;
+        for (i, b) in g.iter().enumerate() {
+            rem[shift + i] = factor
+                .checked_mul(*b)
+                .and_then(|product| rem[shift + i].checked_sub(product))?;
+        }
+        quotient[shift] = factor;
+    }
+    if rem.iter().all(|c| *c == 0) {
+        Some(quotient)
+    } else {
+        None
+    }
+}
+
+fn to_prime_field<const P: u64>(f: &[i128]) -> UnivariatePolynomial<PrimeField<P>> {
+    UnivariatePolynomial::new(
+        f.iter()
+            .map(|c| PrimeField::new(c.rem_euclid(P as i128) as u64))
+            .collect(),
+    )
+}
+
+fn from_prime_field<const P: u64>(f: &UnivariatePolynomial<PrimeField<P>>) -> IntPoly {
+    f.coefficients().iter().map(|c| c.value() as i128).collect()
+}
+
+/// irreducible factors over Z of a primitive squarefree polynomial
+fn factor_squarefree(f: &[i128]) -> Result<Vec<IntPoly>, Error> {
+    if f.len() <= 2 {
+        return Ok(vec![f.to_vec()]);
+    }
+    for p in PRIMES {
+        if let Some(factors) = factor_with_prime(f, p)? {
+            return Ok(factors);
+        }
+    }
+    Err(Error::NoSuitablePrime)
+}
+
+/// dispatches to the prime field of characteristic p
+fn factor_with_prime(f: &[i128], p: u64) -> Result<Option<Vec<IntPoly>>, Error> {
+    match p {
+        3 => zassenhaus::<3>(f),
+        5 => zassenhaus::<5>(f),
+        7 => zassenhaus::<7>(f),
+        11 => zassenhaus::<11>(f),
+        13 => zassenhaus::<13>(f),
+        17 => zassenhaus::<17>(f),
+        19 => zassenhaus::<19>(f),
+        23 => zassenhaus::<23>(f),
+        29 => zassenhaus::<29>(f),
+        31 => zassenhaus::<31>(f),
+        37 => zassenhaus::<37>(f),
+        41 => zassenhaus::<41>(f),
+        43 => zassenhaus::<43>(f),
+        47 => zassenhaus::<47>(f),
+        53 => zassenhaus::<53>(f),
+        59 => zassenhaus::<59>(f),
+        61 => zassenhaus::<61>(f),
+        67 => zassenhaus::<67>(f),
+        71 => zassenhaus::<71>(f),
+        73 => zassenhaus::<73>(f),
+        79 => zassenhaus::<79>(f),
+        83 => zassenhaus::<83>(f),
+        89 => zassenhaus::<89>(f),
+        97 => zassenhaus::<97>(f),
+        _ => Ok(None),
+    }
+}
+
+/// factorization using the prime P
+/// None if P divides the leading coefficient or f is not squarefree modulo P
+fn zassenhaus<const P: u64>(f: &[i128]) -> Result<Option<Vec<IntPoly>>, Error> {
+    let p = P as i128;
+    let lead = *f.last().unwrap();
+    let f_p = to_prime_field::<P>(f);
+    if lead % p == 0 || f_p.gcd(&f_p.derivative()) != UnivariatePolynomial::one() {
+        return Ok(None);
+    }
+    let modular: Vec<UnivariatePolynomial<PrimeField<P>>> =
+        f_p.factor()?.into_iter().map(|(g, _)| g).collect();
+    if modular.len() == 1 {
+        return Ok(Some(vec![f.to_vec()]));
+    }
+
+    // Mignotte bound for the coefficients of lc(f) times a factor
+    let norm = f.iter().map(|c| (*c as f64).powi(2)).sum::<f64>().sqrt();
+    let bound = 2.0 * (lead.abs() as f64) * 2f64.powi(f.len() as i32 - 1) * norm;
+    let mut modulus = p;
+    let mut k = 1;
+    while (modulus as f64) <= bound {
+        modulus = modulus
+            .checked_mul(p)
+            .filter(|m| *m < MAX_MODULUS)
+            .ok_or(Error::HenselBoundTooLarge)?;
+        k += 1;
+    }
+
+    let lifted = hensel_lift::<P>(f, modular, k);
+    Ok(Some(recombine(f.to_vec(), lifted, modulus)))
+}
+
+/// lifts f = lc(f) g_1 ... g_r mod p to a factorization mod p^k
+/// the lifted factors are monic
+fn hensel_lift<const P: u64>(
+    f: &[i128],
+    factors: Vec<UnivariatePolynomial<PrimeField<P>>>,
+    k: usize,
+) -> Vec<IntPoly> {
+    let p = P as i128;
+    let modulus = p.pow(k as u32);
+    let mut rest = f.to_vec();
+    let mut lifted = vec![];
+    for i in 0..factors.len() - 1 {
+        let g = factors[i].clone();
+        let h = factors[i + 1..]
+            .iter()
+            .fold(to_prime_field::<P>(&[*rest.last().unwrap()]), |h, g| {
+                h * g.clone()
+            });
+        let (s, t) = {
+            let (_, s, t) = g.xgcd(&h);
+            (s, t)
+        };
+        let mut big_g = from_prime_field(&g);
+        let mut big_h = from_prime_field(&h);
+        let mut m = p;
+        for _ in 1..k {
+            // e = (rest - G H) / m mod p
+            let product = mul_mod(&big_g, &big_h, m * p);
+            let e: IntPoly = (0..rest.len().max(product.len()))
+                .map(|i| {
+                    let diff =
+                        rest.get(i).copied().unwrap_or(0) - product.get(i).copied().unwrap_or(0);
+                    diff.rem_euclid(m * p) / m
+                })
+                .collect();
+            let e = to_prime_field::<P>(&e);
+            let (q, r) = (t.clone() * e.clone()).div_rem(&g).unwrap();
+            let delta_h = s.clone() * e + q * h.clone();
+            big_g = add_multiple(&big_g, &from_prime_field(&r), m, m * p);
+            big_h = add_multiple(&big_h, &from_prime_field(&delta_h), m, m * p);
+            m *= p;
+        }
+        lifted.push(symmetric(&big_g, modulus));
+        rest = symmetric(&big_h, modulus);
+    }
+    // the remaining factor has leading coefficient lc(f), make it monic
+    let inverse = inverse_mod(*rest.last().unwrap(), modulus);
+    let last: IntPoly = rest
+        .iter()
+        .map(|c| (c.rem_euclid(modulus) * inverse) % modulus)
+        .collect();
+    lifted.push(symmetric(&last, modulus));
+    lifted
+}
+
+/// f + m g with coefficients modulo n
+fn add_multiple(f: &[i128], g: &[i128], m: i128, n: i128) -> IntPoly {
+    trim(
+        (0..f.len().max(g.len()))
+            .map(|i| {
+                (f.get(i).copied().unwrap_or(0) + m * g.get(i).copied().unwrap_or(0)).rem_euclid(n)
+            })
+            .collect(),
+    )
+}
+
+/// inverse of a modulo m, a has to be a unit
+fn inverse_mod(a: i128, m: i128) -> i128 {
+    let (mut r0, mut r1) = (a.rem_euclid(m), m);
+    let (mut s0, mut s1) = (1, 0);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+    }
+    s0.rem_euclid(m)
+}
+
+/// combines lifted modular factors to factors over Z
+/// subsets are tried by increasing size, found factors are divided out
+fn recombine(mut f: IntPoly, mut lifted: Vec<IntPoly>, modulus: i128) -> Vec<IntPoly> {
+    let mut factors = vec![];
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let lead = *f.last().unwrap();
+        let found = subsets(lifted.len(), size).into_iter().find_map(|subset| {
+            let candidate = subset.iter().fold(vec![lead], |product, i| {
+                mul_mod(&product, &lifted[*i], modulus)
+            });
+            let candidate = make_primitive(symmetric(&candidate, modulus));
+            exact_division(&f, &candidate).map(|quotient| (subset, candidate, quotient))
+        });
+        match found {
+            Some((subset, candidate, quotient)) => {
+                factors.push(candidate);
+                f = quotient;
+                lifted = lifted
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| !subset.contains(i))
+                    .map(|(_, g)| g)
+                    .collect();
+            }
+            None => size += 1,
+        }
+    }
+    factors.push(make_primitive(f));
+    factors
+}
+
+#[cfg(test)]
+mod zassenhaus_tests {
+    use super::Factorization;
+    use crate::{errors::Error, polynomials::UnivariatePolynomial, rational::Rational, ring::Ring};
+
+    fn poly(coefficients: Vec<i64>) -> UnivariatePolynomial<Rational> {
+        UnivariatePolynomial::new(coefficients.into_iter().map(Rational::from).collect())
+    }
+
+    fn pow(f: UnivariatePolynomial<Rational>, n: usize) -> UnivariatePolynomial<Rational> {
+        <UnivariatePolynomial<Rational> as Ring>::pow(f, n)
+    }
+
+    #[test]
+    fn squarefree_yun() {
+        let f = pow(poly(vec![1, 1]), 3) * pow(poly(vec![-2, 0, 1]), 2) * poly(vec![5, 1]);
+        let result = f.squarefree_factorization().unwrap();
+        assert_eq!(
+            result,
+            vec![
+                (poly(vec![5, 1]), 1),
+                (poly(vec![-2, 0, 1]), 2),
+                (poly(vec![1, 1]), 3)
+            ]
+        )
+    }
+
+    #[test]
+    fn cyclotomic() {
+        // x^5 - 1 = (x - 1)(x^4 + x^3 + x^2 + x + 1)
+        let factors = poly(vec![-1, 0, 0, 0, 0, 1]).factor().unwrap();
+        assert_eq!(
+            factors,
+            vec![(poly(vec![-1, 1]), 1), (poly(vec![1, 1, 1, 1, 1]), 1)]
+        )
+    }
+
+    #[test]
+    fn rational_roots_and_multiplicity() {
+        // (2x + 1)(x - 3)^2 has leading coefficient 2
+        let f = poly(vec![1, 2]) * pow(poly(vec![-3, 1]), 2);
+        let factors = f.factor().unwrap();
+        let half = UnivariatePolynomial::new(vec![Rational::new(1, 2).unwrap(), 1.into()]);
+        assert_eq!(factors, vec![(half, 1), (poly(vec![-3, 1]), 2)])
+    }
+
+    #[test]
+    fn recombination() {
+        // (x^2 - 2)(x^2 - 3) splits into linear factors modulo many primes
+        let factors = (poly(vec![-2, 0, 1]) * poly(vec![-3, 0, 1]))
+            .factor()
+            .unwrap();
+        assert_eq!(factors.len(), 2);
+        assert!(factors.contains(&(poly(vec![-2, 0, 1]), 1)));
+        assert!(factors.contains(&(poly(vec![-3, 0, 1]), 1)))
+    }
+
+    #[test]
+    fn several_quadratics() {
+        let quadratics = vec![
+            poly(vec![-2, 0, 1]),
+            poly(vec![-3, 0, 1]),
+            poly(vec![-5, 0, 1]),
+            poly(vec![1, 0, 3]),
+        ];
+        let f = quadratics.iter().fold(poly(vec![1]), |f, g| f * g.clone());
+        let factors = f.factor().unwrap();
+        assert_eq!(factors.len(), 4);
+        for g in quadratics.into_iter() {
+            assert!(factors.contains(&(g.make_monic(), 1)))
+        }
+    }
+
+    #[test]
+    fn irreducible_everywhere_reducible() {
+        // x^4 + 1 and x^4 - 10x^2 + 1 are irreducible over Q but reducible modulo every prime
+        assert!(poly(vec![1, 0, 0, 0, 1]).is_irreducible().unwrap());
+        assert!(poly(vec![1, 0, -10, 0, 1]).is_irreducible().unwrap())
+    }
+
+    #[test]
+    fn constant_has_no_factors() {
+        assert!(poly(vec![3]).factor().unwrap().is_empty())
+    }
+
+    #[test]
+    fn large_intermediate_coefficients() {
+        // the rational remainder sequences of this product overflow i64
+        let factors = vec![
+            poly(vec![7, 3, 1]),
+            poly(vec![11, -5, 0, 1]),
+            poly(vec![13, 2, 0, 0, 1]),
+            poly(vec![-17, 0, 4, 0, 0, 1]),
+        ];
+        let f = factors.iter().fold(poly(vec![1]), |f, g| f * g.clone());
+        assert_eq!(f.squarefree_factorization().unwrap(), vec![(f.clone(), 1)]);
+        assert_eq!(
+            f.factor().unwrap(),
+            factors.into_iter().map(|g| (g, 1)).collect::<Vec<_>>()
+        );
+        let square = f.clone() * poly(vec![7, 3, 1]);
+        assert_eq!(square.factor().unwrap().len(), 4)
+    }
+
+    #[test]
+    fn coefficient_bound_too_large() {
+        // the Mignotte bound of a product of many quadratics exceeds 62 bits
+        let f = (1..=16).fold(poly(vec![1]), |f, a| f * poly(vec![a, 0, 1]));
+        assert!(matches!(f.factor(), Err(Error::HenselBoundTooLarge)))
+    }
+}
@@ -0,0 +1,171 @@
+use super::{
+    errors::Error,
+    field::{Field, FiniteField},
+    group::AbelianGroup,
+    ring::Ring,
+};
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// The prime field Z/pZ
+/// P has to be a prime (this is not checked)
+/// elements are represented by 0 <= value < P
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrimeField<const P: u64> {
+    value: u64,
+}
+
+impl<const P: u64> PrimeField<P> {
+    pub fn new(value: u64) -> PrimeField<P> {
+        PrimeField { value: value % P }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// x^n by repeated squaring
+    pub fn pow_mod(self, mut n: u64) -> PrimeField<P> {
+        let mut base = self;
+        let mut res = PrimeField::new(1);
+        while n > 0 {
+            if n % 2 == 1 {
+                res = res * base;
+            }
+            base = base * base;
+            n /= 2;
+        }
+        res
+    }
+}
+
+impl<const P: u64> From<i64> for PrimeField<P> {
+    fn from(n: i64) -> PrimeField<P> {
+        PrimeField {
+            value: n.rem_euclid(P as i64) as u64,
+        }
+    }
+}
+
+impl<const P: u64> fmt::Display for PrimeField<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<const P: u64> Add for PrimeField<P> {
+    type Output = PrimeField<P>;
+    fn add(self, other: PrimeField<P>) -> PrimeField<P> {
+        PrimeField {
+            value: ((self.value as u128 + other.value as u128) % P as u128) as u64,
+        }
+    }
+}
+
+impl<const P: u64> Sub for PrimeField<P> {
+    type Output = PrimeField<P>;
+    fn sub(self, other: PrimeField<P>) -> PrimeField<P> {
+        self + (-other)
+    }
+}
+
+impl<const P: u64> Neg for PrimeField<P> {
+    type Output = PrimeField<P>;
+    fn neg(self) -> PrimeField<P> {
+        PrimeField {
+            value: (P - self.value) % P,
+        }
+    }
+}
+
+impl<const P: u64> Mul for PrimeField<P> {
+    type Output = PrimeField<P>;
+    fn mul(self, other: PrimeField<P>) -> PrimeField<P> {
+        PrimeField {
+            value: ((self.value as u128 * other.value as u128) % P as u128) as u64,
+        }
+    }
+}
+
+/// panics when dividing by zero
+impl<const P: u64> Div for PrimeField<P> {
+    type Output = PrimeField<P>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: PrimeField<P>) -> PrimeField<P> {
+        self * other.inverse()
+    }
+}
+
+impl<const P: u64> AbelianGroup for PrimeField<P> {
+    fn zero() -> PrimeField<P> {
+        PrimeField { value: 0 }
+    }
+}
+
+impl<const P: u64> Ring for PrimeField<P> {
+    fn one() -> PrimeField<P> {
+        PrimeField::new(1)
+    }
+}
+
+impl<const P: u64> Field for PrimeField<P> {
+    fn one() -> PrimeField<P> {
+        PrimeField::new(1)
+    }
+
+    /// Fermat's little theorem x^(p-2) = x^-1
+    fn inverse(self) -> PrimeField<P> {
+        if self.value == 0 {
+            panic!("{}", Error::DivisionByZero)
+        }
+        self.pow_mod(P - 2)
+    }
+}
+
+impl<const P: u64> FiniteField for PrimeField<P> {
+    fn characteristic() -> u64 {
+        P
+    }
+
+    fn order() -> u64 {
+        P
+    }
+
+    fn elements() -> Vec<PrimeField<P>> {
+        (0..P).map(PrimeField::new).collect()
+    }
+}
+
+#[cfg(test)]
+mod prime_field_tests {
+    use super::PrimeField;
+    use crate::field::Field;
+
+    type F7 = PrimeField<7>;
+
+    #[test]
+    fn from_negative() {
+        let result: F7 = (-1).into();
+        assert_eq!(result, F7::new(6))
+    }
+
+    #[test]
+    fn mul_wraps() {
+        let result = F7::new(3) * F7::new(5);
+        assert_eq!(result, F7::new(1))
+    }
+
+    #[test]
+    fn inverse() {
+        let result = F7::new(3).inverse();
+        assert_eq!(result, F7::new(5))
+    }
+
+    #[test]
+    fn div() {
+        let result = F7::new(2) / F7::new(4);
+        assert_eq!(result, F7::new(4))
+    }
+}
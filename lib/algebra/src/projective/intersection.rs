@@ -0,0 +1,213 @@
+use super::{ProjectivePoint, ProjectiveScheme};
+use crate::{
+    errors::Error,
+    field::{Field, FiniteField},
+    ideal::PolynomialIdeal,
+    polynomials::{Monomial, Polynomial},
+};
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// codimension in the ambient projective space
+    /// None for the empty scheme
+    pub fn codim(&self) -> Option<usize> {
+        self.dim().map(|dim| self.num_variables() - 1 - dim)
+    }
+
+    /// checks if the ideal is minimally generated by codim many forms
+    pub fn is_complete_intersection(&self) -> bool {
+        self.codim()
+            .is_some_and(|codim| self.betti_table().total(1) == codim)
+    }
+
+    /// length of the local ring at a point of a zero dimensional scheme, 0 off the scheme
+    /// the primary component at pt is I + m^N for every N >= deg,
+    /// so its degree is the multiplicity
+    pub fn multiplicity_at(&self, pt: &ProjectivePoint<K>) -> Result<usize, Error> {
+        if pt.dim() != self.num_variables() {
+            return Err(Error::DimensionMismatch {
+                found: pt.dim(),
+                expected: self.num_variables(),
+            });
+        }
+        match self.dim() {
+            None => return Ok(0),
+            Some(0) => {}
+            Some(dim) => {
+                return Err(Error::DimensionMismatch {
+                    found: dim,
+                    expected: 0,
+                })
+            }
+        }
+        let power = point_ideal_power(pt, self.degree() as usize);
+        let series = self.ideal().sum(&power).hilbert_series();
+        match series.krull_dimension() {
+            Some(1) => Ok(series.degree() as usize),
+            _ => Ok(0),
+        }
+    }
+
+    /// intersection multiplicity of X and Y at a point of a zero dimensional intersection
+    /// the length of the local ring of X ∩ Y at the point
+    pub fn intersection_multiplicity(
+        &self,
+        other: &ProjectiveScheme<K>,
+        pt: &ProjectivePoint<K>,
+    ) -> Result<usize, Error> {
+        let intersection = self.intersect(other)?;
+        if intersection.dim().is_some_and(|dim| dim > 0) {
+            return Err(Error::ImproperIntersection);
+        }
+        intersection.multiplicity_at(pt)
+    }
+
+    /// Bezout's theorem deg(X ∩ Y) = deg X deg Y
+    /// for complete intersections X and Y meeting in codimension codim X + codim Y
+    pub fn verify_bezout(&self, other: &ProjectiveScheme<K>) -> Result<bool, Error> {
+        let (Some(codim), Some(other_codim)) = (self.codim(), other.codim()) else {
+            return Err(Error::NotCompleteIntersection);
+        };
+        if !self.is_complete_intersection() || !other.is_complete_intersection() {
+            return Err(Error::NotCompleteIntersection);
+        }
+        let intersection = self.intersect(other)?;
+        if intersection.codim() != Some(codim + other_codim) {
+            return Err(Error::ImproperIntersection);
+        }
+        Ok(intersection.degree() == self.degree() * other.degree())
+    }
+}
+
+impl<K: FiniteField + Clone> ProjectiveScheme<K> {
+    /// rational points of a zero dimensional intersection with their multiplicities
+    /// the sum is at most deg(X ∩ Y), with equality if all points are rational
+    pub fn intersection_multiplicities(
+        &self,
+        other: &ProjectiveScheme<K>,
+    ) -> Result<Vec<(ProjectivePoint<K>, usize)>, Error> {
+        let intersection = self.intersect(other)?;
+        if intersection.dim().is_some_and(|dim| dim > 0) {
+            return Err(Error::ImproperIntersection);
+        }
+        intersection
+            .rational_points()
+            .into_iter()
+            .map(|pt| {
+                let multiplicity = intersection.multiplicity_at(&pt)?;
+                Ok((pt, multiplicity))
+            })
+            .collect()
+    }
+}
+
+/// N-th power of the ideal of a point
+/// generated by the products of N of the linear forms p_k X_j - p_j X_k for a fixed p_k != 0
+fn point_ideal_power<K: Field + Clone>(
+    pt: &ProjectivePoint<K>,
+    power: usize,
+) -> PolynomialIdeal<K> {
+    let dim = pt.dim();
+    let coordinates = pt.clone().as_arr();
+    let k = coordinates
+        .iter()
+        .position(|c| *c != K::zero())
+        .expect("projective points are nonzero");
+    let linear_forms: Vec<Polynomial<K>> = (0..dim)
+        .filter(|j| *j != k)
+        .map(|j| {
+            Polynomial::variable(j, dim).scale(coordinates[k].clone())
+                - Polynomial::variable(k, dim).scale(coordinates[j].clone())
+        })
+        .collect();
+    let generators = Monomial::<i64>::all_of_degree(linear_forms.len(), power)
+        .into_iter()
+        .map(|mono| {
+            mono.powers().into_iter().zip(linear_forms.iter()).fold(
+                Polynomial::constant(<K as Field>::one()).with_dim(dim),
+                |acc, (exponent, form)| (0..exponent).fold(acc, |acc, _| acc * form.clone()),
+            )
+        })
+        .collect();
+    PolynomialIdeal::with_dim(generators, dim)
+}
+
+#[cfg(test)]
+mod intersection_tests {
+    use crate::{
+        errors::Error,
+        polynomials::{HomogeneousPolynomial, Monomial},
+        prime_field::PrimeField,
+        projective::{ProjectiveMorphism, ProjectivePoint, ProjectiveScheme},
+        rational::Rational,
+        test_helpers::{point_over, scheme_over},
+    };
+
+    type F7 = PrimeField<7>;
+
+    fn conic() -> ProjectiveScheme<F7> {
+        scheme_over(vec![vec![(1, vec![0, 1, 1]), (-1, vec![2, 0, 0])]])
+    }
+
+    #[test]
+    fn tangent_line() {
+        // the line y = 0 is tangent to y z = x^2 at [0:0:1]
+        let line = scheme_over(vec![vec![(1, vec![0, 1, 0])]]);
+        assert_eq!(
+            conic().intersection_multiplicities(&line).unwrap(),
+            vec![(point_over(vec![0, 0, 1]), 2)]
+        );
+        assert!(conic().verify_bezout(&line).unwrap());
+        // the line x = 0 meets the conic transversally in two points
+        let secant = scheme_over(vec![vec![(1, vec![1, 0, 0])]]);
+        let multiplicities = conic().intersection_multiplicities(&secant).unwrap();
+        assert_eq!(multiplicities.len(), 2);
+        assert!(multiplicities.iter().all(|(_, mult)| *mult == 1));
+        assert_eq!(
+            conic()
+                .intersection_multiplicity(&secant, &point_over(vec![1, 1, 1]))
+                .unwrap(),
+            0
+        )
+    }
+
+    #[test]
+    fn improper_intersections() {
+        assert!(matches!(
+            conic().verify_bezout(&conic()),
+            Err(Error::ImproperIntersection)
+        ));
+        let twisted = ProjectiveMorphism::<Rational>::veronese_embedding(1, 3).image();
+        let plane = ProjectiveScheme::new(vec![HomogeneousPolynomial::from(Monomial::new(
+            Rational::from(1),
+            vec![1, 0, 0, 0],
+        ))])
+        .unwrap();
+        assert!(matches!(
+            twisted.verify_bezout(&plane),
+            Err(Error::NotCompleteIntersection)
+        ));
+        // the plane X_0 = 0 meets the twisted cubic only at [0:0:0:1], with multiplicity 3
+        let intersection = twisted.intersect(&plane).unwrap();
+        assert_eq!(intersection.dim(), Some(0));
+        assert_eq!(intersection.degree(), 3);
+        let pt = ProjectivePoint::new(vec![0.into(), 0.into(), 0.into(), 1.into()]).unwrap();
+        assert_eq!(twisted.intersection_multiplicity(&plane, &pt).unwrap(), 3)
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        // two cubics through the origin of the chart z = 1
+        let cubic = scheme_over(vec![vec![(1, vec![0, 2, 1]), (-1, vec![3, 0, 0])]]);
+        let other = scheme_over(vec![vec![(1, vec![2, 0, 1]), (-1, vec![0, 3, 0])]]);
+        assert!(cubic.verify_bezout(&other).unwrap());
+        assert_eq!(
+            cubic
+                .intersection_multiplicity(&other, &point_over(vec![0, 0, 1]))
+                .unwrap(),
+            4
+        );
+        let union = cubic.union(&conic()).unwrap();
+        assert_eq!(union.degree(), 5);
+        assert_eq!(union.intersect(&conic()).unwrap().degree(), 2)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{field::Field, group::AbelianGroup, polynomials::Polynomial};
+
+/// all k-element subsets of 0..n in increasing order
+pub(crate) fn subsets(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+    if n < k {
+        return vec![];
+    }
+    let mut res = subsets(n - 1, k);
+    for mut subset in subsets(n - 1, k - 1) {
+        subset.push(n - 1);
+        res.push(subset);
+    }
+    res
+}
+
+/// determinant by Laplace expansion along the first row
+pub(crate) fn determinant<K: Field + Clone>(matrix: &[Vec<Polynomial<K>>]) -> Polynomial<K> {
+    if matrix.is_empty() {
+        return Polynomial::constant(<K as Field>::one());
+    }
+    let mut det = Polynomial::zero();
+    for (j, entry) in matrix[0].iter().enumerate() {
+        if entry.is_zero() {
+            continue;
+        }
+        let minor: Vec<Vec<Polynomial<K>>> = matrix[1..]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != j)
+                    .map(|(_, elem)| elem.clone())
+                    .collect()
+            })
+            .collect();
+        let term = entry.clone() * determinant(&minor);
+        det = if j % 2 == 0 { det + term } else { det - term };
+    }
+    det
+}
@@ -1,7 +1,12 @@
+pub mod intersection;
+pub(crate) mod minors;
 pub mod morphism;
 pub mod point;
 pub mod scheme;
+pub mod singular;
+pub mod zeta;
 
 pub use morphism::ProjectiveMorphism;
 pub use point::ProjectivePoint;
 pub use scheme::ProjectiveScheme;
+pub use zeta::ZetaFunction;
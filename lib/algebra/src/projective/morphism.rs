@@ -1,11 +1,19 @@
-use super::ProjectivePoint;
+use super::{
+    minors::{determinant, subsets},
+    ProjectivePoint, ProjectiveScheme,
+};
 use crate::{
     errors::Error,
     field::Field,
-    polynomials::{HomogeneousPolynomial, Monomial},
+    ideal::PolynomialIdeal,
+    matrix::Matrix,
+    polynomials::{HomogeneousPolynomial, Monomial, Polynomial},
 };
 
-#[derive(Clone)]
+/// Morphism P^(dim_domain-1) -> P^(dim_codomain-1) given by homogeneous coordinate functions
+/// of the same degree
+/// equality compares coordinate functions, not the maps up to scaling
+#[derive(Clone, Debug, PartialEq)]
 pub struct ProjectiveMorphism<K: Field> {
     dim_domain: usize,
     dim_codomain: usize,
@@ -28,6 +36,113 @@ where
         })
     }
 
+    /// the identity of P^(n-1)
+    pub fn identity(n: usize) -> ProjectiveMorphism<K>
+    where
+        K: Clone,
+    {
+        ProjectiveMorphism::from_matrix(&Matrix::identity(n)).unwrap()
+    }
+
+    /// linear morphism X -> AX
+    /// row i of the matrix contains the coefficients of the i-th coordinate function
+    /// rows may not be zero
+    pub fn from_matrix(matrix: &Matrix<K>) -> Result<ProjectiveMorphism<K>, Error>
+    where
+        K: Clone,
+    {
+        let dim = matrix.num_cols();
+        let mut coordinate_functions = vec![];
+        for row in matrix.rows() {
+            let terms = row
+                .iter()
+                .enumerate()
+                .map(|(j, coeff)| {
+                    let mut powers = vec![0; dim];
+                    powers[j] = 1;
+                    Monomial::new(coeff.clone(), powers)
+                })
+                .collect();
+            coordinate_functions.push(HomogeneousPolynomial::new(terms)?.with_dim(dim));
+        }
+        ProjectiveMorphism::new(coordinate_functions)
+    }
+
+    /// degree of the coordinate functions
+    pub fn deg(&self) -> usize {
+        self.coordinate_functions
+            .first()
+            .map(|fun| fun.deg())
+            .unwrap_or(0)
+    }
+
+    /// coefficient matrix of a linear morphism
+    /// None if the coordinate functions are not linear
+    pub fn matrix(&self) -> Option<Matrix<K>>
+    where
+        K: Clone,
+    {
+        if self.deg() != 1 {
+            return None;
+        }
+        let rows = self
+            .coordinate_functions
+            .iter()
+            .map(|fun| {
+                let mut row = vec![K::zero(); self.dim_domain];
+                for mono in fun.monomials() {
+                    let j = mono.exponents().iter().position(|pow| *pow == 1).unwrap();
+                    row[j] = mono.coefficient;
+                }
+                row
+            })
+            .collect();
+        Matrix::new(rows).ok()
+    }
+
+    /// inverse of a linear automorphism, an element of PGL
+    pub fn inverse(&self) -> Result<ProjectiveMorphism<K>, Error>
+    where
+        K: Clone,
+    {
+        let inverse = self.matrix().ok_or(Error::NotInvertible)?.inverse()?;
+        ProjectiveMorphism::from_matrix(&inverse)
+    }
+
+    /// self after other, X -> self(other(X))
+    pub fn compose(&self, other: &ProjectiveMorphism<K>) -> Result<ProjectiveMorphism<K>, Error>
+    where
+        K: Clone,
+    {
+        if other.dim_codomain != self.dim_domain {
+            return Err(Error::DimensionMismatch {
+                found: other.dim_codomain,
+                expected: self.dim_domain,
+            });
+        }
+        let coordinate_functions = self
+            .coordinate_functions
+            .iter()
+            .map(|fun| fun.clone().compose_morphism(other))
+            .collect::<Result<Vec<HomogeneousPolynomial<K>>, Error>>()?;
+        ProjectiveMorphism::new(coordinate_functions)
+    }
+
+    pub fn coordinate_functions(&self) -> Vec<HomogeneousPolynomial<K>>
+    where
+        K: Clone,
+    {
+        self.coordinate_functions.clone()
+    }
+
+    pub fn dim_domain(&self) -> usize {
+        self.dim_domain
+    }
+
+    pub fn dim_codomain(&self) -> usize {
+        self.dim_codomain
+    }
+
     pub fn eval(&self, pt: ProjectivePoint<K>) -> Result<ProjectivePoint<K>, Error>
     where
         K: Clone,
@@ -63,18 +178,346 @@ where
     }
 
     /// Segre embedding
-    /// P^n x P^m -> P^(n-1)(m-1)-1
+    /// P^n x P^m -> P^((n+1)(m+1)-1)
     /// [X0:...:Xn] x [Y0:...:Ym] -> [X0Y0:X0Y1:...:X0Ym:...:XnYm]
+    /// the domain has the n+m+2 variables X0,...,Xn,Y0,...,Ym
     pub fn segre_embedding(n: usize, m: usize) -> ProjectiveMorphism<K> {
-        let mut coordinate_functions = vec![];
-        for x_ind in 0..=n {
-            for y_ind in 0..=m {
-                let mut powers = vec![0; (n - 1) * (m - 1) - 1];
-                powers[x_ind * m + y_ind] = 1;
-                let mono = Monomial::new(<K as Field>::one(), powers);
-                coordinate_functions.push(mono.into());
-            }
+        ProjectiveMorphism::multi_segre_embedding(&[n, m])
+    }
+
+    /// Segre embedding of P^n_1 x ... x P^n_k
+    /// coordinates are all products of one variable of each factor, in lexicographic order
+    pub fn multi_segre_embedding(dims: &[usize]) -> ProjectiveMorphism<K> {
+        let num_variables: usize = dims.iter().map(|n| n + 1).sum();
+        let mut products = vec![vec![0; num_variables]];
+        let mut offset = 0;
+        for n in dims.iter() {
+            products = products
+                .into_iter()
+                .flat_map(|powers| {
+                    (0..=*n).map(move |i| {
+                        let mut powers = powers.clone();
+                        powers[offset + i] = 1;
+                        powers
+                    })
+                })
+                .collect();
+            offset += n + 1;
+        }
+        ProjectiveMorphism::monomial_map(products)
+    }
+
+    /// degree d Veronese embedding
+    /// P^n -> P^(binom(n+d,d)-1)
+    /// coordinates are all monomials of degree d, descending in Lex
+    pub fn veronese_embedding(n: usize, d: usize) -> ProjectiveMorphism<K> {
+        let monomials = Monomial::<K>::all_of_degree(n + 1, d)
+            .into_iter()
+            .map(|mono| mono.powers())
+            .collect();
+        ProjectiveMorphism::monomial_map(monomials)
+    }
+
+    /// linear projection P^n --> P^(n-1) from a point
+    /// given by the linear forms X_j - p_j X_i for j != i
+    /// where i is the first nonzero coordinate of the normalized point p
+    /// this is undefined at p itself
+    pub fn linear_projection(center: &ProjectivePoint<K>) -> ProjectiveMorphism<K>
+    where
+        K: Clone,
+    {
+        let dim = center.dim();
+        let center = center.clone().normalize().as_arr();
+        let i = center.iter().position(|c| *c != K::zero()).unwrap();
+        let coordinate_functions = (0..dim)
+            .filter(|j| *j != i)
+            .map(|j| {
+                let mut x_j = vec![0; dim];
+                x_j[j] = 1;
+                let mut x_i = vec![0; dim];
+                x_i[i] = 1;
+                HomogeneousPolynomial::new(vec![
+                    Monomial::new(<K as Field>::one(), x_j),
+                    Monomial::new(-center[j].clone(), x_i),
+                ])
+                .unwrap()
+                .with_dim(dim)
+            })
+            .collect();
+        ProjectiveMorphism::new(coordinate_functions).unwrap()
+    }
+
+    /// Pluecker embedding of the Grassmannian of k-planes in K^n
+    /// the domain is the space of k x n matrices with entries X_(i*n+j)
+    /// the coordinates are the maximal minors, ordered lexicographically by their columns
+    /// this is only defined on matrices of full rank
+    pub fn plucker_embedding(k: usize, n: usize) -> ProjectiveMorphism<K>
+    where
+        K: Clone,
+    {
+        let coordinate_functions = subsets(n, k)
+            .into_iter()
+            .map(|cols| {
+                let minor: Vec<Vec<Polynomial<K>>> = (0..k)
+                    .map(|i| {
+                        cols.iter()
+                            .map(|j| Polynomial::variable(i * n + j, k * n))
+                            .collect()
+                    })
+                    .collect();
+                determinant(&minor).try_into().unwrap()
+            })
+            .collect();
+        ProjectiveMorphism::new(coordinate_functions).unwrap()
+    }
+
+    /// closure of the image of the morphism
+    pub fn image(&self) -> ProjectiveScheme<K>
+    where
+        K: Clone,
+    {
+        let ideal = self.image_ideal(&PolynomialIdeal::zero(self.dim_domain));
+        ProjectiveScheme::from_ideal(self.dim_codomain, &ideal).unwrap()
+    }
+
+    /// ideal of the closure of the image of V(domain)
+    /// the kernel of K[Y]/J -> K[X]/domain, Y_j -> f_j
+    /// computed by eliminating X from domain + <Y_j - f_j>
+    pub(crate) fn image_ideal(&self, domain: &PolynomialIdeal<K>) -> PolynomialIdeal<K>
+    where
+        K: Clone,
+    {
+        let num_variables = self.dim_domain + self.dim_codomain;
+        let mut generators: Vec<Polynomial<K>> = domain
+            .generators()
+            .into_iter()
+            .map(|gen| gen.with_dim(num_variables))
+            .collect();
+        for (j, fun) in self.coordinate_functions.iter().enumerate() {
+            let y_j = Polynomial::variable(self.dim_domain + j, num_variables);
+            generators.push(y_j - Polynomial::from(fun.clone()));
         }
+        PolynomialIdeal::with_dim(generators, num_variables).eliminate(self.dim_domain)
+    }
+
+    pub(crate) fn monomial_map(powers: Vec<Vec<usize>>) -> ProjectiveMorphism<K> {
+        let coordinate_functions = powers
+            .into_iter()
+            .map(|powers| Monomial::new(<K as Field>::one(), powers).into())
+            .collect();
         ProjectiveMorphism::new(coordinate_functions).unwrap()
     }
 }
+
+#[cfg(test)]
+mod morphism_tests {
+    use super::ProjectiveMorphism;
+    use crate::{
+        matrix::Matrix,
+        projective::ProjectiveScheme,
+        rational::Rational,
+        test_helpers::{homogeneous, point},
+    };
+
+    fn twisted_cubic() -> ProjectiveScheme<Rational> {
+        ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0, 1, 0]), (-1, vec![0, 2, 0, 0])]),
+            homogeneous(vec![(1, vec![1, 0, 0, 1]), (-1, vec![0, 1, 1, 0])]),
+            homogeneous(vec![(1, vec![0, 1, 0, 1]), (-1, vec![0, 0, 2, 0])]),
+        ])
+        .unwrap()
+    }
+
+    fn quadric_surface() -> ProjectiveScheme<Rational> {
+        ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![1, 0, 0, 1]),
+            (-1, vec![0, 1, 1, 0]),
+        ])])
+        .unwrap()
+    }
+
+    #[test]
+    fn segre_dimensions() {
+        let segre = ProjectiveMorphism::<Rational>::segre_embedding(1, 2);
+        assert_eq!(segre.dim_domain(), 5);
+        assert_eq!(segre.dim_codomain(), 6)
+    }
+
+    #[test]
+    fn segre_eval() {
+        let segre = ProjectiveMorphism::segre_embedding(1, 1);
+        let result = segre.eval(point(vec![1, 2, 3, 5])).unwrap();
+        assert_eq!(result, point(vec![3, 5, 6, 10]))
+    }
+
+    #[test]
+    fn segre_image_quadric() {
+        let image = ProjectiveMorphism::segre_embedding(1, 1).image();
+        assert_eq!(image.ideal(), quadric_surface().ideal())
+    }
+
+    #[test]
+    fn veronese_image_twisted_cubic() {
+        let image = ProjectiveMorphism::veronese_embedding(1, 3).image();
+        assert_eq!(image.ideal(), twisted_cubic().ideal())
+    }
+
+    #[test]
+    fn veronese_surface() {
+        let image = ProjectiveMorphism::<Rational>::veronese_embedding(2, 2).image();
+        assert_eq!(image.num_variables(), 6);
+        assert_eq!(image.ideal_generators().len(), 6);
+        assert_eq!(image.dim(), Some(2));
+        assert_eq!(image.degree(), 4)
+    }
+
+    #[test]
+    fn projection_from_point() {
+        let projection = ProjectiveMorphism::linear_projection(&point(vec![0, 0, 0, 1]));
+        assert_eq!(projection.dim_codomain(), 3);
+        let result = projection.eval(point(vec![1, 2, 4, 8])).unwrap();
+        assert_eq!(result, point(vec![1, 2, 4]));
+        assert!(projection.eval(point(vec![0, 0, 0, 2])).is_err())
+    }
+
+    #[test]
+    fn projection_vanishes_at_center() {
+        let center = point(vec![1, 2, 3]);
+        let projection = ProjectiveMorphism::linear_projection(&center);
+        let result = projection.eval(point(vec![1, 3, 3])).unwrap();
+        assert_eq!(result, point(vec![1, 0]));
+        assert!(projection.eval(center).is_err())
+    }
+
+    #[test]
+    fn plucker_quadric() {
+        // Gr(2,4) is the quadric p01 p23 - p02 p13 + p03 p12 in P^5
+        let grassmannian = ProjectiveScheme::<Rational>::grassmannian(2, 4);
+        let expected = ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![1, 0, 0, 0, 0, 1]),
+            (-1, vec![0, 1, 0, 0, 1, 0]),
+            (1, vec![0, 0, 1, 1, 0, 0]),
+        ])])
+        .unwrap();
+        assert_eq!(grassmannian.ideal(), expected.ideal());
+        assert_eq!(grassmannian.dim(), Some(4))
+    }
+
+    #[test]
+    fn product_of_lines() {
+        let line = ProjectiveScheme::<Rational>::projective_space(2);
+        let product = line
+            .product(&ProjectiveScheme::projective_space(2))
+            .unwrap();
+        assert_eq!(product.ideal(), quadric_surface().ideal())
+    }
+
+    #[test]
+    fn product_point_line() {
+        let point = ProjectiveScheme::new(vec![homogeneous(vec![(1, vec![0, 1])])]).unwrap();
+        let product = point
+            .product(&ProjectiveScheme::projective_space(2))
+            .unwrap();
+        assert_eq!(product.dim(), Some(1));
+        assert_eq!(product.degree(), 1)
+    }
+
+    fn matrix(rows: Vec<Vec<i64>>) -> ProjectiveMorphism<Rational> {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Rational::from).collect())
+            .collect();
+        ProjectiveMorphism::from_matrix(&Matrix::new(rows).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn compose_with_identity() {
+        let veronese = ProjectiveMorphism::<Rational>::veronese_embedding(1, 2);
+        let result = veronese.compose(&ProjectiveMorphism::identity(2)).unwrap();
+        assert_eq!(result, veronese);
+        let result = ProjectiveMorphism::identity(3).compose(&veronese).unwrap();
+        assert_eq!(result, veronese)
+    }
+
+    #[test]
+    fn compose_projection_veronese() {
+        // projecting the twisted cubic from [0:0:0:1] gives the conic parametrization
+        let projection = ProjectiveMorphism::linear_projection(&point(vec![0, 0, 0, 1]));
+        let composed = projection
+            .compose(&ProjectiveMorphism::veronese_embedding(1, 3))
+            .unwrap();
+        assert_eq!(composed.deg(), 3);
+        let result = composed.eval(point(vec![1, 2])).unwrap();
+        assert_eq!(result, point(vec![1, 2, 4]))
+    }
+
+    #[test]
+    fn compose_dimension_mismatch() {
+        let veronese = ProjectiveMorphism::<Rational>::veronese_embedding(1, 2);
+        assert!(veronese.compose(&veronese).is_err())
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let morphism = matrix(vec![vec![1, 1, 0], vec![0, 1, 0], vec![2, 0, 1]]);
+        let inverse = morphism.inverse().unwrap();
+        let expected = matrix(vec![vec![1, -1, 0], vec![0, 1, 0], vec![-2, 2, 1]]);
+        assert_eq!(inverse, expected);
+        let result = morphism.compose(&inverse).unwrap();
+        assert_eq!(result, ProjectiveMorphism::identity(3))
+    }
+
+    #[test]
+    fn singular_matrix() {
+        let morphism = matrix(vec![vec![1, 2], vec![2, 4]]);
+        assert!(morphism.inverse().is_err());
+        let veronese = ProjectiveMorphism::<Rational>::veronese_embedding(1, 1);
+        assert!(veronese.compose(&morphism).unwrap().matrix().is_some());
+        assert!(ProjectiveMorphism::<Rational>::veronese_embedding(1, 2)
+            .inverse()
+            .is_err())
+    }
+
+    #[test]
+    fn pullback_along_veronese() {
+        let preimage = twisted_cubic()
+            .pullback(&ProjectiveMorphism::veronese_embedding(1, 3))
+            .unwrap();
+        assert_eq!(preimage.num_variables(), 2);
+        assert!(preimage.ideal().is_zero())
+    }
+
+    #[test]
+    fn pushforward_line() {
+        let image = ProjectiveScheme::projective_space(2)
+            .pushforward(&ProjectiveMorphism::veronese_embedding(1, 3))
+            .unwrap();
+        assert_eq!(image.ideal(), twisted_cubic().ideal())
+    }
+
+    #[test]
+    fn pushforward_projection() {
+        let projection = ProjectiveMorphism::linear_projection(&point(vec![0, 0, 0, 1]));
+        let image = twisted_cubic().pushforward(&projection).unwrap();
+        let conic = ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![1, 0, 1]),
+            (-1, vec![0, 2, 0]),
+        ])])
+        .unwrap();
+        assert_eq!(image.ideal(), conic.ideal())
+    }
+
+    #[test]
+    fn change_coordinates_point() {
+        // [0:1:0] is moved to [1:1:0]
+        let pt = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0, 0])]),
+            homogeneous(vec![(1, vec![0, 0, 1])]),
+        ])
+        .unwrap();
+        let morphism = matrix(vec![vec![1, 1, 0], vec![0, 1, 0], vec![0, 0, 1]]);
+        let moved = pt.change_coordinates(&morphism).unwrap();
+        assert!(moved.contains(&point(vec![1, 1, 0])).unwrap());
+        assert_eq!(moved.degree(), 1)
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{errors::Error, field::Field};
+use crate::{
+    complex::{ApproxEq, Complex},
+    errors::Error,
+    field::{Field, FiniteField},
+};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ProjectivePoint<K: Field> {
     dim: usize,
     coordinates: Vec<K>,
@@ -25,8 +29,62 @@ impl<K: Field> ProjectivePoint<K> {
     pub fn as_arr(self) -> Vec<K> {
         self.coordinates
     }
+
+    /// canonical representative
+    /// the coordinates are scaled so the first nonzero coordinate is one
+    pub fn normalize(self) -> ProjectivePoint<K>
+    where
+        K: Clone,
+    {
+        let first = self
+            .coordinates
+            .iter()
+            .find(|elem| **elem != K::zero())
+            .cloned()
+            .unwrap();
+        ProjectivePoint {
+            dim: self.dim,
+            coordinates: self
+                .coordinates
+                .into_iter()
+                .map(|elem| elem / first.clone())
+                .collect(),
+        }
+    }
+}
+
+impl<K: FiniteField> ProjectivePoint<K> {
+    /// all points of P^(dim-1) over a finite field, in normalized form
+    /// every point is listed exactly once
+    pub fn all_points(dim: usize) -> Vec<ProjectivePoint<K>> {
+        let mut points = vec![];
+        for first in 0..dim {
+            // [0:...:0:1:*:...:*] with the one at position first
+            let mut tails: Vec<Vec<K>> = vec![vec![]];
+            for _ in first + 1..dim {
+                tails = tails
+                    .into_iter()
+                    .flat_map(|tail| {
+                        K::elements().into_iter().map(move |elem| {
+                            let mut tail = tail.clone();
+                            tail.push(elem);
+                            tail
+                        })
+                    })
+                    .collect();
+            }
+            for tail in tails.into_iter() {
+                let mut coordinates = vec![K::zero(); first];
+                coordinates.push(<K as Field>::one());
+                coordinates.extend(tail);
+                points.push(ProjectivePoint { dim, coordinates });
+            }
+        }
+        points
+    }
 }
 
+/// points are equal if their normalized representatives are
 impl<K> PartialEq for ProjectivePoint<K>
 where
     K: Field + Clone,
@@ -35,10 +93,80 @@ where
         if self.dim() != other.dim() {
             return false;
         }
-        let mut divisors = vec![];
-        for (elem1, elem2) in self.coordinates.iter().zip(other.coordinates.iter()) {
-            divisors.push(elem1.clone() / elem2.clone());
+        self.clone().normalize().coordinates == other.clone().normalize().coordinates
+    }
+}
+
+/// points agree up to epsilon if all 2x2 minors of the coordinates
+/// scaled to unit norm are at most epsilon in absolute value
+/// this does not depend on the chosen representatives
+impl ApproxEq for ProjectivePoint<Complex> {
+    fn approx_eq(&self, other: &ProjectivePoint<Complex>, epsilon: f64) -> bool {
+        if self.dim() != other.dim() {
+            return false;
         }
-        divisors.windows(2).all(|elems| elems[0] == elems[1])
+        let unit = |coordinates: &[Complex]| -> Vec<Complex> {
+            let norm = coordinates
+                .iter()
+                .map(Complex::norm_sqr)
+                .sum::<f64>()
+                .sqrt();
+            coordinates
+                .iter()
+                .map(|z| Complex::new(z.re / norm, z.im / norm))
+                .collect()
+        };
+        let x = unit(&self.coordinates);
+        let y = unit(&other.coordinates);
+        (0..self.dim).all(|i| {
+            (i + 1..self.dim).all(|j| {
+                let minor = x[i].clone() * y[j].clone() - x[j].clone() * y[i].clone();
+                minor.abs() <= epsilon
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod point_tests {
+    use super::ProjectivePoint;
+    use crate::{
+        complex::{ApproxEq, Complex, EPSILON},
+        prime_field::PrimeField,
+        rational::Rational,
+    };
+
+    #[test]
+    fn scaled_points_equal() {
+        let pt1 = ProjectivePoint::new(vec![Rational::from(0), 2.into(), 4.into()]).unwrap();
+        let pt2 = ProjectivePoint::new(vec![Rational::from(0), 1.into(), 2.into()]).unwrap();
+        assert_eq!(pt1, pt2)
+    }
+
+    #[test]
+    fn different_points() {
+        let pt1 = ProjectivePoint::new(vec![Rational::from(1), 0.into()]).unwrap();
+        let pt2 = ProjectivePoint::new(vec![Rational::from(0), 1.into()]).unwrap();
+        assert_ne!(pt1, pt2)
+    }
+
+    #[test]
+    fn count_projective_plane() {
+        // |P^2(F_5)| = 25 + 5 + 1
+        let points = ProjectivePoint::<PrimeField<5>>::all_points(3);
+        assert_eq!(points.len(), 31)
+    }
+
+    #[test]
+    fn approximately_equal_complex_points() {
+        // [1 : i] and [-i + 1e-12 : 1] differ by the factor -i up to rounding
+        let pt1 = ProjectivePoint::new(vec![Complex::new(1.0, 0.0), Complex::i()]).unwrap();
+        let pt2 =
+            ProjectivePoint::new(vec![Complex::new(1e-12, -1.0), Complex::new(1.0, 0.0)]).unwrap();
+        assert_ne!(pt1, pt2);
+        assert!(pt1.approx_eq(&pt2, EPSILON));
+        let pt3 =
+            ProjectivePoint::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, -1.0)]).unwrap();
+        assert!(!pt1.approx_eq(&pt3, EPSILON))
     }
 }
@@ -1,81 +1,352 @@
 use super::{ProjectiveMorphism, ProjectivePoint};
-use crate::{errors::Error, field::Field, polynomials::HomogeneousPolynomial};
+use crate::{
+    errors::Error,
+    field::{Field, FiniteField},
+    groebner::GroebnerBasis,
+    hilbert::{HilbertPolynomial, HilbertSeries},
+    ideal::PolynomialIdeal,
+    polynomials::{HomogeneousPolynomial, MonomialOrder, Polynomial},
+    quotient_ring::QuotientRing,
+    rational::Rational,
+};
 
 use std::fmt;
 
 pub struct ProjectiveScheme<K: Field> {
-    dim: usize,
+    num_variables: usize,
     ideal_generators: Vec<HomogeneousPolynomial<K>>,
 }
 
 impl<K: Field> ProjectiveScheme<K> {
+    /// generators may have different degrees, but need the same number of variables
     pub fn new(
         ideal_generators: Vec<HomogeneousPolynomial<K>>,
     ) -> Result<ProjectiveScheme<K>, Error> {
-        HomogeneousPolynomial::check_deg(ideal_generators.as_slice())?;
-        let dim = HomogeneousPolynomial::check_dim(ideal_generators.as_slice())?;
+        let num_variables = HomogeneousPolynomial::check_dim(ideal_generators.as_slice())?;
 
         Ok(ProjectiveScheme {
-            dim,
+            num_variables,
             ideal_generators,
         })
     }
 
-    pub fn dim(&self) -> usize {
-        self.dim
+    /// subscheme of P^(num_variables-1)
+    /// generators in fewer variables are extended
+    pub fn in_space(
+        num_variables: usize,
+        ideal_generators: Vec<HomogeneousPolynomial<K>>,
+    ) -> Result<ProjectiveScheme<K>, Error> {
+        if let Some(gen) = ideal_generators
+            .iter()
+            .find(|gen| gen.dim() > num_variables)
+        {
+            return Err(Error::DimensionMismatch {
+                found: gen.dim(),
+                expected: num_variables,
+            });
+        }
+        Ok(ProjectiveScheme {
+            num_variables,
+            ideal_generators: ideal_generators
+                .into_iter()
+                .map(|gen| gen.with_dim(num_variables))
+                .collect(),
+        })
     }
 
-    pub fn apply_morphism(
-        self,
-        morphism: ProjectiveMorphism<K>,
+    /// the whole space P^(num_variables-1)
+    pub fn projective_space(num_variables: usize) -> ProjectiveScheme<K> {
+        ProjectiveScheme {
+            num_variables,
+            ideal_generators: vec![],
+        }
+    }
+
+    /// Grassmannian of k-planes in K^n in its Pluecker embedding
+    pub fn grassmannian(k: usize, n: usize) -> ProjectiveScheme<K>
+    where
+        K: Clone,
+    {
+        ProjectiveMorphism::plucker_embedding(k, n).image()
+    }
+
+    /// scheme defined by a homogeneous ideal
+    /// the generators are taken from the reduced Groebner basis
+    pub fn from_ideal(
+        num_variables: usize,
+        ideal: &PolynomialIdeal<K>,
     ) -> Result<ProjectiveScheme<K>, Error>
+    where
+        K: Clone,
+    {
+        let generators = ideal
+            .groebner_basis()
+            .basis()
+            .iter()
+            .map(|gen| gen.clone().try_into())
+            .collect::<Result<Vec<HomogeneousPolynomial<K>>, Error>>()?;
+        ProjectiveScheme::in_space(num_variables, generators)
+    }
+
+    /// number of homogeneous coordinates n+1 of the ambient P^n
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// dimension of the scheme, computed from the Hilbert polynomial
+    /// None for the empty scheme
+    pub fn dim(&self) -> Option<usize>
+    where
+        K: Clone,
+    {
+        self.hilbert_series()
+            .krull_dimension()
+            .and_then(|dim| dim.checked_sub(1))
+    }
+
+    /// Hilbert series of the homogeneous coordinate ring
+    pub fn hilbert_series(&self) -> HilbertSeries
+    where
+        K: Clone,
+    {
+        self.ideal().hilbert_series()
+    }
+
+    /// dimension of the degree d part of the homogeneous coordinate ring
+    pub fn hilbert_function(&self, d: usize) -> i64
+    where
+        K: Clone,
+    {
+        self.hilbert_series().hilbert_function(d)
+    }
+
+    pub fn hilbert_polynomial(&self) -> HilbertPolynomial
+    where
+        K: Clone,
+    {
+        self.hilbert_series().hilbert_polynomial()
+    }
+
+    /// degree of the scheme
+    /// the number of intersection points with a general linear space of complementary dimension
+    pub fn degree(&self) -> i64
+    where
+        K: Clone,
+    {
+        self.hilbert_series().degree()
+    }
+
+    /// arithmetic genus (-1)^r (P(0) - 1) where r is the dimension and P the Hilbert polynomial
+    /// None for the empty scheme
+    pub fn arithmetic_genus(&self) -> Option<Rational>
+    where
+        K: Clone,
+    {
+        let dim = self.dim()?;
+        let genus = self.hilbert_polynomial().eval(0) - Rational::from(1);
+        if dim % 2 == 0 {
+            Some(genus)
+        } else {
+            Some(-genus)
+        }
+    }
+
+    pub fn ideal_generators(&self) -> Vec<HomogeneousPolynomial<K>>
+    where
+        K: Clone,
+    {
+        self.ideal_generators.clone()
+    }
+
+    pub fn groebner_basis(&self, order: MonomialOrder) -> GroebnerBasis<K>
+    where
+        K: Clone,
+    {
+        let generators = self
+            .ideal_generators
+            .iter()
+            .map(|gen| gen.clone().into())
+            .collect();
+        GroebnerBasis::new(generators, order)
+    }
+
+    /// the same scheme over a field containing K
+    pub fn base_change<L>(&self) -> ProjectiveScheme<L>
+    where
+        K: Clone,
+        L: Field + From<K>,
+    {
+        ProjectiveScheme {
+            num_variables: self.num_variables,
+            ideal_generators: self
+                .ideal_generators
+                .iter()
+                .map(|gen| gen.clone().map_coefficients(L::from))
+                .collect(),
+        }
+    }
+
+    /// ideal generated by the ideal generators
+    pub fn ideal(&self) -> PolynomialIdeal<K>
+    where
+        K: Clone,
+    {
+        let generators = self
+            .ideal_generators
+            .iter()
+            .map(|gen| gen.clone().into())
+            .collect();
+        PolynomialIdeal::with_dim(generators, self.num_variables)
+    }
+
+    /// saturation of the ideal by the irrelevant ideal <X_0,...,X_n>
+    /// this is the largest ideal defining the same scheme
+    pub fn saturated_ideal(&self) -> PolynomialIdeal<K>
+    where
+        K: Clone,
+    {
+        self.ideal()
+            .saturation(&PolynomialIdeal::maximal_at_origin(self.num_variables))
+    }
+
+    /// homogeneous coordinate ring K[X_0,...,X_n]/I
+    pub fn coordinate_ring(&self) -> QuotientRing<K>
+    where
+        K: Clone,
+    {
+        QuotientRing::new(self.ideal())
+    }
+
+    /// checks if poly is contained in the ideal generated by the ideal generators
+    pub fn ideal_contains(&self, poly: &HomogeneousPolynomial<K>) -> bool
+    where
+        K: Clone,
+    {
+        self.groebner_basis(MonomialOrder::GRevLex)
+            .contains(&poly.clone().into())
+    }
+
+    /// preimage of the scheme under a morphism
+    /// the generators are pulled back by substituting the coordinate functions
+    pub fn pullback(&self, morphism: &ProjectiveMorphism<K>) -> Result<ProjectiveScheme<K>, Error>
     where
         K: Clone,
     {
         let new_generators = self
             .ideal_generators
-            .into_iter()
-            .map(|gen| gen.compose_morphism(&morphism))
+            .iter()
+            .map(|gen| gen.clone().compose_morphism(morphism))
             .collect::<Result<Vec<HomogeneousPolynomial<K>>, Error>>()?;
-        ProjectiveScheme::new(new_generators)
+        ProjectiveScheme::in_space(morphism.dim_domain(), new_generators)
     }
 
-    pub fn disjoint_union(self, other: ProjectiveScheme<K>) -> ProjectiveScheme<K>
+    /// closure of the image of the scheme under a morphism, computed by elimination
+    pub fn pushforward(
+        &self,
+        morphism: &ProjectiveMorphism<K>,
+    ) -> Result<ProjectiveScheme<K>, Error>
     where
         K: Clone,
     {
-        let mut new_polys = vec![];
-        for f in self.ideal_generators.iter() {
-            for g in other.ideal_generators.iter() {
-                let new_poly = f.clone() * g.clone();
-                new_polys.push(new_poly);
-            }
+        if morphism.dim_domain() != self.num_variables {
+            return Err(Error::DimensionMismatch {
+                found: morphism.dim_domain(),
+                expected: self.num_variables,
+            });
+        }
+        let image = morphism.image_ideal(&self.ideal());
+        ProjectiveScheme::from_ideal(morphism.dim_codomain(), &image)
+    }
+
+    /// image of the scheme under a linear automorphism
+    /// this is the pullback along the inverse
+    pub fn change_coordinates(
+        &self,
+        automorphism: &ProjectiveMorphism<K>,
+    ) -> Result<ProjectiveScheme<K>, Error>
+    where
+        K: Clone,
+    {
+        self.pullback(&automorphism.inverse()?)
+    }
+
+    /// union X ∪ Y, defined by the intersection I ∩ J of the ideals
+    pub fn union(&self, other: &ProjectiveScheme<K>) -> Result<ProjectiveScheme<K>, Error>
+    where
+        K: Clone,
+    {
+        self.check_same_space(other)?;
+        ProjectiveScheme::from_ideal(
+            self.num_variables,
+            &self.ideal().intersection(&other.ideal()),
+        )
+    }
+
+    /// intersection X ∩ Y, defined by the saturation of I + J
+    pub fn intersect(&self, other: &ProjectiveScheme<K>) -> Result<ProjectiveScheme<K>, Error>
+    where
+        K: Clone,
+    {
+        self.check_same_space(other)?;
+        let mut generators = self.ideal_generators.clone();
+        generators.extend(other.ideal_generators.iter().cloned());
+        ProjectiveScheme::in_space(self.num_variables, generators)?.saturate()
+    }
+
+    /// the same scheme defined by its saturated ideal
+    pub fn saturate(&self) -> Result<ProjectiveScheme<K>, Error>
+    where
+        K: Clone,
+    {
+        ProjectiveScheme::from_ideal(self.num_variables, &self.saturated_ideal())
+    }
+
+    fn check_same_space(&self, other: &ProjectiveScheme<K>) -> Result<(), Error> {
+        if other.num_variables != self.num_variables {
+            return Err(Error::DimensionMismatch {
+                found: other.num_variables,
+                expected: self.num_variables,
+            });
         }
-        ProjectiveScheme::new(new_polys).unwrap()
+        Ok(())
     }
 
+    /// product X x Y embedded into P^((n+1)(m+1)-1) by the Segre embedding
     pub fn product(self, other: &ProjectiveScheme<K>) -> Result<ProjectiveScheme<K>, Error>
     where
         K: Clone,
     {
-        let embedding = ProjectiveMorphism::segre_embedding(self.dim(), other.dim());
-        let mut new_polys = vec![];
-        for self_poly in self.ideal_generators.iter() {
-            for other_poly in other.ideal_generators.iter() {
-                let new_poly = self_poly.clone().product(other_poly.clone());
-                new_polys.push(new_poly.compose_morphism(&embedding)?);
-            }
+        if self.num_variables == 0 || other.num_variables == 0 {
+            return Err(Error::DimensionMismatch {
+                found: 0,
+                expected: 1,
+            });
         }
-        ProjectiveScheme::new(new_polys)
+        let num_variables = self.num_variables + other.num_variables;
+        let mut generators: Vec<Polynomial<K>> = self
+            .ideal_generators
+            .iter()
+            .map(|gen| Polynomial::from(gen.clone()).with_dim(num_variables))
+            .collect();
+        generators.extend(
+            other
+                .ideal_generators
+                .iter()
+                .map(|gen| Polynomial::from(gen.clone()).shift_variables(self.num_variables)),
+        );
+        let embedding =
+            ProjectiveMorphism::segre_embedding(self.num_variables - 1, other.num_variables - 1);
+        let image = embedding.image_ideal(&PolynomialIdeal::with_dim(generators, num_variables));
+        ProjectiveScheme::from_ideal(embedding.dim_codomain(), &image)
     }
 
     pub fn contains(&self, pt: &ProjectivePoint<K>) -> Result<bool, Error>
     where
         K: Clone,
     {
-        if pt.dim() != self.dim {
+        if pt.dim() != self.num_variables {
             Err(Error::DimensionMismatch {
-                expected: self.dim,
+                expected: self.num_variables,
                 found: pt.dim(),
             })
         } else {
@@ -92,6 +363,24 @@ impl<K: Field> ProjectiveScheme<K> {
     }
 }
 
+impl<K: FiniteField> ProjectiveScheme<K> {
+    /// all points of the scheme over K, in normalized form
+    pub fn rational_points(&self) -> Vec<ProjectivePoint<K>> {
+        ProjectivePoint::all_points(self.num_variables)
+            .into_iter()
+            .filter(|pt| self.contains(pt).unwrap())
+            .collect()
+    }
+
+    /// number of points over K
+    pub fn count_points(&self) -> usize {
+        ProjectivePoint::all_points(self.num_variables)
+            .iter()
+            .filter(|pt| self.contains(pt).unwrap())
+            .count()
+    }
+}
+
 impl<K> fmt::Display for ProjectiveScheme<K>
 where
     K: Field,
@@ -103,6 +392,99 @@ where
             .iter()
             .map(|poly| format!("{}", poly))
             .collect();
-        write!(f, "P^{}/<{}>", self.dim, ideal_str.join(", "))
+        write!(
+            f,
+            "P^{}/<{}>",
+            self.num_variables.saturating_sub(1),
+            ideal_str.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod scheme_tests {
+    use super::ProjectiveScheme;
+    use crate::{rational::Rational, test_helpers::homogeneous};
+
+    fn twisted_cubic() -> ProjectiveScheme<Rational> {
+        ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0, 1, 0]), (-1, vec![0, 2, 0, 0])]),
+            homogeneous(vec![(1, vec![1, 0, 0, 1]), (-1, vec![0, 1, 1, 0])]),
+            homogeneous(vec![(1, vec![0, 1, 0, 1]), (-1, vec![0, 0, 2, 0])]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn point_dimension() {
+        let point = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0, 0])]),
+            homogeneous(vec![(1, vec![0, 1, 0])]),
+        ])
+        .unwrap();
+        assert_eq!(point.dim(), Some(0));
+        assert_eq!(point.degree(), 1)
+    }
+
+    #[test]
+    fn empty_scheme() {
+        let empty = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0])]),
+            homogeneous(vec![(1, vec![0, 1])]),
+        ])
+        .unwrap();
+        assert_eq!(empty.dim(), None)
+    }
+
+    #[test]
+    fn twisted_cubic_invariants() {
+        let cubic = twisted_cubic();
+        assert_eq!(cubic.dim(), Some(1));
+        assert_eq!(cubic.degree(), 3);
+        assert_eq!(cubic.arithmetic_genus(), Some(0.into()));
+        assert_eq!(cubic.hilbert_polynomial().eval(5), 16.into());
+        assert_eq!(cubic.hilbert_function(2), 7)
+    }
+
+    #[test]
+    fn plane_cubic_genus() {
+        let cubic = ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![0, 2, 1]),
+            (-1, vec![3, 0, 0]),
+            (1, vec![0, 0, 3]),
+        ])])
+        .unwrap();
+        assert_eq!(cubic.dim(), Some(1));
+        assert_eq!(cubic.degree(), 3);
+        assert_eq!(cubic.arithmetic_genus(), Some(1.into()))
+    }
+
+    #[test]
+    fn quadric_surface() {
+        // the Segre embedding of P^1 x P^1
+        let quadric = ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![1, 0, 0, 1]),
+            (-1, vec![0, 1, 1, 0]),
+        ])])
+        .unwrap();
+        assert_eq!(quadric.dim(), Some(2));
+        assert_eq!(quadric.degree(), 2);
+        assert_eq!(quadric.arithmetic_genus(), Some(0.into()))
+    }
+
+    #[test]
+    fn union_with_shared_component() {
+        let conic = ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![1, 1, 0]),
+            (-1, vec![0, 0, 2]),
+        ])])
+        .unwrap();
+        let union = conic.union(&conic).unwrap();
+        assert_eq!(union.degree(), 2);
+        assert_eq!(union.ideal_generators, conic.ideal_generators);
+        let line = ProjectiveScheme::new(vec![homogeneous(vec![(1, vec![1, 0, 0])])]).unwrap();
+        let cubic = conic.union(&line).unwrap();
+        assert_eq!(cubic.union(&conic).unwrap().degree(), 3);
+        assert_eq!(cubic.union(&line).unwrap().degree(), 3)
     }
 }
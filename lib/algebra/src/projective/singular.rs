@@ -0,0 +1,225 @@
+use super::{
+    minors::{determinant, subsets},
+    ProjectivePoint, ProjectiveScheme,
+};
+use crate::{
+    errors::Error,
+    field::Field,
+    matrix::Matrix,
+    polynomials::{HomogeneousPolynomial, Monomial, Polynomial},
+};
+
+impl<K: Field + Clone> ProjectiveScheme<K> {
+    /// Jacobian matrix of the ideal generators
+    /// row i contains the partial derivatives of the i-th generator
+    pub fn jacobian(&self) -> Vec<Vec<HomogeneousPolynomial<K>>> {
+        self.ideal_generators()
+            .iter()
+            .map(|gen| gen.gradient())
+            .collect()
+    }
+
+    /// Jacobian matrix evaluated at a point
+    /// a scheme without generators has an empty Jacobian
+    pub fn jacobian_at(&self, pt: &ProjectivePoint<K>) -> Result<Matrix<K>, Error> {
+        let rows = self
+            .jacobian()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|entry| entry.eval(pt.clone().as_arr()))
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<K>>, Error>>()?;
+        Matrix::new(rows)
+    }
+
+    /// embedded projective tangent space at a point of the scheme
+    /// the linear space cut out by sum_j df/dX_j(pt) X_j for all generators f
+    /// its dimension is the dimension of the Zariski tangent space
+    pub fn tangent_space(&self, pt: &ProjectivePoint<K>) -> Result<ProjectiveScheme<K>, Error> {
+        if !self.contains(pt)? {
+            return Err(Error::PointNotOnScheme);
+        }
+        let mut linear_forms = vec![];
+        for row in self.jacobian_at(pt)?.into_rows() {
+            let terms = row
+                .into_iter()
+                .enumerate()
+                .map(|(j, coeff)| {
+                    let mut powers = vec![0; self.num_variables()];
+                    powers[j] = 1;
+                    Monomial::new(coeff, powers)
+                })
+                .collect();
+            let form = HomogeneousPolynomial::new(terms)?;
+            if form != HomogeneousPolynomial::new(vec![])? {
+                linear_forms.push(form);
+            }
+        }
+        ProjectiveScheme::in_space(self.num_variables(), linear_forms)
+    }
+
+    /// dimension of the Zariski tangent space at a point of the scheme
+    /// n - rank J(pt) for a subscheme of P^n
+    pub fn tangent_space_dim(&self, pt: &ProjectivePoint<K>) -> Result<usize, Error> {
+        if !self.contains(pt)? {
+            return Err(Error::PointNotOnScheme);
+        }
+        Ok(self.num_variables() - 1 - self.jacobian_at(pt)?.rank())
+    }
+
+    /// checks if the tangent space at pt has the dimension of the scheme
+    /// assumes the scheme is equidimensional
+    pub fn is_smooth_at(&self, pt: &ProjectivePoint<K>) -> Result<bool, Error> {
+        let tangent_dim = self.tangent_space_dim(pt)?;
+        Ok(self.dim() == Some(tangent_dim))
+    }
+
+    /// singular locus by the Jacobian criterion
+    /// cut out by the generators and the c x c minors of the Jacobian, c the codimension
+    /// this is only correct for equidimensional schemes,
+    /// non-reduced schemes are singular everywhere
+    pub fn singular_locus(&self) -> ProjectiveScheme<K> {
+        let mut generators = self.ideal_generators();
+        let dim = match self.dim() {
+            None => {
+                return ProjectiveScheme::in_space(self.num_variables(), generators).unwrap();
+            }
+            Some(dim) => dim,
+        };
+        let codim = self.num_variables() - 1 - dim;
+        let jacobian: Vec<Vec<Polynomial<K>>> = self
+            .jacobian()
+            .into_iter()
+            .map(|row| row.into_iter().map(|entry| entry.into()).collect())
+            .collect();
+        for rows in subsets(jacobian.len(), codim) {
+            for cols in subsets(self.num_variables(), codim) {
+                let minor: Vec<Vec<Polynomial<K>>> = rows
+                    .iter()
+                    .map(|i| cols.iter().map(|j| jacobian[*i][*j].clone()).collect())
+                    .collect();
+                let det = determinant(&minor);
+                if !det.is_zero() {
+                    generators.push(det.try_into().unwrap());
+                }
+            }
+        }
+        ProjectiveScheme::in_space(self.num_variables(), generators).unwrap()
+    }
+
+    /// checks if the singular locus is empty
+    pub fn is_smooth(&self) -> bool {
+        self.singular_locus().dim().is_none()
+    }
+}
+
+#[cfg(test)]
+mod singular_tests {
+    use super::super::ProjectiveScheme;
+    use crate::{
+        polynomials::{Monomial, Polynomial},
+        prime_field::PrimeField,
+        rational::Rational,
+        test_helpers::{homogeneous, point},
+    };
+
+    fn twisted_cubic() -> ProjectiveScheme<Rational> {
+        ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![1, 0, 1, 0]), (-1, vec![0, 2, 0, 0])]),
+            homogeneous(vec![(1, vec![1, 0, 0, 1]), (-1, vec![0, 1, 1, 0])]),
+            homogeneous(vec![(1, vec![0, 1, 0, 1]), (-1, vec![0, 0, 2, 0])]),
+        ])
+        .unwrap()
+    }
+
+    // y^2z - x^3 - x^2z with a node at [0:0:1]
+    fn nodal_cubic() -> ProjectiveScheme<Rational> {
+        ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![0, 2, 1]),
+            (-1, vec![3, 0, 0]),
+            (-1, vec![2, 0, 1]),
+        ])])
+        .unwrap()
+    }
+
+    #[test]
+    fn partial_derivative() {
+        let poly = homogeneous(vec![(1, vec![2, 1]), (3, vec![0, 3])]);
+        let result = poly.partial_derivative(1);
+        let expected = homogeneous(vec![(1, vec![2, 0]), (9, vec![0, 2])]);
+        assert_eq!(result, expected);
+        assert_eq!(result.deg(), 2)
+    }
+
+    #[test]
+    fn derivative_characteristic_two() {
+        let poly: Polynomial<PrimeField<2>> = Polynomial::new(vec![
+            Monomial::new(1.into(), vec![2, 1]),
+            Monomial::new(1.into(), vec![1, 0]),
+        ]);
+        let expected = Polynomial::new(vec![Monomial::new(1.into(), vec![0, 0])]);
+        assert_eq!(poly.partial_derivative(0), expected)
+    }
+
+    #[test]
+    fn twisted_cubic_tangent_line() {
+        let tangent = twisted_cubic()
+            .tangent_space(&point(vec![1, 0, 0, 0]))
+            .unwrap();
+        let expected = ProjectiveScheme::new(vec![
+            homogeneous(vec![(1, vec![0, 0, 1, 0])]),
+            homogeneous(vec![(1, vec![0, 0, 0, 1])]),
+        ])
+        .unwrap();
+        assert_eq!(tangent.ideal(), expected.ideal());
+        assert_eq!(tangent.dim(), Some(1))
+    }
+
+    #[test]
+    fn tangent_space_off_scheme() {
+        assert!(twisted_cubic()
+            .tangent_space(&point(vec![0, 1, 0, 0]))
+            .is_err())
+    }
+
+    #[test]
+    fn twisted_cubic_smooth() {
+        assert!(twisted_cubic().is_smooth())
+    }
+
+    #[test]
+    fn nodal_cubic_singular_point() {
+        let cubic = nodal_cubic();
+        let singular = cubic.singular_locus();
+        assert_eq!(singular.dim(), Some(0));
+        assert_eq!(singular.degree(), 1);
+        assert!(singular.contains(&point(vec![0, 0, 1])).unwrap());
+        assert!(!cubic.is_smooth_at(&point(vec![0, 0, 1])).unwrap());
+        assert!(cubic.is_smooth_at(&point(vec![-1, 0, 1])).unwrap())
+    }
+
+    #[test]
+    fn cone_vertex() {
+        // x^2 + y^2 - z^2 in P^3 is singular exactly at [0:0:0:1]
+        let cone = ProjectiveScheme::new(vec![homogeneous(vec![
+            (1, vec![2, 0, 0, 0]),
+            (1, vec![0, 2, 0, 0]),
+            (-1, vec![0, 0, 2, 0]),
+        ])])
+        .unwrap();
+        let vertex = point(vec![0, 0, 0, 1]);
+        assert_eq!(cone.tangent_space_dim(&vertex).unwrap(), 3);
+        let singular = cone.singular_locus();
+        assert_eq!(singular.dim(), Some(0));
+        assert!(singular.contains(&vertex).unwrap())
+    }
+
+    #[test]
+    fn double_line_singular() {
+        let double_line =
+            ProjectiveScheme::new(vec![homogeneous(vec![(1, vec![2, 0, 0])])]).unwrap();
+        assert_eq!(double_line.singular_locus().dim(), Some(1))
+    }
+}
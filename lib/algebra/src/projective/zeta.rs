@@ -0,0 +1,429 @@
+use super::ProjectiveScheme;
+use crate::{
+    errors::Error,
+    field::{Field, FiniteField},
+    polynomials::HomogeneousPolynomial,
+    rational::Rational,
+};
+use std::fmt;
+
+/// largest k for which points over GF(p^k) could be counted
+/// when extensions were built from GaloisField types
+#[deprecated(note = "the degree is only limited by q^k fitting into u64")]
+pub const MAX_EXTENSION_DEGREE: usize = 6;
+
+/// Local zeta function Z(t) = exp(sum_k N_k t^k / k) of a scheme over GF(q)
+/// where N_k is the number of points over GF(q^k)
+/// only the counts up to some degree are known, so Z is a truncated power series
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZetaFunction {
+    q: u64,
+    counts: Vec<usize>,
+}
+
+impl ZetaFunction {
+    /// counts[k-1] is the number of points over GF(q^k)
+    pub fn new(q: u64, counts: Vec<usize>) -> ZetaFunction {
+        ZetaFunction { q, counts }
+    }
+
+    pub fn q(&self) -> u64 {
+        self.q
+    }
+
+    pub fn counts(&self) -> Vec<usize> {
+        self.counts.clone()
+    }
+
+    /// coefficients z_0,...,z_m of Z(t) where m is the number of counts
+    /// from Z' = (sum_k N_k t^(k-1)) Z, i.e. n z_n = sum_k N_k z_(n-k)
+    pub fn series(&self) -> Vec<Rational> {
+        let mut coefficients = vec![Rational::from(1)];
+        for n in 1..=self.counts.len() {
+            let sum = (1..=n).fold(Rational::from(0), |sum, k| {
+                sum + Rational::from(self.counts[k - 1] as i64) * coefficients[n - k].clone()
+            });
+            coefficients.push(sum / Rational::from(n as i64));
+        }
+        coefficients
+    }
+
+    /// coefficients of the truncated product Z(t) f(t)
+    /// for f = (1-t)(1-qt)...(1-q^d t) this gives the numerator of Z
+    /// of a variety with the cohomology of a curve or P^d
+    pub fn multiply_series(&self, factor: &[i64]) -> Vec<Rational> {
+        let series = self.series();
+        (0..series.len())
+            .map(|n| {
+                (0..=n)
+                    .filter(|i| *i < factor.len())
+                    .fold(Rational::from(0), |sum, i| {
+                        sum + Rational::from(factor[i]) * series[n - i].clone()
+                    })
+            })
+            .collect()
+    }
+
+    /// coefficients of (1-t)(1-qt)...(1-q^d t)
+    pub fn projective_denominator(&self, d: usize) -> Vec<i64> {
+        let mut poly = vec![1];
+        for i in 0..=d {
+            let root = (self.q as i64).pow(i as u32);
+            let mut next = poly.clone();
+            next.push(0);
+            for (j, c) in poly.iter().enumerate() {
+                next[j + 1] -= root * c;
+            }
+            poly = next;
+        }
+        poly
+    }
+}
+
+impl<K: FiniteField> ProjectiveScheme<K> {
+    /// number of points over GF(q^k) where q is the order of K
+    /// all points over GF(q^k) are enumerated, so this is only feasible for small q^k
+    /// k = 0 or q^k not fitting into u64 give an UnsupportedExtensionDegree error
+    pub fn count_points_extension(&self, k: usize) -> Result<usize, Error> {
+        let max = (1..)
+            .take_while(|k| K::order().checked_pow(*k).is_some())
+            .count();
+        if k == 0 || k > max {
+            return Err(Error::UnsupportedExtensionDegree { found: k, max });
+        }
+        if k == 1 {
+            return Ok(self.count_points());
+        }
+        let extension = Extension::<K>::new(k);
+        let elements = extension.elements();
+        let n = self.num_variables();
+        let mut count = 0;
+        // points with x_0 = ... = x_(first-1) = 0 and x_first = 1
+        for first in 0..n {
+            let mut index = vec![0; n - first - 1];
+            loop {
+                let mut point = vec![extension.constant(K::zero()); first];
+                point.push(extension.constant(<K as Field>::one()));
+                point.extend(index.iter().map(|i| elements[*i].clone()));
+                if self
+                    .ideal_generators()
+                    .iter()
+                    .all(|gen| extension.eval(gen, &point).is_empty())
+                {
+                    count += 1;
+                }
+                let Some(i) = index.iter().position(|i| *i + 1 < elements.len()) else {
+                    break;
+                };
+                index[..i].fill(0);
+                index[i] += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// zeta function from the point counts over GF(q),...,GF(q^m)
+    pub fn zeta_function(&self, m: usize) -> Result<ZetaFunction, Error> {
+        let counts = (1..=m)
+            .map(|k| self.count_points_extension(k))
+            .collect::<Result<Vec<usize>, Error>>()?;
+        Ok(ZetaFunction::new(K::order(), counts))
+    }
+}
+
+/// The extension GF(q^k) = K[y]/(h) of K = GF(q)
+/// h is the first monic irreducible polynomial of degree k in the order of K::elements()
+/// elements are coefficient vectors c_0 + c_1 y + ... + c_(k-1) y^(k-1)
+/// a runtime field, so extensions of any finite field and of any degree can be built
+struct Extension<K: FiniteField> {
+    /// the coefficients of h below the leading one
+    modulus: Vec<K>,
+}
+
+impl<K: FiniteField> Extension<K> {
+    fn new(k: usize) -> Extension<K> {
+        let elements = K::elements();
+        let mut index = vec![0; k];
+        loop {
+            let extension = Extension {
+                modulus: index.iter().map(|i| elements[*i].clone()).collect(),
+            };
+            if extension.is_field() {
+                return extension;
+            }
+            // irreducible polynomials of every degree exist, so this does not run out
+            let i = index.iter().position(|i| *i + 1 < elements.len()).unwrap();
+            index[..i].fill(0);
+            index[i] += 1;
+        }
+    }
+
+    fn degree(&self) -> usize {
+        self.modulus.len()
+    }
+
+    fn constant(&self, c: K) -> Vec<K> {
+        let mut constant = vec![K::zero(); self.degree()];
+        constant[0] = c;
+        constant
+    }
+
+    /// the class of y
+    fn generator(&self) -> Vec<K> {
+        if self.degree() == 1 {
+            return vec![-self.modulus[0].clone()];
+        }
+        let mut generator = self.constant(K::zero());
+        generator[1] = <K as Field>::one();
+        generator
+    }
+
+    /// all q^k elements, starting with zero
+    fn elements(&self) -> Vec<Vec<K>> {
+        let mut elements = vec![self.constant(K::zero())];
+        for i in 0..self.degree() {
+            elements = elements
+                .iter()
+                .flat_map(|element| {
+                    K::elements().into_iter().map(move |c| {
+                        let mut element = element.clone();
+                        element[i] = c;
+                        element
+                    })
+                })
+                .collect();
+        }
+        elements
+    }
+
+    fn add(&self, a: &[K], b: &[K]) -> Vec<K> {
+        a.iter()
+            .zip(b.iter())
+            .map(|(x, y)| x.clone() + y.clone())
+            .collect()
+    }
+
+    /// product reduced with y^k = -(h_0 + h_1 y + ... + h_(k-1) y^(k-1))
+    fn mul(&self, a: &[K], b: &[K]) -> Vec<K> {
+        let k = self.degree();
+        let mut product = vec![K::zero(); 2 * k - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                product[i + j] = product[i + j].clone() + x.clone() * y.clone();
+            }
+        }
+        for d in (k..2 * k - 1).rev() {
+            let c = std::mem::replace(&mut product[d], K::zero());
+            for (j, h) in self.modulus.iter().enumerate() {
+                product[d - k + j] = product[d - k + j].clone() + -(c.clone() * h.clone());
+            }
+        }
+        product.truncate(k);
+        product
+    }
+
+    fn pow(&self, a: &[K], mut n: u64) -> Vec<K> {
+        let mut power = self.constant(<K as Field>::one());
+        let mut base = a.to_vec();
+        while n > 0 {
+            if n % 2 == 1 {
+                power = self.mul(&power, &base);
+            }
+            base = self.mul(&base, &base);
+            n /= 2;
+        }
+        power
+    }
+
+    /// value of a polynomial over K, trimmed so that zero is empty
+    fn eval(&self, poly: &HomogeneousPolynomial<K>, x: &[Vec<K>]) -> Vec<K> {
+        let value = poly
+            .monomials()
+            .into_iter()
+            .fold(self.constant(K::zero()), |sum, mono| {
+                let term = mono
+                    .exponents()
+                    .iter()
+                    .zip(x.iter())
+                    .fold(self.constant(mono.coefficient.clone()), |term, (e, x)| {
+                        self.mul(&term, &self.pow(x, *e as u64))
+                    });
+                self.add(&sum, &term)
+            });
+        trim(value)
+    }
+
+    /// Ben-Or's test: h is irreducible if gcd(h, y^(q^i) - y) = 1 for i <= k/2
+    fn is_field(&self) -> bool {
+        let mut h = self.modulus.clone();
+        h.push(<K as Field>::one());
+        let generator = self.generator();
+        let negated: Vec<K> = generator.iter().map(|c| -c.clone()).collect();
+        let mut frobenius = generator;
+        for _ in 0..self.degree() / 2 {
+            frobenius = self.pow(&frobenius, K::order());
+            let (mut a, mut b) = (h.clone(), trim(self.add(&frobenius, &negated)));
+            while !b.is_empty() {
+                let r = remainder(a, &b);
+                a = b;
+                b = r;
+            }
+            if a.len() > 1 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// removes leading zero coefficients
+fn trim<K: Field>(mut coefficients: Vec<K>) -> Vec<K> {
+    while coefficients.last().is_some_and(|c| *c == K::zero()) {
+        coefficients.pop();
+    }
+    coefficients
+}
+
+/// remainder of the division of polynomials over K, b has a nonzero leading coefficient
+fn remainder<K: Field + Clone>(mut a: Vec<K>, b: &[K]) -> Vec<K> {
+    let lead_inverse = b.last().unwrap().clone().inverse();
+    while a.len() >= b.len() {
+        let c = a.last().unwrap().clone() * lead_inverse.clone();
+        let shift = a.len() - b.len();
+        for (j, coefficient) in b.iter().enumerate() {
+            a[shift + j] = a[shift + j].clone() + -(c.clone() * coefficient.clone());
+        }
+        a.pop();
+        a = trim(a);
+    }
+    a
+}
+
+impl fmt::Display for ZetaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self
+            .series()
+            .iter()
+            .enumerate()
+            .map(|(n, c)| format!("{}t^{}", c, n))
+            .collect();
+        write!(f, "{} + O(t^{})", terms.join(" + "), self.counts.len() + 1)
+    }
+}
+
+#[cfg(test)]
+mod zeta_tests {
+    use super::ZetaFunction;
+    use crate::{
+        galois_field::GaloisField, prime_field::PrimeField, projective::ProjectiveScheme,
+        rational::Rational, test_helpers::homogeneous_over,
+    };
+
+    type F2 = PrimeField<2>;
+    type F3 = PrimeField<3>;
+    type F4 = GaloisField<2, 2>;
+
+    #[test]
+    fn conic_points() {
+        // xz - y^2 is isomorphic to P^1, so it has q^k + 1 points
+        let conic: ProjectiveScheme<F3> = ProjectiveScheme::new(vec![homogeneous_over(vec![
+            (1, vec![1, 0, 1]),
+            (-1, vec![0, 2, 0]),
+        ])])
+        .unwrap();
+        let counts: Vec<usize> = (1..=3)
+            .map(|k| conic.count_points_extension(k).unwrap())
+            .collect();
+        assert_eq!(counts, vec![4, 10, 28])
+    }
+
+    #[test]
+    fn rational_points_normalized() {
+        // x y = 0 in P^1 consists of [1:0] and [0:1]
+        let scheme: ProjectiveScheme<F3> =
+            ProjectiveScheme::new(vec![homogeneous_over(vec![(1, vec![1, 1])])]).unwrap();
+        let points: Vec<Vec<F3>> = scheme
+            .rational_points()
+            .into_iter()
+            .map(|pt| pt.as_arr())
+            .collect();
+        assert_eq!(
+            points,
+            vec![vec![1.into(), 0.into()], vec![0.into(), 1.into()]]
+        )
+    }
+
+    #[test]
+    fn zeta_projective_line() {
+        // Z(P^1) = 1/((1-t)(1-qt))
+        let line = ZetaFunction::new(2, vec![3, 5, 9, 17]);
+        let numerator = line.multiply_series(&line.projective_denominator(1));
+        let expected: Vec<Rational> = vec![1.into(), 0.into(), 0.into(), 0.into(), 0.into()];
+        assert_eq!(numerator, expected)
+    }
+
+    #[test]
+    fn zeta_elliptic_curve() {
+        // y^2 z + y z^2 = x^3 + z^3 over F_2
+        // Z = (1 - a t + 2 t^2)/((1-t)(1-2t)) with a = 2 + 1 - N_1
+        let curve: ProjectiveScheme<F2> = ProjectiveScheme::new(vec![homogeneous_over(vec![
+            (1, vec![0, 2, 1]),
+            (1, vec![0, 1, 2]),
+            (1, vec![3, 0, 0]),
+            (1, vec![0, 0, 3]),
+        ])])
+        .unwrap();
+        let zeta = curve.zeta_function(4).unwrap();
+        let a = 3 - zeta.counts()[0] as i64;
+        let numerator = zeta.multiply_series(&zeta.projective_denominator(1));
+        let expected: Vec<Rational> = vec![1.into(), (-a).into(), 2.into(), 0.into(), 0.into()];
+        assert_eq!(numerator, expected)
+    }
+
+    #[test]
+    fn extension_degrees() {
+        // x y = 0 in P^1 has two points over every field
+        let scheme: ProjectiveScheme<F2> =
+            ProjectiveScheme::new(vec![homogeneous_over(vec![(1, vec![1, 1])])]).unwrap();
+        assert_eq!(scheme.count_points_extension(7).unwrap(), 2);
+        assert!(scheme.count_points_extension(0).is_err());
+        // 2^64 does not fit into u64
+        assert!(scheme.count_points_extension(64).is_err())
+    }
+
+    #[test]
+    fn agrees_with_galois_fields() {
+        // y^2 z + y z^2 = x^3 + z^3 over F_2
+        let curve: ProjectiveScheme<F2> = ProjectiveScheme::new(vec![homogeneous_over(vec![
+            (1, vec![0, 2, 1]),
+            (1, vec![0, 1, 2]),
+            (1, vec![3, 0, 0]),
+            (1, vec![0, 0, 3]),
+        ])])
+        .unwrap();
+        let expected = [
+            curve.base_change::<GaloisField<2, 2>>().count_points(),
+            curve.base_change::<GaloisField<2, 3>>().count_points(),
+            curve.base_change::<GaloisField<2, 4>>().count_points(),
+        ];
+        let counts: Vec<usize> = (2..=4)
+            .map(|k| curve.count_points_extension(k).unwrap())
+            .collect();
+        assert_eq!(counts, expected)
+    }
+
+    #[test]
+    fn extensions_of_galois_fields() {
+        // the conic xz - y^2 over GF(4) has 4^k + 1 points over GF(4^k)
+        let conic: ProjectiveScheme<F4> = ProjectiveScheme::new(vec![homogeneous_over(vec![
+            (1, vec![1, 0, 1]),
+            (-1, vec![0, 2, 0]),
+        ])])
+        .unwrap();
+        let counts: Vec<usize> = (1..=3)
+            .map(|k| conic.count_points_extension(k).unwrap())
+            .collect();
+        assert_eq!(counts, vec![5, 17, 65]);
+        assert_eq!(conic.zeta_function(2).unwrap().q(), 4)
+    }
+}
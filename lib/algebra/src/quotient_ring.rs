@@ -0,0 +1,359 @@
+use super::{
+    errors::Error,
+    field::Field,
+    graded_ring::GradedRing,
+    group::AbelianGroup,
+    ideal::PolynomialIdeal,
+    polynomials::{HomogeneousPolynomial, Monomial, Polynomial},
+    ring::{CommutativeRing, Ring},
+};
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+    rc::Rc,
+};
+
+/// The quotient ring K[X_0,...,X_(dim-1)]/I
+/// elements are represented by their normal forms modulo a Groebner basis of I
+#[derive(Clone, Debug)]
+pub struct QuotientRing<K: Field> {
+    ideal: Rc<PolynomialIdeal<K>>,
+}
+
+/// Element of a quotient ring K[X_0,...,X_(dim-1)]/I
+/// zero and one from the Ring traits do not know their ring,
+/// they take the ring of the element they are combined with
+#[derive(Clone, Debug)]
+pub struct QuotientRingElement<K: Field> {
+    ideal: Option<Rc<PolynomialIdeal<K>>>,
+    normal_form: Polynomial<K>,
+}
+
+impl<K> QuotientRing<K>
+where
+    K: Field + Clone,
+{
+    pub fn new(ideal: PolynomialIdeal<K>) -> QuotientRing<K> {
+        QuotientRing {
+            ideal: Rc::new(ideal),
+        }
+    }
+
+    pub fn ideal(&self) -> &PolynomialIdeal<K> {
+        &self.ideal
+    }
+
+    /// the class of f in the quotient ring
+    pub fn elem(&self, f: Polynomial<K>) -> QuotientRingElement<K> {
+        QuotientRingElement {
+            normal_form: self.ideal.groebner_basis().normal_form(&f),
+            ideal: Some(self.ideal.clone()),
+        }
+    }
+
+    /// monomials of degree d that are not in the leading term ideal
+    /// their classes form a K-basis of the degree d part for homogeneous ideals
+    pub fn graded_piece_basis(&self, d: usize) -> Vec<Monomial<K>> {
+        let leads = self.ideal.groebner_basis().leading_terms();
+        Monomial::all_of_degree(self.ideal.dim(), d)
+            .into_iter()
+            .filter(|mono| !leads.iter().any(|lead| lead.divides(mono)))
+            .collect()
+    }
+
+    /// dimension of the degree d part as K vector space
+    pub fn graded_piece_dim(&self, d: usize) -> usize {
+        self.graded_piece_basis(d).len()
+    }
+
+    /// annihilator of f, i.e. I : f modulo I
+    pub fn annihilator(&self, f: &QuotientRingElement<K>) -> PolynomialIdeal<K> {
+        self.ideal.quotient_element(&f.normal_form)
+    }
+
+    /// f is a zero divisor if gf = 0 for some g != 0, i.e. if I : f != I
+    pub fn is_zero_divisor(&self, f: &QuotientRingElement<K>) -> bool {
+        self.annihilator(f) != *self.ideal
+    }
+}
+
+impl<K> QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    /// the unique representative modulo the ideal
+    pub fn normal_form(&self) -> &Polynomial<K> {
+        &self.normal_form
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.normal_form.is_zero()
+    }
+
+    /// the common ring of two elements, elements without a ring fit into any ring
+    /// two rings agree if their ideals have the same reduced Groebner basis
+    fn common_ideal(
+        &self,
+        other: &QuotientRingElement<K>,
+    ) -> Result<Option<Rc<PolynomialIdeal<K>>>, Error> {
+        match (&self.ideal, &other.ideal) {
+            (Some(left), Some(right)) => {
+                if Rc::ptr_eq(left, right) || left == right {
+                    Ok(Some(left.clone()))
+                } else {
+                    Err(Error::DifferentRings)
+                }
+            }
+            (left, right) => Ok(left.clone().or(right.clone())),
+        }
+    }
+
+    /// checks if both elements can be combined, i.e. live in the same quotient ring
+    pub fn same_ring(&self, other: &QuotientRingElement<K>) -> bool {
+        self.common_ideal(other).is_ok()
+    }
+
+    /// combines two elements, the result lives in the ring of whichever side has one
+    /// panics if the elements belong to different quotient rings
+    fn combine(
+        self,
+        other: QuotientRingElement<K>,
+        op: impl Fn(Polynomial<K>, Polynomial<K>) -> Polynomial<K>,
+    ) -> QuotientRingElement<K> {
+        let ideal = self
+            .common_ideal(&other)
+            .unwrap_or_else(|err| panic!("{err}"));
+        let res = op(self.normal_form, other.normal_form);
+        QuotientRingElement {
+            normal_form: match &ideal {
+                Some(ideal) => ideal.groebner_basis().normal_form(&res),
+                None => res,
+            },
+            ideal,
+        }
+    }
+}
+
+impl<K> Add for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    type Output = QuotientRingElement<K>;
+    fn add(self, other: QuotientRingElement<K>) -> QuotientRingElement<K> {
+        self.combine(other, |f, g| f + g)
+    }
+}
+
+impl<K> Sub for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    type Output = QuotientRingElement<K>;
+    fn sub(self, other: QuotientRingElement<K>) -> QuotientRingElement<K> {
+        self.combine(other, |f, g| f - g)
+    }
+}
+
+impl<K> Mul for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    type Output = QuotientRingElement<K>;
+    fn mul(self, other: QuotientRingElement<K>) -> QuotientRingElement<K> {
+        self.combine(other, |f, g| f * g)
+    }
+}
+
+impl<K> Neg for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    type Output = QuotientRingElement<K>;
+    fn neg(self) -> QuotientRingElement<K> {
+        QuotientRingElement {
+            ideal: self.ideal,
+            normal_form: -self.normal_form,
+        }
+    }
+}
+
+/// elements without a ring are reduced by the ring of the other side
+/// elements of different quotient rings are never equal
+impl<K> PartialEq for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    fn eq(&self, other: &QuotientRingElement<K>) -> bool {
+        match (&self.ideal, &other.ideal) {
+            (None, Some(ideal)) | (Some(ideal), None) => {
+                ideal.groebner_basis().normal_form(&self.normal_form)
+                    == ideal.groebner_basis().normal_form(&other.normal_form)
+            }
+            _ => self.same_ring(other) && self.normal_form == other.normal_form,
+        }
+    }
+}
+
+impl<K> AbelianGroup for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    fn zero() -> QuotientRingElement<K> {
+        QuotientRingElement {
+            ideal: None,
+            normal_form: Polynomial::zero(),
+        }
+    }
+}
+
+impl<K> Ring for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    fn one() -> QuotientRingElement<K> {
+        QuotientRingElement {
+            ideal: None,
+            normal_form: Polynomial::one(),
+        }
+    }
+}
+
+impl<K: Field + Clone> CommutativeRing for QuotientRingElement<K> {}
+
+/// for homogeneous ideals the normal form of a homogeneous element is homogeneous
+impl<K> GradedRing for QuotientRingElement<K>
+where
+    K: Field + Clone,
+{
+    fn is_homogeneous(&self) -> bool {
+        self.normal_form.is_homogeneous()
+    }
+
+    fn degree(&self) -> Option<usize> {
+        self.normal_form.degree()
+    }
+}
+
+impl<K> From<QuotientRingElement<K>> for Polynomial<K>
+where
+    K: Field,
+{
+    fn from(elem: QuotientRingElement<K>) -> Polynomial<K> {
+        elem.normal_form
+    }
+}
+
+impl<K> TryFrom<QuotientRingElement<K>> for HomogeneousPolynomial<K>
+where
+    K: Field + Clone,
+{
+    type Error = Error;
+    fn try_from(elem: QuotientRingElement<K>) -> Result<HomogeneousPolynomial<K>, Self::Error> {
+        elem.normal_form.try_into()
+    }
+}
+
+impl<K> fmt::Display for QuotientRingElement<K>
+where
+    K: Field,
+    K: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.normal_form)
+    }
+}
+
+#[cfg(test)]
+mod quotient_ring_tests {
+    use super::QuotientRing;
+    use crate::{
+        graded_ring::GradedRing, group::AbelianGroup, ideal::PolynomialIdeal,
+        polynomials::Polynomial, rational::Rational, ring::Ring, test_helpers::poly,
+    };
+
+    /// coordinate ring of the conic xz - y^2
+    fn conic() -> QuotientRing<Rational> {
+        QuotientRing::new(PolynomialIdeal::new(vec![poly(vec![
+            (1, vec![1, 0, 1]),
+            (-1, vec![0, 2, 0]),
+        ])]))
+    }
+
+    #[test]
+    fn relation_holds() {
+        let ring = conic();
+        let x = ring.elem(Polynomial::variable(0, 3));
+        let y = ring.elem(Polynomial::variable(1, 3));
+        let z = ring.elem(Polynomial::variable(2, 3));
+        assert_eq!(x * z, y.clone() * y)
+    }
+
+    #[test]
+    fn zero_and_one() {
+        let ring = conic();
+        let x = ring.elem(Polynomial::variable(0, 3));
+        assert_eq!(x.clone() * Ring::one(), x.clone());
+        assert_eq!(x.clone() + AbelianGroup::zero(), x);
+        let relation = ring.elem(poly(vec![(1, vec![1, 0, 1]), (-1, vec![0, 2, 0])]));
+        assert_eq!(relation, AbelianGroup::zero())
+    }
+
+    #[test]
+    fn graded_pieces() {
+        // a conic has Hilbert function 2d + 1
+        let ring = conic();
+        let dims: Vec<usize> = (0..5).map(|d| ring.graded_piece_dim(d)).collect();
+        assert_eq!(dims, vec![1, 3, 5, 7, 9])
+    }
+
+    #[test]
+    fn homogeneous_class() {
+        let ring = conic();
+        let f = ring.elem(poly(vec![(1, vec![0, 2, 0]), (1, vec![1, 1, 0])]));
+        assert!(f.is_homogeneous());
+        assert_eq!(f.degree(), Some(2))
+    }
+
+    #[test]
+    fn zero_divisors() {
+        // in K[x, y]/<xy> x is a zero divisor, x + y is not
+        let ring = QuotientRing::new(PolynomialIdeal::new(vec![poly(vec![(1, vec![1, 1])])]));
+        let x = ring.elem(Polynomial::variable(0, 2));
+        let x_plus_y = ring.elem(poly(vec![(1, vec![1, 0]), (1, vec![0, 1])]));
+        assert!(ring.is_zero_divisor(&x));
+        assert!(!ring.is_zero_divisor(&x_plus_y))
+    }
+
+    #[test]
+    fn rings_must_agree() {
+        // the same ideal given by other generators is the same ring
+        let ring = conic();
+        let same = QuotientRing::new(PolynomialIdeal::new(vec![poly(vec![
+            (2, vec![0, 2, 0]),
+            (-2, vec![1, 0, 1]),
+        ])]));
+        let x = ring.elem(Polynomial::variable(0, 3));
+        let y = same.elem(Polynomial::variable(1, 3));
+        assert!(x.same_ring(&y));
+        assert_eq!(
+            x.clone() * x.clone(),
+            same.elem(poly(vec![(1, vec![2, 0, 0])]))
+        );
+        // in K[x, y, z]/<x> the class of x is zero, in the conic ring it is not
+        let other = QuotientRing::new(PolynomialIdeal::new(vec![Polynomial::variable(0, 3)]));
+        let zero = other.elem(Polynomial::variable(0, 3));
+        assert!(!x.same_ring(&zero));
+        assert_ne!(
+            other.elem(Polynomial::zero()),
+            ring.elem(Polynomial::zero())
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "different quotient rings")]
+    fn mixing_rings_panics() {
+        let ring = conic();
+        let other = QuotientRing::new(PolynomialIdeal::new(vec![Polynomial::variable(0, 3)]));
+        let _ = ring.elem(Polynomial::variable(0, 3)) + other.elem(Polynomial::variable(1, 3));
+    }
+}
@@ -0,0 +1,200 @@
+use super::{
+    big_integer::BigInteger, errors::Error, field::Field, group::AbelianGroup, ring::Ring,
+};
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// Rational numbers num/den with arbitrary precision
+/// always kept in lowest terms with a positive denominator
+/// so derived equality is equality of fractions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: BigInteger,
+    den: BigInteger,
+}
+
+impl Rational {
+    pub fn new(num: i64, den: i64) -> Result<Rational, Error> {
+        if den == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Rational::reduced(num.into(), den.into()))
+    }
+
+    pub fn num(&self) -> &BigInteger {
+        &self.num
+    }
+
+    pub fn den(&self) -> &BigInteger {
+        &self.den
+    }
+
+    /// den is nonzero
+    fn reduced(num: BigInteger, den: BigInteger) -> Rational {
+        let mut divisor = num.gcd(&den);
+        if den.is_negative() {
+            divisor = -divisor;
+        }
+        Rational {
+            num: exact_quotient(num, &divisor),
+            den: exact_quotient(den, &divisor),
+        }
+    }
+}
+
+/// a / b for b dividing a
+fn exact_quotient(a: BigInteger, b: &BigInteger) -> BigInteger {
+    if *b == BigInteger::one() {
+        return a;
+    }
+    a.div_rem_truncated(b).0
+}
+
+impl From<i64> for Rational {
+    fn from(num: i64) -> Rational {
+        Rational {
+            num: num.into(),
+            den: BigInteger::one(),
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == BigInteger::one() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    /// Henrici's addition, gcds are only taken with divisors of the denominators
+    fn add(self, other: Rational) -> Rational {
+        let divisor = self.den.gcd(&other.den);
+        let self_den = exact_quotient(self.den, &divisor);
+        let other_den = exact_quotient(other.den, &divisor);
+        let num = self.num * other_den.clone() + other.num * self_den.clone();
+        if num.is_zero() {
+            return Rational::zero();
+        }
+        let common = num.gcd(&divisor);
+        Rational {
+            num: exact_quotient(num, &common),
+            den: self_den * other_den * exact_quotient(divisor, &common),
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, other: Rational) -> Rational {
+        self + (-other)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    /// cancels before multiplying, both factors are in lowest terms
+    fn mul(self, other: Rational) -> Rational {
+        let first = self.num.gcd(&other.den);
+        let second = other.num.gcd(&self.den);
+        Rational {
+            num: exact_quotient(self.num, &first) * exact_quotient(other.num, &second),
+            den: exact_quotient(self.den, &second) * exact_quotient(other.den, &first),
+        }
+    }
+}
+
+/// panics when dividing by zero, as integer division does
+impl Div for Rational {
+    type Output = Rational;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Rational) -> Rational {
+        self * Field::inverse(other)
+    }
+}
+
+impl AbelianGroup for Rational {
+    fn zero() -> Rational {
+        Rational::from(0)
+    }
+}
+
+impl Ring for Rational {
+    fn one() -> Rational {
+        Rational::from(1)
+    }
+}
+
+impl Field for Rational {
+    fn one() -> Rational {
+        Rational::from(1)
+    }
+
+    fn inverse(self) -> Rational {
+        if self.num.is_zero() {
+            panic!("{}", Error::DivisionByZero)
+        }
+        Rational::reduced(self.den, self.num)
+    }
+}
+
+#[cfg(test)]
+mod rational_tests {
+    use super::Rational;
+    use crate::field::Field;
+
+    #[test]
+    fn new_reduces() {
+        let result = Rational::new(4, -6).unwrap();
+        let expected = Rational::new(-2, 3).unwrap();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn new_zero_den() {
+        assert!(Rational::new(1, 0).is_err())
+    }
+
+    #[test]
+    fn add_fractions() {
+        let result = Rational::new(1, 2).unwrap() + Rational::new(1, 3).unwrap();
+        let expected = Rational::new(5, 6).unwrap();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn inverse_negative() {
+        let result = Rational::new(-2, 5).unwrap().inverse();
+        let expected = Rational::new(-5, 2).unwrap();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn beyond_i64() {
+        let large = Rational::from(i64::MAX) * Rational::from(i64::MAX);
+        let result = large.clone() / Rational::new(i64::MAX, 3).unwrap();
+        assert_eq!(result / Rational::from(i64::MAX), Rational::from(3));
+        assert_eq!(large.clone() - large.clone(), Rational::new(0, 1).unwrap());
+        let sum = large.clone().inverse() + large.inverse();
+        assert_eq!(
+            format!("{}", sum),
+            "2/85070591730234615847396907784232501249"
+        )
+    }
+}
@@ -1,5 +1,5 @@
-use super::group::{AbelianGroup, AbelianSubGroup};
-use std::ops::Mul;
+use super::group::{AbelianGroup, AbelianSubGroup, FiniteAbelianGroup};
+use std::{fmt, ops::Mul};
 
 ///Rings
 ///Rings are abelian groups with multiplication and identity
@@ -27,18 +27,163 @@ where
     }
 }
 
-///Subring of a ring R
-///implmented as a element check
-pub struct SubRing<R>
+/// Rings with commutative multiplication
+/// commutativity can not be checked by the compiler, so this is a marker
+pub trait CommutativeRing: Ring {}
+
+/// Rings in which finitely many elements generate a finite subring
+/// this is a marker like CommutativeRing, subrings are only enumerated for these rings
+pub trait FiniteRing: Ring + FiniteAbelianGroup {}
+
+/// Subring of a ring R generated by finitely many elements
+/// R has to be a FiniteRing, the elements are enumerated on construction
+#[derive(Clone, Debug)]
+pub struct SubRing<R: Ring> {
+    generators: Vec<R>,
+    elements: Vec<R>,
+}
+
+impl<R: FiniteRing + Clone> SubRing<R> {
+    /// the smallest subring containing one and all generators
+    /// every new element is added to and multiplied with all known elements
+    pub fn generated_by(generators: Vec<R>) -> SubRing<R> {
+        let mut elements: Vec<R> = vec![];
+        let mut queue = vec![R::zero(), R::one()];
+        queue.extend(generators.iter().cloned());
+        while let Some(next) = queue.pop() {
+            if elements.contains(&next) {
+                continue;
+            }
+            for elem in elements.iter() {
+                queue.push(next.clone() + elem.clone());
+                queue.push(next.clone() * elem.clone());
+                queue.push(elem.clone() * next.clone());
+            }
+            queue.push(next.clone() + next.clone());
+            queue.push(next.clone() * next.clone());
+            queue.push(-next.clone());
+            elements.push(next);
+        }
+        SubRing {
+            generators,
+            elements,
+        }
+    }
+
+    /// subring with the given elements
+    /// generators are picked greedily among them
+    fn from_elements(elements: &[R]) -> SubRing<R> {
+        let mut subring = SubRing::generated_by(vec![]);
+        for elem in elements.iter() {
+            if !subring.contains(elem) {
+                let mut generators = subring.generators;
+                generators.push(elem.clone());
+                subring = SubRing::generated_by(generators);
+            }
+        }
+        subring
+    }
+
+    pub fn intersection(&self, other: &SubRing<R>) -> SubRing<R> {
+        let common: Vec<R> = self
+            .elements
+            .iter()
+            .filter(|elem| other.contains(elem))
+            .cloned()
+            .collect();
+        SubRing::from_elements(&common)
+    }
+
+    ///Every subring is also an abelian subgroup
+    pub fn as_subgroup(&self) -> AbelianSubGroup<R> {
+        AbelianSubGroup::generated_by(self.elements.clone())
+    }
+}
+
+impl<R: Ring + Clone> SubRing<R> {
+    pub fn generators(&self) -> &[R] {
+        &self.generators
+    }
+
+    pub fn elements(&self) -> &[R] {
+        &self.elements
+    }
+
+    /// number of elements
+    pub fn order(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn contains(&self, elem: &R) -> bool {
+        self.elements.contains(elem)
+    }
+}
+
+/// subrings are equal if they have the same elements
+impl<R: Ring + Clone> PartialEq for SubRing<R> {
+    fn eq(&self, other: &SubRing<R>) -> bool {
+        self.order() == other.order() && self.generators.iter().all(|gen| other.contains(gen))
+    }
+}
+
+impl<R> fmt::Display for SubRing<R>
 where
-    R: Ring,
+    R: Ring + fmt::Display,
 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gen_str: Vec<String> = self
+            .generators
+            .iter()
+            .map(|gen| format!("{}", gen))
+            .collect();
+        write!(f, "[{}]", gen_str.join(", "))
+    }
+}
+
+/// Subring given only by a membership test
+pub struct PredicateSubRing<R> {
     pub elem: Box<dyn Fn(&R) -> bool>,
 }
 
-///Every subring is also an abelian subgroup
-impl<R: Ring> SubRing<R> {
-    pub fn as_subgroup(self) -> AbelianSubGroup<R> {
-        AbelianSubGroup { elem: self.elem }
+impl<R: 'static> PredicateSubRing<R> {
+    pub fn contains(&self, elem: &R) -> bool {
+        (self.elem)(elem)
+    }
+
+    /// elements satisfying both predicates
+    pub fn intersection(self, other: PredicateSubRing<R>) -> PredicateSubRing<R> {
+        PredicateSubRing {
+            elem: Box::new(move |r| (self.elem)(r) && (other.elem)(r)),
+        }
+    }
+}
+
+impl<R: Ring + Clone + 'static> From<SubRing<R>> for PredicateSubRing<R> {
+    fn from(subring: SubRing<R>) -> PredicateSubRing<R> {
+        PredicateSubRing {
+            elem: Box::new(move |r| subring.contains(r)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod ring_tests {
+    use super::SubRing;
+    use crate::galois_field::GaloisField;
+
+    #[test]
+    fn generated_subrings() {
+        type F16 = GaloisField<2, 4>;
+        // the prime ring is F_2
+        assert_eq!(SubRing::<F16>::generated_by(vec![]).order(), 2);
+        // a^5 has order 3 and generates F_4
+        let subring = SubRing::generated_by(vec![F16::primitive_element().pow_mod(5)]);
+        assert_eq!(subring.order(), 4);
+        assert_eq!(
+            SubRing::generated_by(vec![F16::primitive_element()]).order(),
+            16
+        );
+        let full = SubRing::generated_by(vec![F16::primitive_element()]);
+        assert_eq!(subring.intersection(&full), subring)
     }
 }
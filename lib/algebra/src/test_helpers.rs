@@ -0,0 +1,66 @@
+//! constructors shared by the unit tests, coefficients and coordinates are given as integers
+//! the plain versions work over the rationals, the _over versions over any field
+
+use crate::{
+    field::Field,
+    ideal::PolynomialIdeal,
+    polynomials::{HomogeneousPolynomial, Monomial, Polynomial},
+    projective::{ProjectivePoint, ProjectiveScheme},
+    rational::Rational,
+};
+
+/// polynomial from (coefficient, powers) pairs
+pub(crate) fn poly_over<K: Field + From<i64>>(terms: Vec<(i64, Vec<usize>)>) -> Polynomial<K> {
+    Polynomial::new(
+        terms
+            .into_iter()
+            .map(|(coeff, powers)| Monomial::new(coeff.into(), powers))
+            .collect(),
+    )
+}
+
+/// form from (coefficient, powers) pairs of the same degree
+pub(crate) fn homogeneous_over<K: Field + Clone + From<i64>>(
+    terms: Vec<(i64, Vec<usize>)>,
+) -> HomogeneousPolynomial<K> {
+    HomogeneousPolynomial::new(
+        terms
+            .into_iter()
+            .map(|(coeff, powers)| Monomial::new(coeff.into(), powers))
+            .collect(),
+    )
+    .unwrap()
+}
+
+/// projective scheme cut out by the given forms
+pub(crate) fn scheme_over<K: Field + Clone + From<i64>>(
+    forms: Vec<Vec<(i64, Vec<usize>)>>,
+) -> ProjectiveScheme<K> {
+    ProjectiveScheme::new(forms.into_iter().map(homogeneous_over).collect()).unwrap()
+}
+
+pub(crate) fn point_over<K: Field + Clone + From<i64>>(
+    coordinates: Vec<i64>,
+) -> ProjectivePoint<K> {
+    ProjectivePoint::new(coordinates.into_iter().map(K::from).collect()).unwrap()
+}
+
+pub(crate) fn poly(terms: Vec<(i64, Vec<usize>)>) -> Polynomial<Rational> {
+    poly_over(terms)
+}
+
+pub(crate) fn homogeneous(terms: Vec<(i64, Vec<usize>)>) -> HomogeneousPolynomial<Rational> {
+    homogeneous_over(terms)
+}
+
+pub(crate) fn ideal(generators: Vec<Vec<(i64, Vec<usize>)>>) -> PolynomialIdeal<Rational> {
+    PolynomialIdeal::new(generators.into_iter().map(poly).collect())
+}
+
+pub(crate) fn scheme(forms: Vec<Vec<(i64, Vec<usize>)>>) -> ProjectiveScheme<Rational> {
+    scheme_over(forms)
+}
+
+pub(crate) fn point(coordinates: Vec<i64>) -> ProjectivePoint<Rational> {
+    point_over(coordinates)
+}
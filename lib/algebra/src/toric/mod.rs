@@ -0,0 +1,5 @@
+pub mod polytope;
+pub mod variety;
+
+pub use polytope::LatticePolytope;
+pub use variety::ToricVariety;
@@ -0,0 +1,276 @@
+use crate::{arithmetic::gcd_all, errors::Error, projective::minors::subsets};
+
+/// Full dimensional lattice polytope in R^d, the convex hull of the given points
+/// facets are stored as pairs (u, b) with primitive inner normal u,
+/// so the polytope is {a | <u, a> + b >= 0 for all facets}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LatticePolytope {
+    dim: usize,
+    vertices: Vec<Vec<i64>>,
+    facets: Vec<(Vec<i64>, i64)>,
+}
+
+impl LatticePolytope {
+    /// convex hull of the vertices, which have to span R^d affinely
+    pub fn new(vertices: Vec<Vec<i64>>) -> Result<LatticePolytope, Error> {
+        let dim = vertices.first().map(|v| v.len()).unwrap_or(0);
+        if let Some(v) = vertices.iter().find(|v| v.len() != dim) {
+            return Err(Error::DimensionMismatch {
+                found: v.len(),
+                expected: dim,
+            });
+        }
+        let homogenized: Vec<Vec<i64>> = vertices.iter().map(|v| homogenize(v)).collect();
+        let affine_relations = integer_kernel(&homogenized, dim + 1).len();
+        if vertices.is_empty() || affine_relations > 0 {
+            return Err(Error::DimensionMismatch {
+                found: (dim + 1 - affine_relations).saturating_sub(1),
+                expected: dim,
+            });
+        }
+        let facets = facets(&vertices, dim);
+        Ok(LatticePolytope {
+            dim,
+            vertices,
+            facets,
+        })
+    }
+
+    /// d times the standard simplex, with vertices 0 and d e_i in R^n
+    pub fn simplex(n: usize, d: i64) -> LatticePolytope {
+        let mut vertices = vec![vec![0; n]];
+        for i in 0..n {
+            let mut vertex = vec![0; n];
+            vertex[i] = d;
+            vertices.push(vertex);
+        }
+        LatticePolytope::new(vertices).expect("the simplex is full dimensional")
+    }
+
+    /// the unit cube [0, 1]^n
+    pub fn cube(n: usize) -> LatticePolytope {
+        (0..n).fold(LatticePolytope::simplex(0, 1), |cube, _| {
+            cube.product(&LatticePolytope::simplex(1, 1))
+        })
+    }
+
+    /// P x Q in R^(d+e)
+    pub fn product(&self, other: &LatticePolytope) -> LatticePolytope {
+        let vertices = self
+            .vertices
+            .iter()
+            .flat_map(|v| {
+                other.vertices.iter().map(move |w| {
+                    let mut vertex = v.clone();
+                    vertex.extend(w);
+                    vertex
+                })
+            })
+            .collect();
+        LatticePolytope::new(vertices).expect("products of full dimensional polytopes are")
+    }
+
+    /// the dilation kP
+    pub fn dilate(&self, k: i64) -> Result<LatticePolytope, Error> {
+        LatticePolytope::new(
+            self.vertices
+                .iter()
+                .map(|v| v.iter().map(|x| k * x).collect())
+                .collect(),
+        )
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn vertices(&self) -> &[Vec<i64>] {
+        &self.vertices
+    }
+
+    pub fn facets(&self) -> &[(Vec<i64>, i64)] {
+        &self.facets
+    }
+
+    /// values <u, a> + b of all facets at a, the lattice distances to the facets
+    pub fn facet_distances(&self, point: &[i64]) -> Vec<i64> {
+        self.facets
+            .iter()
+            .map(|(normal, b)| dot(normal, point) + b)
+            .collect()
+    }
+
+    pub fn contains(&self, point: &[i64]) -> bool {
+        point.len() == self.dim && self.facet_distances(point).iter().all(|dist| *dist >= 0)
+    }
+
+    /// all lattice points, in lexicographic order
+    pub fn lattice_points(&self) -> Vec<Vec<i64>> {
+        let mut points = vec![vec![]];
+        for i in 0..self.dim {
+            let min = self.vertices.iter().map(|v| v[i]).min().unwrap();
+            let max = self.vertices.iter().map(|v| v[i]).max().unwrap();
+            points = points
+                .into_iter()
+                .flat_map(|point: Vec<i64>| {
+                    (min..=max).map(move |x| {
+                        let mut point = point.clone();
+                        point.push(x);
+                        point
+                    })
+                })
+                .collect();
+        }
+        points
+            .into_iter()
+            .filter(|point| self.contains(point))
+            .collect()
+    }
+
+    /// normalized volume d! vol(P), the degree of the toric variety
+    /// computed from the leading coefficient of the Ehrhart polynomial
+    /// via the finite differences of the lattice point counts of kP for k = 0, ..., d
+    pub fn normalized_volume(&self) -> i64 {
+        let counts: Vec<i64> = (0..=self.dim as i64)
+            .map(|k| match k {
+                0 => 1,
+                _ => self.dilate(k).unwrap().lattice_points().len() as i64,
+            })
+            .collect();
+        (0..=self.dim)
+            .map(|k| {
+                let sign = if (self.dim - k).is_multiple_of(2) {
+                    1
+                } else {
+                    -1
+                };
+                sign * binomial(self.dim, k) * counts[k]
+            })
+            .sum()
+    }
+}
+
+fn homogenize(point: &[i64]) -> Vec<i64> {
+    let mut homogenized = point.to_vec();
+    homogenized.push(1);
+    homogenized
+}
+
+fn dot(left: &[i64], right: &[i64]) -> i64 {
+    left.iter().zip(right).map(|(x, y)| x * y).sum()
+}
+
+fn binomial(n: usize, k: usize) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) as i64 / (i + 1) as i64)
+}
+
+/// facets through d affinely independent vertices with all vertices on one side
+fn facets(vertices: &[Vec<i64>], dim: usize) -> Vec<(Vec<i64>, i64)> {
+    let mut facets: Vec<(Vec<i64>, i64)> = vec![];
+    for subset in subsets(vertices.len(), dim) {
+        let rows: Vec<Vec<i64>> = subset.iter().map(|i| homogenize(&vertices[*i])).collect();
+        let kernel = integer_kernel(&rows, dim + 1);
+        if kernel.len() != 1 {
+            continue;
+        }
+        let mut normal = kernel[0].clone();
+        let mut b = normal.pop().unwrap();
+        let g = gcd_all(&normal);
+        if g == 0 {
+            continue;
+        }
+        normal.iter_mut().for_each(|x| *x /= g);
+        b /= g;
+        let distances: Vec<i64> = vertices.iter().map(|v| dot(&normal, v) + b).collect();
+        if distances.iter().all(|dist| *dist <= 0) {
+            normal.iter_mut().for_each(|x| *x = -*x);
+            b = -b;
+        } else if distances.iter().any(|dist| *dist < 0) {
+            continue;
+        }
+        if !facets.contains(&(normal.clone(), b)) {
+            facets.push((normal, b));
+        }
+    }
+    facets
+}
+
+/// Z-basis of the lattice {x in Z^n | A x = 0}
+/// integer column operations bring A to echelon form A U with U unimodular,
+/// the columns of U after the pivot columns span the kernel
+pub(crate) fn integer_kernel(rows: &[Vec<i64>], num_cols: usize) -> Vec<Vec<i64>> {
+    let mut matrix = rows.to_vec();
+    let mut transform: Vec<Vec<i64>> = (0..num_cols)
+        .map(|j| (0..num_cols).map(|i| i64::from(i == j)).collect())
+        .collect();
+    let mut pivot = 0;
+    for r in 0..matrix.len() {
+        while let Some(min) = (pivot..num_cols)
+            .filter(|c| matrix[r][*c] != 0)
+            .min_by_key(|c| matrix[r][*c].abs())
+        {
+            let mut reduced = true;
+            for c in pivot..num_cols {
+                if c == min || matrix[r][c] == 0 {
+                    continue;
+                }
+                // column c -= q column min
+                let q = matrix[r][c] / matrix[r][min];
+                for row in matrix.iter_mut() {
+                    row[c] -= q * row[min];
+                }
+                let column = transform[min].clone();
+                for (entry, x) in transform[c].iter_mut().zip(column) {
+                    *entry -= q * x;
+                }
+                reduced &= matrix[r][c] == 0;
+            }
+            if reduced {
+                for row in matrix.iter_mut() {
+                    row.swap(min, pivot);
+                }
+                transform.swap(min, pivot);
+                pivot += 1;
+                break;
+            }
+        }
+    }
+    transform.split_off(pivot)
+}
+
+#[cfg(test)]
+mod polytope_tests {
+    use super::{integer_kernel, LatticePolytope};
+
+    #[test]
+    fn kernel_is_saturated() {
+        // 2x + 4y + 6z = 0 has the kernel basis (2, -1, 0), (3, 0, -1) up to unimodular changes
+        let kernel = integer_kernel(&[vec![2, 4, 6]], 3);
+        assert_eq!(kernel.len(), 2);
+        for v in kernel.iter() {
+            assert_eq!(2 * v[0] + 4 * v[1] + 6 * v[2], 0);
+        }
+        // the 2x2 minors are coprime, so the lattice is saturated
+        let minors = [
+            kernel[0][0] * kernel[1][1] - kernel[0][1] * kernel[1][0],
+            kernel[0][0] * kernel[1][2] - kernel[0][2] * kernel[1][0],
+            kernel[0][1] * kernel[1][2] - kernel[0][2] * kernel[1][1],
+        ];
+        assert_eq!(crate::arithmetic::gcd_all(&minors), 1)
+    }
+
+    #[test]
+    fn facets_and_lattice_points() {
+        let square = LatticePolytope::cube(2);
+        assert_eq!(square.facets().len(), 4);
+        assert_eq!(square.lattice_points().len(), 4);
+        let triangle = LatticePolytope::simplex(2, 2);
+        assert_eq!(triangle.facets().len(), 3);
+        assert_eq!(triangle.lattice_points().len(), 6);
+        assert!(triangle.contains(&[1, 1]));
+        assert!(!triangle.contains(&[2, 1]));
+        assert_eq!(triangle.normalized_volume(), 4);
+        // a segment in the plane is not full dimensional
+        assert!(LatticePolytope::new(vec![vec![0, 0], vec![1, 1]]).is_err())
+    }
+}
@@ -0,0 +1,188 @@
+use super::{polytope::integer_kernel, LatticePolytope};
+use crate::{
+    field::Field,
+    ideal::PolynomialIdeal,
+    polynomials::{Monomial, Polynomial},
+    projective::{ProjectiveMorphism, ProjectiveScheme},
+};
+
+/// Projective toric variety X_P of a lattice polytope P
+/// the closure of {(t^a_0 : ... : t^a_(m-1))} in P^(m-1) for the lattice points a_i of P
+#[derive(Clone, Debug)]
+pub struct ToricVariety {
+    polytope: LatticePolytope,
+    lattice_points: Vec<Vec<i64>>,
+}
+
+impl ToricVariety {
+    pub fn new(polytope: LatticePolytope) -> ToricVariety {
+        let lattice_points = polytope.lattice_points();
+        ToricVariety {
+            polytope,
+            lattice_points,
+        }
+    }
+
+    /// the d-uple Veronese embedding of P^n, from d times the standard simplex
+    pub fn veronese(n: usize, d: i64) -> ToricVariety {
+        ToricVariety::new(LatticePolytope::simplex(n, d))
+    }
+
+    /// the Segre embedding of P^n x P^m, from the product of standard simplices
+    pub fn segre(n: usize, m: usize) -> ToricVariety {
+        ToricVariety::new(LatticePolytope::simplex(n, 1).product(&LatticePolytope::simplex(m, 1)))
+    }
+
+    pub fn polytope(&self) -> &LatticePolytope {
+        &self.polytope
+    }
+
+    /// the lattice points of P, in the order of the homogeneous coordinates
+    pub fn lattice_points(&self) -> &[Vec<i64>] {
+        &self.lattice_points
+    }
+
+    /// number of homogeneous coordinates of the ambient projective space
+    pub fn num_variables(&self) -> usize {
+        self.lattice_points.len()
+    }
+
+    /// Z-basis of the affine relations sum_i c_i (a_i, 1) = 0 between the lattice points
+    pub fn lattice_relations(&self) -> Vec<Vec<i64>> {
+        let dim = self.polytope.dim();
+        let rows: Vec<Vec<i64>> = (0..=dim)
+            .map(|j| {
+                self.lattice_points
+                    .iter()
+                    .map(|point| if j < dim { point[j] } else { 1 })
+                    .collect()
+            })
+            .collect();
+        integer_kernel(&rows, self.num_variables())
+    }
+
+    /// the toric ideal generated by all x^u - x^v with u - v a lattice relation
+    /// the binomials of a lattice basis generate it up to saturation by x_0 ... x_(m-1)
+    pub fn toric_ideal<K: Field + Clone>(&self) -> PolynomialIdeal<K> {
+        let num_variables = self.num_variables();
+        let binomial = |relation: &Vec<i64>| {
+            let positive = relation.iter().map(|c| (*c).max(0) as usize).collect();
+            let negative = relation.iter().map(|c| (-*c).max(0) as usize).collect();
+            Polynomial::from(Monomial::new(<K as Field>::one(), positive))
+                - Polynomial::from(Monomial::new(<K as Field>::one(), negative))
+        };
+        let lattice_ideal = PolynomialIdeal::with_dim(
+            self.lattice_relations().iter().map(binomial).collect(),
+            num_variables,
+        );
+        let product = (0..num_variables).fold(
+            Polynomial::constant(<K as Field>::one()).with_dim(num_variables),
+            |product, i| product * Polynomial::variable(i, num_variables),
+        );
+        lattice_ideal.saturation_element(&product)
+    }
+
+    /// X_P as a subscheme of P^(m-1), cut out by the toric ideal
+    pub fn scheme<K: Field + Clone>(&self) -> ProjectiveScheme<K> {
+        ProjectiveScheme::from_ideal(self.num_variables(), &self.toric_ideal())
+            .expect("toric ideals are homogeneous")
+    }
+
+    /// monomial parametrization by the facet coordinates x_F
+    /// the lattice point a maps to prod_F x_F^(<u_F, a> + b_F),
+    /// this is the Veronese embedding for simplices and the Segre embedding for products of them
+    /// if these monomials have different degrees, an extra variable makes them homogeneous
+    pub fn parametrization<K: Field + Clone>(&self) -> ProjectiveMorphism<K> {
+        let mut exponents: Vec<Vec<usize>> = self
+            .lattice_points
+            .iter()
+            .map(|point| {
+                self.polytope
+                    .facet_distances(point)
+                    .into_iter()
+                    .map(|dist| dist as usize)
+                    .collect()
+            })
+            .collect();
+        let degrees: Vec<usize> = exponents.iter().map(|powers| powers.iter().sum()).collect();
+        let max_degree = degrees.iter().copied().max().unwrap_or(0);
+        if degrees.iter().any(|deg| *deg != max_degree) {
+            for (powers, deg) in exponents.iter_mut().zip(degrees) {
+                powers.push(max_degree - deg);
+            }
+        }
+        ProjectiveMorphism::monomial_map(exponents)
+    }
+}
+
+#[cfg(test)]
+mod toric_tests {
+    use super::ToricVariety;
+    use crate::{
+        field::Field, polynomials::Polynomial, prime_field::PrimeField,
+        projective::ProjectiveMorphism, rational::Rational, toric::LatticePolytope,
+    };
+
+    /// sorted exponents of a monomial map
+    fn exponents<K: Field + Clone>(morphism: &ProjectiveMorphism<K>) -> Vec<Vec<usize>> {
+        let mut exponents: Vec<Vec<usize>> = morphism
+            .coordinate_functions()
+            .into_iter()
+            .map(|fun| Polynomial::from(fun).terms()[0].powers())
+            .collect();
+        exponents.sort();
+        exponents
+    }
+
+    #[test]
+    fn quadric_surface() {
+        let segre = ToricVariety::segre(1, 1);
+        assert_eq!(segre.lattice_relations().len(), 1);
+        let quadric = segre.scheme::<Rational>();
+        assert_eq!(quadric.ideal_generators().len(), 1);
+        assert_eq!(quadric.degree(), 2);
+        assert_eq!(quadric.dim(), Some(2))
+    }
+
+    #[test]
+    fn veronese_and_segre() {
+        // the facet coordinates of d times the simplex are the homogeneous coordinates of P^n,
+        // so the parametrization has all monomials of degree d
+        for (n, d) in [(1, 3), (2, 2)] {
+            let toric = ToricVariety::veronese(n, d);
+            let veronese = ProjectiveMorphism::<Rational>::veronese_embedding(n, d as usize);
+            let parametrization = toric.parametrization::<Rational>();
+            assert_eq!(parametrization.dim_domain(), n + 1);
+            assert_eq!(exponents(&parametrization), exponents(&veronese));
+            assert_eq!(
+                toric.scheme::<Rational>().betti_table().to_string(),
+                veronese.image().betti_table().to_string()
+            );
+        }
+        let segre = ToricVariety::segre(1, 2);
+        let morphism = ProjectiveMorphism::<PrimeField<101>>::segre_embedding(1, 2);
+        assert_eq!(
+            segre.scheme::<PrimeField<101>>().hilbert_series(),
+            morphism.image().hilbert_series()
+        )
+    }
+
+    #[test]
+    fn cubic_scroll() {
+        // the trapezoid with vertices (0,0), (2,0), (1,1), (0,1) gives a cubic scroll in P^4
+        let trapezoid =
+            LatticePolytope::new(vec![vec![0, 0], vec![2, 0], vec![1, 1], vec![0, 1]]).unwrap();
+        assert_eq!(trapezoid.normalized_volume(), 3);
+        let toric = ToricVariety::new(trapezoid);
+        let scroll = toric.scheme::<Rational>();
+        assert_eq!(scroll.num_variables(), 5);
+        assert_eq!(scroll.degree(), 3);
+        assert_eq!(scroll.dim(), Some(2));
+        // the facet normals do not sum to zero, so the parametrization needs an extra variable
+        let parametrization = toric.parametrization::<Rational>();
+        assert_eq!(parametrization.dim_domain(), 5);
+        let image = parametrization.image();
+        assert!(image.ideal().contains_ideal(&scroll.ideal()));
+        assert!(scroll.ideal().contains_ideal(&image.ideal()))
+    }
+}
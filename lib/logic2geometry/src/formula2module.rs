@@ -0,0 +1,107 @@
+use algebra::{field::Field, graded_module::GradedModule};
+use common::definitions::{Atom, Polarity};
+use mll::formula::Formula;
+
+/// Interprets a formula of linear logic as a graded module
+/// a positive atom is sent to its module and a negative atom to the dual,
+/// tensor is the tensor product and par is (M* (x) N*)*
+/// None for unknown atoms and for the exponentials, which have no interpretation yet
+pub fn formula2module<K, F>(formula: &Formula, atoms: &F) -> Option<GradedModule<K>>
+where
+    K: Field + Clone,
+    F: Fn(&Atom) -> Option<GradedModule<K>>,
+{
+    match formula {
+        Formula::Atomic(at) => {
+            let module = atoms(&at.atom)?;
+            match at.pol {
+                Polarity::Pos => Some(module),
+                Polarity::Neg => Some(module.dual()),
+            }
+        }
+        Formula::Tensor(l, r) => Some(formula2module(l, atoms)?.tensor(&formula2module(r, atoms)?)),
+        Formula::Par(l, r) => Some(formula2module(l, atoms)?.par(&formula2module(r, atoms)?)),
+        Formula::Bang(_) | Formula::Quest(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod formula2module_tests {
+    use super::formula2module;
+    use algebra::{
+        graded_module::{FreeModule, GradedModule},
+        rational::Rational,
+    };
+    use common::definitions::{Atom, OrientedAtom, Polarity};
+    use mll::formula::Formula;
+    use std::rc::Rc;
+
+    fn atom(name: &str, pol: Polarity) -> Formula {
+        Formula::Atomic(OrientedAtom {
+            atom: name.to_string(),
+            pol,
+        })
+    }
+
+    /// a is S(-1) and b is S + S(-2) over K[x, y]
+    fn atoms(at: &Atom) -> Option<GradedModule<Rational>> {
+        match at.as_str() {
+            "a" => Some(GradedModule::free(&FreeModule::new(2, vec![1]))),
+            "b" => Some(GradedModule::free(&FreeModule::new(2, vec![0, 2]))),
+            _ => None,
+        }
+    }
+
+    fn assert_free(module: Option<GradedModule<Rational>>, degrees: Vec<i64>) {
+        let module = module.unwrap();
+        let expected = FreeModule::new(2, degrees);
+        for d in -4..=6 {
+            assert_eq!(module.hilbert_function(d), expected.hilbert_function(d))
+        }
+    }
+
+    #[test]
+    fn atoms_and_duals() {
+        assert_free(formula2module(&atom("a", Polarity::Pos), &atoms), vec![1]);
+        assert_free(formula2module(&atom("a", Polarity::Neg), &atoms), vec![-1]);
+        assert_free(
+            formula2module(&atom("b", Polarity::Neg), &atoms),
+            vec![0, -2],
+        )
+    }
+
+    #[test]
+    fn tensor_and_par() {
+        let (a, b) = (
+            Rc::new(atom("a", Polarity::Pos)),
+            Rc::new(atom("b", Polarity::Pos)),
+        );
+        let tensor = Formula::Tensor(a.clone(), b.clone());
+        assert_free(formula2module(&tensor, &atoms), vec![1, 3]);
+        // for free modules par agrees with the tensor product
+        let par = Formula::Par(a, b);
+        assert_free(formula2module(&par, &atoms), vec![1, 3])
+    }
+
+    #[test]
+    fn negation_is_the_dual() {
+        // -(a ⊗ b) = -a ⅋ -b is interpreted as (a ⊗ b)*
+        let tensor = Formula::Tensor(
+            Rc::new(atom("a", Polarity::Pos)),
+            Rc::new(atom("b", Polarity::Pos)),
+        );
+        let negation = -tensor;
+        assert!(matches!(negation, Formula::Par(_, _)));
+        assert_free(formula2module(&negation, &atoms), vec![-1, -3])
+    }
+
+    #[test]
+    fn no_interpretation() {
+        let known = Rc::new(atom("a", Polarity::Pos));
+        let unknown = Rc::new(atom("c", Polarity::Pos));
+        assert!(formula2module(&unknown, &atoms).is_none());
+        assert!(formula2module(&Formula::Tensor(known.clone(), unknown), &atoms).is_none());
+        assert!(formula2module(&Formula::Bang(known.clone()), &atoms).is_none());
+        assert!(formula2module(&Formula::Quest(known), &atoms).is_none())
+    }
+}
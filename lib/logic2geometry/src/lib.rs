@@ -1 +1,2 @@
+pub mod formula2module;
 pub mod formula2scheme;